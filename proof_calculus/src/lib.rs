@@ -5,3 +5,6 @@ pub mod proofs;
 pub mod propositions;
 
 // pub mod stringification;
+
+#[cfg(test)]
+mod test_helpers;
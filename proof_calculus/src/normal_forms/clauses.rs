@@ -0,0 +1,169 @@
+use std::{collections::HashSet, hash::Hash};
+
+use crate::utils::{
+    collections::sets::tiny_immutable_ordered_set::TinyImmutableOrderedSet,
+    traits::fast_ord::FastOrd,
+};
+
+/// A proposition which may be the negation of another, so that clauses containing both can be simplified away
+pub trait ComplementaryProposition {
+    fn is_complement_of(&self, other: &Self) -> bool;
+}
+
+/// A set of literals which forms a clause of a conjunctive or disjunctive normal form
+pub trait NormalFormClause<T>: Clone + FromIterator<T> {
+    fn literals<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+    fn contains_literal(&self, literal: &T) -> bool;
+    fn literal_count(&self) -> usize;
+
+    /// Whether every literal of this clause is also in the other
+    fn is_subset_of(&self, other: &Self) -> bool {
+        self.literal_count() <= other.literal_count()
+            && self
+                .literals()
+                .all(|literal| other.contains_literal(literal))
+    }
+    /// Whether this clause contains a literal alongside its complement
+    fn is_complementary(&self) -> bool
+    where
+        T: ComplementaryProposition,
+    {
+        self.literals()
+            .any(|literal| self.literals().any(|other| literal.is_complement_of(other)))
+    }
+}
+impl<T: FastOrd + Eq + Clone> NormalFormClause<T> for TinyImmutableOrderedSet<T> {
+    fn literals<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+    fn contains_literal(&self, literal: &T) -> bool {
+        self.contains(literal)
+    }
+    fn literal_count(&self) -> usize {
+        self.count()
+    }
+}
+impl<T: Hash + Eq + Clone> NormalFormClause<T> for HashSet<T> {
+    fn literals<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter()
+    }
+    fn contains_literal(&self, literal: &T) -> bool {
+        self.contains(literal)
+    }
+    fn literal_count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Remove every clause which contains another clause, along with duplicate clauses
+/// In a CNF the removed clauses are implied by the clauses which they contain, and in a DNF they imply them, so the result is equivalent in either form
+pub fn remove_subsumed<T, C: NormalFormClause<T>>(clauses: impl IntoIterator<Item = C>) -> Vec<C> {
+    let mut clauses: Vec<C> = clauses.into_iter().collect();
+    // A clause can only contain clauses no larger than itself, so these are kept first
+    clauses.sort_by_key(|clause| clause.literal_count());
+    let mut kept: Vec<C> = Vec::new();
+    for clause in clauses {
+        if !kept.iter().any(|smaller| smaller.is_subset_of(&clause)) {
+            kept.push(clause);
+        }
+    }
+    kept
+}
+
+/// Remove every clause which contains a literal alongside its complement
+/// In a CNF such clauses are tautologies and in a DNF they are contradictions, so in either form they can be dropped without changing its meaning
+pub fn remove_complementary<T: ComplementaryProposition, C: NormalFormClause<T>>(
+    clauses: impl IntoIterator<Item = C>,
+) -> Vec<C> {
+    clauses
+        .into_iter()
+        .filter(|clause| !clause.is_complementary())
+        .collect()
+}
+
+/// Distribute the clauses of one normal form over each other, giving the clauses of the dual normal form
+/// Each resulting clause takes one literal from every provided clause; subsumed clauses are removed as the result is built, to keep it from growing needlessly
+pub fn distribute<'a, T: Clone + 'a, C: NormalFormClause<T> + 'a, D: NormalFormClause<T>>(
+    clauses: impl IntoIterator<Item = &'a C>,
+) -> Vec<D> {
+    let mut distributed = vec![D::from_iter([])];
+    for clause in clauses {
+        distributed = remove_subsumed(distributed.iter().flat_map(|partial| {
+            clause.literals().map(move |literal| {
+                partial
+                    .literals()
+                    .cloned()
+                    .chain([literal.clone()])
+                    .collect::<D>()
+            })
+        }));
+    }
+    distributed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clauses(clauses: &[&[i32]]) -> Vec<HashSet<i32>> {
+        clauses
+            .iter()
+            .map(|clause| clause.iter().copied().collect())
+            .collect()
+    }
+    /// The literals of each clause in order, with the clauses themselves in order, so that results can be compared
+    fn sorted(clauses: Vec<HashSet<i32>>) -> Vec<Vec<i32>> {
+        let mut sorted: Vec<Vec<i32>> = clauses
+            .into_iter()
+            .map(|clause| {
+                let mut clause: Vec<i32> = clause.into_iter().collect();
+                clause.sort();
+                clause
+            })
+            .collect();
+        sorted.sort();
+        sorted
+    }
+
+    #[test]
+    fn test_remove_subsumed() {
+        let kept = remove_subsumed(clauses(&[&[1, 2], &[1], &[2, 3], &[1], &[3, 2, 4]]));
+        assert_eq!(sorted(kept), vec![vec![1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_remove_subsumed_keeps_empty_clause_only() {
+        let kept = remove_subsumed(clauses(&[&[1, 2], &[], &[3]]));
+        assert_eq!(sorted(kept), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    fn test_distribute() {
+        let distributed: Vec<HashSet<i32>> = distribute(&clauses(&[&[1, 2], &[3]]));
+        assert_eq!(sorted(distributed), vec![vec![1, 3], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_distribute_removes_subsumed() {
+        // {1} is drawn from both clauses, so it subsumes {1, 2} and {1, 3}
+        let distributed: Vec<HashSet<i32>> = distribute(&clauses(&[&[1, 2], &[1, 3]]));
+        assert_eq!(sorted(distributed), vec![vec![1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_distribute_empty() {
+        // No clauses distribute to a single empty clause, and an empty clause distributes to no clauses
+        let distributed: Vec<HashSet<i32>> = distribute(&clauses(&[]));
+        assert_eq!(sorted(distributed), vec![Vec::<i32>::new()]);
+        let distributed: Vec<HashSet<i32>> = distribute(&clauses(&[&[1], &[]]));
+        assert!(distributed.is_empty());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    normal_forms::{
+        Conjunctive, UnassignedCnf, UnassignedCnfClause,
+        clauses::ComplementaryProposition,
+        implementations::tiny::{
+            TinyUnassignedDnf, TinyUnassignedNormalForm, TinyUnassignedNormalFormClause,
+        },
+    },
+    propositions::types::unassigned::UnassignedProposition,
+    utils::traits::fast_ord::FastOrd,
+};
+
+pub type TinyUnassignedCnf<UP> = TinyUnassignedNormalForm<UP, Conjunctive>;
+pub type TinyUnassignedCnfClause<UP> = TinyUnassignedNormalFormClause<UP, Conjunctive>;
+
+impl<P: UnassignedProposition + FastOrd> UnassignedCnf for TinyUnassignedCnf<P> {
+    type UnassignedClause = TinyUnassignedCnfClause<P>;
+}
+impl<P: UnassignedProposition + FastOrd> TinyUnassignedCnf<P> {
+    /// Remove every clause which contains a proposition alongside its complement, as such clauses always hold
+    pub fn remove_tautologies(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        self.remove_complementary()
+    }
+    /// Convert to the equivalent disjunctive normal form by distributing the clauses over each other
+    /// This may produce exponentially many clauses
+    pub fn to_dnf(&self) -> TinyUnassignedDnf<P> {
+        self.to_dual()
+    }
+}
+
+impl<UP: UnassignedProposition + FastOrd> UnassignedCnfClause for TinyUnassignedCnfClause<UP> {
+    type UnassignedProposition = UP;
+}
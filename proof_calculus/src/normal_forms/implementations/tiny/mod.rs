@@ -1,3 +1,7 @@
+mod conjunctive;
 mod disjunctive;
+mod normal_form;
 
+pub use conjunctive::{TinyUnassignedCnf, TinyUnassignedCnfClause};
 pub use disjunctive::{TinyUnassignedDnf, TinyUnassignedDnfClause};
+pub use normal_form::{TinyUnassignedNormalForm, TinyUnassignedNormalFormClause};
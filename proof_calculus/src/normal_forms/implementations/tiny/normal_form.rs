@@ -0,0 +1,299 @@
+use std::{cmp::Ordering, marker::PhantomData, ops::Deref};
+
+use crate::{
+    normal_forms::{
+        NormalFormKind,
+        clauses::{
+            ComplementaryProposition, NormalFormClause, distribute, remove_complementary,
+            remove_subsumed,
+        },
+    },
+    propositions::types::unassigned::UnassignedProposition,
+    utils::{
+        collections::sets::tiny_immutable_ordered_set::TinyImmutableOrderedSet,
+        traits::fast_ord::FastOrd,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// A normal form of unassigned propositions, which is conjunctive or disjunctive depending on its kind
+pub struct TinyUnassignedNormalForm<UP: UnassignedProposition + FastOrd, Kind: NormalFormKind>(
+    TinyImmutableOrderedSet<TinyUnassignedNormalFormClause<UP, Kind>>,
+);
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> TinyUnassignedNormalForm<P, Kind> {
+    pub fn new(clauses: Box<[TinyUnassignedNormalFormClause<P, Kind>]>) -> Self {
+        Self(TinyImmutableOrderedSet::from_iter(clauses))
+    }
+    pub fn get_clauses(
+        &self,
+    ) -> &impl IntoIterator<Item = TinyUnassignedNormalFormClause<P, Kind>> {
+        &self.0
+    }
+
+    /// Remove every clause which contains another clause
+    /// In a CNF such a clause is implied by the clause it contains, and in a DNF it implies it
+    pub fn remove_subsumed(&self) -> Self {
+        remove_subsumed(self.0.iter().cloned())
+            .into_iter()
+            .collect()
+    }
+    /// Remove every clause which contains a proposition alongside its complement
+    /// In a CNF such clauses always hold, and in a DNF they never do
+    pub fn remove_complementary(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        remove_complementary(self.0.iter().cloned())
+            .into_iter()
+            .collect()
+    }
+    pub fn simplify(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        self.remove_complementary().remove_subsumed()
+    }
+    /// Convert to the equivalent normal form of the dual kind by distributing the clauses over each other
+    /// This may produce exponentially many clauses
+    pub fn to_dual(&self) -> TinyUnassignedNormalForm<P, Kind::Dual> {
+        distribute::<_, _, TinyUnassignedNormalFormClause<P, Kind::Dual>>(self.0.iter())
+            .into_iter()
+            .collect()
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind>
+    FromIterator<TinyUnassignedNormalFormClause<P, Kind>> for TinyUnassignedNormalForm<P, Kind>
+{
+    fn from_iter<I: IntoIterator<Item = TinyUnassignedNormalFormClause<P, Kind>>>(
+        clauses: I,
+    ) -> Self {
+        Self(TinyImmutableOrderedSet::from_iter(clauses))
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> IntoIterator
+    for TinyUnassignedNormalForm<P, Kind>
+{
+    type Item = TinyUnassignedNormalFormClause<P, Kind>;
+    type IntoIter = std::vec::IntoIter<TinyUnassignedNormalFormClause<P, Kind>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> Deref
+    for TinyUnassignedNormalForm<P, Kind>
+{
+    type Target = TinyImmutableOrderedSet<TinyUnassignedNormalFormClause<P, Kind>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> FastOrd
+    for TinyUnassignedNormalForm<P, Kind>
+{
+    #[inline]
+    fn fast_cmp(&self, other: &Self) -> Ordering {
+        self.0.fast_cmp(&other.0)
+    }
+}
+
+#[derive(Clone, Hash, Debug, PartialEq, Eq)]
+/// A clause of a [TinyUnassignedNormalForm], which is disjunctive in a CNF and conjunctive in a DNF
+pub struct TinyUnassignedNormalFormClause<P: UnassignedProposition + FastOrd, Kind: NormalFormKind>(
+    TinyImmutableOrderedSet<P>,
+    PhantomData<Kind>,
+);
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind>
+    TinyUnassignedNormalFormClause<P, Kind>
+{
+    pub fn new(propositions: Box<[P]>) -> Self {
+        Self::from_iter(propositions)
+    }
+    pub fn get_propositions(&self) -> &impl IntoIterator<Item = P> {
+        &self.0
+    }
+    pub fn into_propositions(self) -> impl IntoIterator<Item = P> {
+        self.0
+    }
+
+    pub fn contains(&self, proposition: &P) -> bool {
+        self.0.contains(proposition)
+    }
+    pub fn len(&self) -> usize {
+        self.0.count()
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> NormalFormClause<P>
+    for TinyUnassignedNormalFormClause<P, Kind>
+{
+    fn literals<'a>(&'a self) -> impl Iterator<Item = &'a P>
+    where
+        P: 'a,
+    {
+        self.0.iter()
+    }
+    fn contains_literal(&self, literal: &P) -> bool {
+        self.0.contains(literal)
+    }
+    fn literal_count(&self) -> usize {
+        self.0.count()
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> FromIterator<P>
+    for TinyUnassignedNormalFormClause<P, Kind>
+{
+    fn from_iter<I: IntoIterator<Item = P>>(propositions: I) -> Self {
+        Self(TinyImmutableOrderedSet::from_iter(propositions), PhantomData)
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> IntoIterator
+    for TinyUnassignedNormalFormClause<P, Kind>
+{
+    type Item = P;
+    type IntoIter = std::vec::IntoIter<P>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> Deref
+    for TinyUnassignedNormalFormClause<P, Kind>
+{
+    type Target = TinyImmutableOrderedSet<P>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<P: UnassignedProposition + FastOrd, Kind: NormalFormKind> FastOrd
+    for TinyUnassignedNormalFormClause<P, Kind>
+{
+    #[inline]
+    fn fast_cmp(&self, other: &Self) -> Ordering {
+        self.0.fast_cmp(&other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        normal_forms::implementations::tiny::{TinyUnassignedCnf, TinyUnassignedDnf},
+        propositions::types::normalised_unassigned::NormalisedUnassignedProposition,
+    };
+
+    /// An atom numbered by its absolute value, which is negated if it is negative
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    struct Literal(i32);
+    impl UnassignedProposition for Literal {
+        type DefaultNormalisation = Literal;
+
+        fn normalise(self) -> Self::DefaultNormalisation {
+            self
+        }
+    }
+    impl NormalisedUnassignedProposition for Literal {
+        type Inner = Literal;
+
+        fn inner(&self) -> &Self::Inner {
+            self
+        }
+        fn into_inner(self) -> Self::Inner {
+            self
+        }
+    }
+    impl FastOrd for Literal {
+        fn fast_cmp(&self, other: &Self) -> Ordering {
+            self.0.fast_cmp(&other.0)
+        }
+    }
+    impl ComplementaryProposition for Literal {
+        fn is_complement_of(&self, other: &Self) -> bool {
+            self.0 == -other.0
+        }
+    }
+    impl Literal {
+        /// Whether this literal holds when each atom is true exactly if its bit is set
+        fn holds(&self, bits: u32) -> bool {
+            (bits >> self.0.unsigned_abs() & 1 == 1) == (self.0 > 0)
+        }
+    }
+
+    fn form<Kind: NormalFormKind>(clauses: &[&[i32]]) -> TinyUnassignedNormalForm<Literal, Kind> {
+        clauses
+            .iter()
+            .map(|clause| clause.iter().map(|literal| Literal(*literal)).collect())
+            .collect()
+    }
+    fn cnf_holds(cnf: &TinyUnassignedCnf<Literal>, bits: u32) -> bool {
+        cnf.iter()
+            .all(|clause| clause.iter().any(|literal| literal.holds(bits)))
+    }
+    fn dnf_holds(dnf: &TinyUnassignedDnf<Literal>, bits: u32) -> bool {
+        dnf.iter()
+            .any(|clause| clause.iter().all(|literal| literal.holds(bits)))
+    }
+    /// Every assignment of the atoms 1 to 3
+    fn assignments() -> impl Iterator<Item = u32> {
+        (0..8).map(|bits| bits << 1)
+    }
+
+    #[test]
+    fn test_simplify() {
+        let cnf: TinyUnassignedCnf<Literal> = form(&[&[1, -1, 3], &[2, 3], &[2], &[2]]);
+        assert_eq!(cnf.simplify(), form(&[&[2]]));
+        for bits in assignments() {
+            assert_eq!(cnf_holds(&cnf.simplify(), bits), cnf_holds(&cnf, bits));
+        }
+    }
+
+    #[test]
+    fn test_empty_forms() {
+        // An empty CNF always holds, and an empty DNF never does
+        let cnf: TinyUnassignedCnf<Literal> = form(&[]);
+        let dnf: TinyUnassignedDnf<Literal> = form(&[]);
+        assert_eq!(cnf.simplify(), cnf);
+        assert_eq!(dnf.simplify(), dnf);
+        assert_eq!(cnf.to_dual(), form(&[&[]]));
+        assert_eq!(dnf.to_dual(), form(&[&[]]));
+        for bits in assignments() {
+            assert!(cnf_holds(&cnf, bits));
+            assert!(dnf_holds(&cnf.to_dual(), bits));
+            assert!(!dnf_holds(&dnf, bits));
+            assert!(!cnf_holds(&dnf.to_dual(), bits));
+        }
+    }
+
+    #[test]
+    fn test_empty_clause() {
+        // An empty clause never holds in a CNF, and always holds in a DNF, so it subsumes every other clause
+        let cnf: TinyUnassignedCnf<Literal> = form(&[&[], &[1, 2]]);
+        let dnf: TinyUnassignedDnf<Literal> = form(&[&[], &[1, 2]]);
+        assert_eq!(cnf.simplify(), form(&[&[]]));
+        assert_eq!(dnf.simplify(), form(&[&[]]));
+        assert_eq!(cnf.to_dual(), form(&[]));
+        assert_eq!(dnf.to_dual(), form(&[]));
+        for bits in assignments() {
+            assert!(!cnf_holds(&cnf, bits));
+            assert!(dnf_holds(&dnf, bits));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // Without complements, the clauses of a simplified form are recovered exactly
+        let cnf: TinyUnassignedCnf<Literal> = form(&[&[1, 2], &[3]]);
+        assert_eq!(cnf.to_dual(), form(&[&[1, 3], &[2, 3]]));
+        assert_eq!(cnf.to_dual().to_dual(), cnf);
+
+        // With them, the round trip is only equivalent
+        let cnf: TinyUnassignedCnf<Literal> = form(&[&[1, 2], &[-1, 3], &[2, -3]]);
+        let dnf = cnf.to_dual().simplify();
+        let round_trip = dnf.to_dual().simplify();
+        for bits in assignments() {
+            assert_eq!(dnf_holds(&dnf, bits), cnf_holds(&cnf, bits));
+            assert_eq!(cnf_holds(&round_trip, bits), cnf_holds(&cnf, bits));
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use std::{fmt::Debug, hash::Hash};
+
 use crate::propositions::types::unassigned::UnassignedProposition;
 
+pub mod clauses;
 pub mod implementations;
 
 pub trait UnassignedDnf: IntoIterator<Item = Self::UnassignedClause> {
@@ -15,3 +18,21 @@ pub trait UnassignedCnf: IntoIterator<Item = Self::UnassignedClause> {
 pub trait UnassignedCnfClause: IntoIterator<Item = Self::UnassignedProposition> {
     type UnassignedProposition: UnassignedProposition;
 }
+
+/// Whether a normal form is a conjunction of disjunctive clauses or a disjunction of conjunctive clauses
+/// Both kinds share their structure, and distributing the clauses of either gives the other, which is its dual
+pub trait NormalFormKind: Clone + PartialEq + Eq + Hash + Debug {
+    type Dual: NormalFormKind<Dual = Self>;
+}
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// The kind of a conjunctive normal form
+pub struct Conjunctive;
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// The kind of a disjunctive normal form
+pub struct Disjunctive;
+impl NormalFormKind for Conjunctive {
+    type Dual = Disjunctive;
+}
+impl NormalFormKind for Disjunctive {
+    type Dual = Conjunctive;
+}
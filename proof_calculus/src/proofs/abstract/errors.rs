@@ -0,0 +1,364 @@
+use std::collections::{HashMap, HashSet};
+
+use path_lib::obj_at_path::OwnedObjAtPath;
+
+use crate::{
+    proofs::{
+        r#abstract::{AbstractProof, InferenceInAbstractProofPath, valid::ValidAbstractProof},
+        errors::ValidatableInferenceRule,
+        inferences::{Inference, verifiable::ValidInference},
+    },
+    propositions::types::assigned::{
+        Proposition, collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbstractProofValidityError<P: Proposition, InferenceErr> {
+    /// The inference is not a valid instance of its [InferenceRule](crate::proofs::inferences::InferenceRule)
+    InvalidInference(InferenceErr),
+    /// The inference is part of a loop of inferences which justify one another. Contains the conclusion identifying each inference within that loop
+    CircularJustification(Box<[P]>),
+    /// The inference is not part of a loop, but some of its assumptions can only be proven by an inference which is
+    UnfoundedAssumptions(HashPropSet1O<P>),
+}
+
+pub type OwnedAbstractProofValidityErrorAtPath<P, InferenceErr> =
+    OwnedObjAtPath<AbstractProofValidityError<P, InferenceErr>, InferenceInAbstractProofPath<P>>;
+
+/// Verify that every inference within the provided [AbstractProof] is valid, and that every conclusion can be reached from the proof's [base assumptions](AbstractProof::base_assumptions) without circular justification
+pub fn verify_abstract_proof_validity<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    proof: &AbstractProof<P, Rule>,
+) -> Result<(), OwnedAbstractProofValidityErrorAtPath<P, Rule::Err>> {
+    match get_abstract_proof_validity_errors(proof).next() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+pub fn get_abstract_proof_validity_errors<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    proof: &AbstractProof<P, Rule>,
+) -> impl Iterator<Item = OwnedAbstractProofValidityErrorAtPath<P, Rule::Err>> {
+    let inference_errors = proof.inferences().filter_map(|inference| {
+        Rule::validate(inference).err().map(|err| OwnedObjAtPath {
+            obj: AbstractProofValidityError::InvalidInference(err),
+            path: InferenceInAbstractProofPath::of(inference),
+        })
+    });
+    inference_errors.chain(get_abstract_proof_foundation_errors(proof))
+}
+
+/// Get an error for every inference within the provided [AbstractProof] which cannot be reached from the proof's [base assumptions](AbstractProof::base_assumptions)
+/// This does not check whether the inferences themselves are valid
+pub fn get_abstract_proof_foundation_errors<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    proof: &AbstractProof<P, Rule>,
+) -> impl Iterator<Item = OwnedAbstractProofValidityErrorAtPath<P, Rule::Err>> {
+    let unfounded = get_unfounded_inferences(proof);
+    let cycles = get_circular_components(&unfounded);
+    let unfounded_conclusions: HashSet<&P> = unfounded
+        .iter()
+        .flat_map(|inference| inference.conclusions.iter())
+        .collect();
+
+    let mut errors = Vec::new();
+    for (index, inference) in unfounded.iter().enumerate() {
+        let error = match cycles.get(&index) {
+            Some(cycle) => AbstractProofValidityError::CircularJustification(cycle.clone()),
+            None => AbstractProofValidityError::UnfoundedAssumptions(
+                inference
+                    .assumptions
+                    .iter()
+                    .filter(|assumption| unfounded_conclusions.contains(assumption))
+                    .cloned()
+                    .collect(),
+            ),
+        };
+        errors.push(OwnedObjAtPath {
+            obj: error,
+            path: InferenceInAbstractProofPath::of(inference),
+        });
+    }
+    errors.into_iter()
+}
+
+/// Get every inference within the provided [AbstractProof] whose assumptions cannot all be proven from the proof's [base assumptions](AbstractProof::base_assumptions)
+fn get_unfounded_inferences<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    proof: &AbstractProof<P, Rule>,
+) -> Vec<&Inference<P, Rule>> {
+    // For each inference, count how many of the (distinct) inferences proving its assumptions have not yet been founded
+    let mut remaining: HashMap<usize, usize> = HashMap::with_capacity(proof.len());
+    let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut founded = Vec::new();
+    for (index, inference) in proof.slotted_inferences() {
+        let dependencies: HashSet<usize> = inference
+            .assumptions
+            .iter()
+            .filter_map(|assumption| proof.slot_concluding(assumption))
+            .collect();
+        if dependencies.is_empty() {
+            founded.push(index);
+        }
+        remaining.insert(index, dependencies.len());
+        for dependency in dependencies {
+            dependents.entry(dependency).or_default().push(index);
+        }
+    }
+    // Found inferences outwards from the base assumptions
+    while let Some(index) = founded.pop() {
+        remaining.remove(&index);
+        for dependent in dependents.get(&index).into_iter().flatten() {
+            if let Some(count) = remaining.get_mut(dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    founded.push(*dependent);
+                }
+            }
+        }
+    }
+    // Any inference which could not be founded is left over
+    let mut unfounded: Vec<usize> = remaining.into_keys().collect();
+    unfounded.sort_unstable();
+    unfounded
+        .into_iter()
+        .filter_map(|index| proof.get_slot(index))
+        .collect()
+}
+
+/// Find the loops of justification among some inferences, using Tarjan's strongly connected components algorithm
+/// Returns a map from the index of each inference within a loop, to the conclusion identifying each inference within that loop
+fn get_circular_components<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    inferences: &[&Inference<P, Rule>],
+) -> HashMap<usize, Box<[P]>> {
+    let indices: HashMap<&P, usize> = inferences
+        .iter()
+        .enumerate()
+        .flat_map(|(index, inference)| {
+            inference
+                .conclusions
+                .iter()
+                .map(move |conclusion| (conclusion, index))
+        })
+        .collect();
+    let edges: Vec<Vec<usize>> = inferences
+        .iter()
+        .map(|inference| {
+            inference
+                .assumptions
+                .iter()
+                .filter_map(|assumption| indices.get(assumption).copied())
+                .collect()
+        })
+        .collect();
+
+    let mut next_order = 0;
+    let mut order: Vec<Option<usize>> = vec![None; inferences.len()];
+    let mut low_link: Vec<usize> = vec![0; inferences.len()];
+    let mut on_stack: Vec<bool> = vec![false; inferences.len()];
+    let mut component_stack: Vec<usize> = Vec::new();
+    let mut cycles = HashMap::new();
+
+    for root in 0..inferences.len() {
+        if order[root].is_some() {
+            continue;
+        }
+        // Each frame holds a node being visited, alongside the index of the next edge to follow
+        let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+        while let Some((node, next_edge)) = call_stack.last_mut() {
+            let node = *node;
+            if *next_edge == 0 && order[node].is_none() {
+                order[node] = Some(next_order);
+                low_link[node] = next_order;
+                next_order += 1;
+                component_stack.push(node);
+                on_stack[node] = true;
+            }
+            if let Some(&target) = edges[node].get(*next_edge) {
+                *next_edge += 1;
+                match order[target] {
+                    None => call_stack.push((target, 0)),
+                    Some(target_order) if on_stack[target] => {
+                        low_link[node] = low_link[node].min(target_order)
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+            // All edges have been followed, so return from this node
+            call_stack.pop();
+            if let Some((parent, _)) = call_stack.last() {
+                low_link[*parent] = low_link[*parent].min(low_link[node]);
+            }
+            if Some(low_link[node]) == order[node] {
+                let mut component = Vec::new();
+                while let Some(member) = component_stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                // A lone inference is only circular if it assumes its own conclusion
+                if component.len() > 1 || edges[node].contains(&node) {
+                    let conclusions: Box<[P]> = component
+                        .iter()
+                        .map(|member| InferenceInAbstractProofPath::of(inferences[*member]).0)
+                        .collect();
+                    for member in component {
+                        cycles.insert(member, conclusions.clone());
+                    }
+                }
+            }
+        }
+    }
+    cycles
+}
+
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>> TryFrom<AbstractProof<P, Rule>>
+    for ValidAbstractProof<P, Rule>
+{
+    type Error = Box<[OwnedAbstractProofValidityErrorAtPath<P, Rule::Err>]>;
+
+    /// Check the validity and well-foundedness of an [AbstractProof], converting it into a [ValidAbstractProof] if no errors are found
+    fn try_from(proof: AbstractProof<P, Rule>) -> Result<Self, Self::Error> {
+        let foundation_errors = get_abstract_proof_foundation_errors(&proof).collect::<Vec<_>>();
+        let mut valid_inferences = Vec::with_capacity(proof.len());
+        let mut errors = Vec::new();
+        for inference in proof.slots.into_iter().flatten() {
+            let path = InferenceInAbstractProofPath::of(&inference);
+            match ValidInference::try_from(inference) {
+                Ok(valid) => valid_inferences.push(valid),
+                Err(err) => errors.push(OwnedObjAtPath {
+                    obj: AbstractProofValidityError::InvalidInference(err),
+                    path,
+                }),
+            }
+        }
+        errors.extend(foundation_errors);
+        if errors.is_empty() {
+            Ok(valid_inferences.into_iter().collect())
+        } else {
+            Err(errors.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{TestProp, TestRule, inference, props};
+
+    fn get_errors(
+        proof: &AbstractProof<TestProp, TestRule>,
+    ) -> Vec<(TestProp, AbstractProofValidityError<TestProp, TestProp>)> {
+        let mut errors: Vec<_> = get_abstract_proof_validity_errors(proof)
+            .map(|error| (error.path.0, error.obj))
+            .collect();
+        errors.sort_by(|a, b| a.0.cmp(&b.0));
+        errors
+    }
+    fn sorted(
+        error: AbstractProofValidityError<TestProp, TestProp>,
+    ) -> AbstractProofValidityError<TestProp, TestProp> {
+        match error {
+            AbstractProofValidityError::CircularJustification(cycle) => {
+                let mut cycle = cycle.into_vec();
+                cycle.sort();
+                AbstractProofValidityError::CircularJustification(cycle.into())
+            }
+            error => error,
+        }
+    }
+
+    #[test]
+    fn test_valid_proof() {
+        let proof = AbstractProof::from_iter([
+            inference(TestRule::Valid, &[1], 2),
+            inference(TestRule::Valid, &[2, 1], 3),
+        ]);
+        assert_eq!(get_errors(&proof), vec![]);
+        assert!(verify_abstract_proof_validity(&proof).is_ok());
+        assert!(ValidAbstractProof::try_from(proof).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_inference() {
+        let proof = AbstractProof::from_iter([
+            inference(TestRule::Valid, &[1], 2),
+            inference(TestRule::Invalid, &[2], 3),
+        ]);
+        assert_eq!(
+            get_errors(&proof),
+            vec![(
+                TestProp(3),
+                AbstractProofValidityError::InvalidInference(TestProp(3))
+            )]
+        );
+        assert!(matches!(ValidAbstractProof::try_from(proof), Err(errors) if errors.len() == 1));
+    }
+
+    #[test]
+    fn test_cycle() {
+        // 2 and 3 justify one another, 4 depends on the cycle, and 5 assumes itself
+        let proof = AbstractProof::from_iter([
+            inference(TestRule::Valid, &[1, 3], 2),
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[3, 1], 4),
+            inference(TestRule::Valid, &[5], 5),
+            inference(TestRule::Valid, &[1], 6),
+        ]);
+        let errors: Vec<_> = get_errors(&proof)
+            .into_iter()
+            .map(|(path, error)| (path, sorted(error)))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    TestProp(2),
+                    AbstractProofValidityError::CircularJustification(props(&[2, 3]))
+                ),
+                (
+                    TestProp(3),
+                    AbstractProofValidityError::CircularJustification(props(&[2, 3]))
+                ),
+                (
+                    TestProp(4),
+                    AbstractProofValidityError::UnfoundedAssumptions(HashPropSet1O::from_iter([
+                        TestProp(3)
+                    ]))
+                ),
+                (
+                    TestProp(5),
+                    AbstractProofValidityError::CircularJustification(props(&[5]))
+                ),
+            ]
+        );
+        assert!(matches!(ValidAbstractProof::try_from(proof), Err(errors) if errors.len() == 4));
+    }
+
+    #[test]
+    fn test_separate_cycles() {
+        // Two cycles, where the second depends on the first, must be reported separately
+        let proof = AbstractProof::from_iter([
+            inference(TestRule::Valid, &[3], 2),
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[3, 5], 4),
+            inference(TestRule::Valid, &[4], 5),
+        ]);
+        let errors: Vec<_> = get_errors(&proof)
+            .into_iter()
+            .map(|(path, error)| (path, sorted(error)))
+            .collect();
+        let first = AbstractProofValidityError::CircularJustification(props(&[2, 3]));
+        let second = AbstractProofValidityError::CircularJustification(props(&[4, 5]));
+        assert_eq!(
+            errors,
+            vec![
+                (TestProp(2), first.clone()),
+                (TestProp(3), first),
+                (TestProp(4), second.clone()),
+                (TestProp(5), second),
+            ]
+        );
+    }
+}
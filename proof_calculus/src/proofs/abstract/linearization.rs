@@ -0,0 +1,285 @@
+use std::collections::HashSet;
+
+use crate::{
+    proofs::{
+        r#abstract::{AbstractProof, InferenceInAbstractProofPath},
+        inferences::{Inference, InferenceRule},
+        sequential::{SequentialProof, composite::CompositeSequentialProof},
+    },
+    propositions::types::assigned::Proposition,
+    utils::traits::try_from_iter::TryFromIterator,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbstractProofLinearizationError<P: Proposition> {
+    /// Two distinct inferences were provided which both conclude the contained [Proposition]
+    DuplicatedConclusion(P),
+    /// An inference was provided which has no conclusions
+    NoConclusions,
+    /// The inferences identified by the contained conclusions justify one another in a loop; each one proves an assumption of the next, and the last proves an assumption of the first
+    DependencyCycle(Box<[P]>),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VisitState {
+    Visiting,
+    Visited,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> AbstractProof<P, Rule> {
+    /// Insert an inference into this proof, returning an error (and leaving the proof unchanged) if a different inference shares a conclusion with it
+    pub fn try_insert(
+        &mut self,
+        inference: Inference<P, Rule>,
+    ) -> Result<(), AbstractProofLinearizationError<P>> {
+        if inference.conclusions.is_empty() {
+            return Err(AbstractProofLinearizationError::NoConclusions);
+        }
+        for conclusion in &inference.conclusions {
+            match self.get_inference_concluding(conclusion) {
+                Some(existing) if existing == &inference => return Ok(()),
+                Some(_) => {
+                    return Err(AbstractProofLinearizationError::DuplicatedConclusion(
+                        conclusion.clone(),
+                    ));
+                }
+                None => {}
+            }
+        }
+        self.insert(inference);
+        Ok(())
+    }
+
+    /// Order the inferences within this proof such that every inference comes after the inferences which prove its assumptions
+    /// Ties are broken by the order in which the inferences were inserted, so the result does not vary between runs
+    pub fn get_linearized_inferences(
+        &self,
+    ) -> Result<Vec<&Inference<P, Rule>>, AbstractProofLinearizationError<P>> {
+        let mut states: Vec<Option<VisitState>> = vec![None; self.slot_count()];
+        let mut ordered = Vec::with_capacity(self.len());
+        // The stack holds the index of each inference being visited, alongside the index of the next assumption to visit
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+
+        for (root, _) in self.slotted_inferences() {
+            if states[root].is_some() {
+                continue;
+            }
+            states[root] = Some(VisitState::Visiting);
+            stack.push((root, 0));
+
+            while let Some((index, next_assumption)) = stack.last_mut() {
+                let inference = self
+                    .get_slot(*index)
+                    .expect("Every visited slot holds an inference");
+                let Some(assumption) = inference.assumptions.get(*next_assumption) else {
+                    // All dependencies of this inference have been placed, so it can be placed too
+                    states[*index] = Some(VisitState::Visited);
+                    ordered.push(inference);
+                    stack.pop();
+                    continue;
+                };
+                *next_assumption += 1;
+                // Assumptions which no inference concludes are base assumptions, and need no ordering
+                let Some(dependency) = self.slot_concluding(assumption) else {
+                    continue;
+                };
+                match states[dependency] {
+                    Some(VisitState::Visited) => {}
+                    Some(VisitState::Visiting) => {
+                        let cycle_start = stack
+                            .iter()
+                            .position(|(i, _)| *i == dependency)
+                            .expect("Every inference marked as visiting must be on the stack");
+                        return Err(AbstractProofLinearizationError::DependencyCycle(
+                            stack[cycle_start..]
+                                .iter()
+                                .map(|(i, _)| {
+                                    InferenceInAbstractProofPath::of(
+                                        self.get_slot(*i)
+                                            .expect("Every visited slot holds an inference"),
+                                    )
+                                    .0
+                                })
+                                .collect(),
+                        ));
+                    }
+                    None => {
+                        states[dependency] = Some(VisitState::Visiting);
+                        stack.push((dependency, 0));
+                    }
+                }
+            }
+        }
+        Ok(ordered)
+    }
+
+    /// Convert this proof into a [CompositeSequentialProof] which assumes [base_assumptions](AbstractProof::base_assumptions) and explicitly concludes [final_conclusions](AbstractProof::final_conclusions)
+    /// The resulting proof contains each inference exactly once, in an order where every inference follows the inferences it depends upon
+    pub fn linearize(
+        &self,
+    ) -> Result<CompositeSequentialProof<P, Rule>, AbstractProofLinearizationError<P>> {
+        let subproofs = self
+            .get_linearized_inferences()?
+            .into_iter()
+            .map(|inference| SequentialProof::Inference(inference.clone()))
+            .collect();
+        Ok(CompositeSequentialProof {
+            assumptions: self
+                .ordered_base_assumptions()
+                .into_iter()
+                .cloned()
+                .collect(),
+            subproofs,
+            explicit_conclusions: self
+                .ordered_final_conclusions()
+                .into_iter()
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Get the [base_assumptions](AbstractProof::base_assumptions) in the order they first appear among the inferences, so that linearizing the same proof always gives the same result
+    fn ordered_base_assumptions(&self) -> Vec<&P> {
+        let mut seen = HashSet::new();
+        self.inferences()
+            .flat_map(|inference| inference.assumptions.iter())
+            .filter(|assumption| {
+                !self.concluded_by.contains_key(*assumption) && seen.insert(*assumption)
+            })
+            .collect()
+    }
+    /// Get the [final_conclusions](AbstractProof::final_conclusions) in the order they first appear among the inferences
+    fn ordered_final_conclusions(&self) -> Vec<&P> {
+        let assumptions = self.assumptions();
+        let mut seen = HashSet::new();
+        self.inferences()
+            .flat_map(|inference| inference.conclusions.iter())
+            .filter(|conclusion| !assumptions.contains(*conclusion) && seen.insert(*conclusion))
+            .collect()
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> TryFromIterator<Inference<P, Rule>>
+    for AbstractProof<P, Rule>
+{
+    type Error = AbstractProofLinearizationError<P>;
+
+    fn try_from_iter<T>(iter: T) -> Result<Self, Self::Error>
+    where
+        T: Iterator<Item = Inference<P, Rule>>,
+    {
+        let mut proof = Self::default();
+        for inference in iter {
+            proof.try_insert(inference)?;
+        }
+        Ok(proof)
+    }
+}
+
+/// Collect some inferences, provided in any order, into a [CompositeSequentialProof]
+/// Once wrapped in [SequentialProof::Composite], the result can be checked with [verify_proof_validity](crate::proofs::errors::verify_proof_validity)
+pub fn linearize_inferences<P: Proposition, Rule: InferenceRule<P>>(
+    inferences: impl IntoIterator<Item = Inference<P, Rule>>,
+) -> Result<CompositeSequentialProof<P, Rule>, AbstractProofLinearizationError<P>> {
+    AbstractProof::try_from_iter(inferences.into_iter())?.linearize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proofs::errors::verify_proof_validity,
+        test_helpers::{TestProp, TestRule, composite, inference, inference_step},
+    };
+
+    #[test]
+    fn test_linearize_orders_dependencies_first() {
+        let linearized = linearize_inferences([
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[1], 2),
+            inference(TestRule::Valid, &[3, 1], 4),
+        ])
+        .unwrap();
+        assert_eq!(
+            linearized,
+            composite(
+                &[1],
+                vec![
+                    inference_step(TestRule::Valid, &[1], 2),
+                    inference_step(TestRule::Valid, &[2], 3),
+                    inference_step(TestRule::Valid, &[3, 1], 4),
+                ],
+                &[4]
+            )
+        );
+        verify_proof_validity(&SequentialProof::Composite(linearized)).unwrap();
+    }
+
+    #[test]
+    fn test_linearize_round_trip() {
+        let inferences = [
+            inference(TestRule::Valid, &[1], 2),
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[3, 1], 4),
+        ];
+        let linearized = linearize_inferences(inferences.clone().into_iter().rev()).unwrap();
+        let relinearized =
+            linearize_inferences(linearized.subproofs.iter().map(|subproof| match subproof {
+                SequentialProof::Inference(inference) => inference.clone(),
+                _ => panic!("Linearization only produces inferences"),
+            }))
+            .unwrap();
+        assert_eq!(relinearized, linearized);
+        assert_eq!(
+            AbstractProof::from_iter(inferences.clone()).linearize(),
+            Ok(linearized)
+        );
+    }
+
+    #[test]
+    fn test_linearize_cycle() {
+        let Err(AbstractProofLinearizationError::DependencyCycle(cycle)) = linearize_inferences([
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[3], 2),
+            inference(TestRule::Valid, &[3, 1], 4),
+        ]) else {
+            panic!("Expected a dependency cycle");
+        };
+        let mut cycle = cycle.into_vec();
+        cycle.sort();
+        assert_eq!(cycle, vec![TestProp(2), TestProp(3)]);
+    }
+
+    #[test]
+    fn test_linearize_duplicated_conclusion() {
+        assert_eq!(
+            linearize_inferences([
+                inference(TestRule::Valid, &[2], 3),
+                inference(TestRule::Valid, &[1], 3),
+            ]),
+            Err(AbstractProofLinearizationError::DuplicatedConclusion(
+                TestProp(3)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_remove_keeps_remaining_slots() {
+        let mut proof = AbstractProof::from_iter([
+            inference(TestRule::Valid, &[1], 2),
+            inference(TestRule::Valid, &[2], 3),
+            inference(TestRule::Valid, &[3], 4),
+        ]);
+        let displaced = proof.insert(inference(TestRule::Valid, &[5], 2));
+        assert_eq!(displaced, vec![inference(TestRule::Valid, &[1], 2)]);
+        assert_eq!(proof.len(), 3);
+        assert_eq!(
+            proof.get_inference_concluding(&TestProp(4)),
+            Some(&inference(TestRule::Valid, &[3], 4))
+        );
+        assert_eq!(
+            proof.linearize().unwrap().assumptions,
+            Box::from([TestProp(5)])
+        );
+    }
+}
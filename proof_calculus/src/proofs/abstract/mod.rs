@@ -7,22 +7,108 @@ use crate::{
     propositions::types::assigned::Proposition,
 };
 
+// Feature: verification
+pub mod errors;
+pub mod linearization;
+
+/// A collection of inferences, kept in the order they were inserted, each of which may depend upon the conclusions of others
+/// No two inferences within an [AbstractProof] share a conclusion, so every inference can be identified by any one of its conclusions
 pub struct AbstractProof<P: Proposition, Rule: InferenceRule<P>> {
-    inferences: HashMap<P, Inference<P, Rule>>,
+    /// The inferences in the order they were inserted, where a removed inference leaves an empty slot so that the slots of later inferences do not change
+    slots: Vec<Option<Inference<P, Rule>>>,
+    concluded_by: HashMap<P, usize>,
+    len: usize,
 }
 impl<P: Proposition, Rule: InferenceRule<P>> AbstractProof<P, Rule> {
-    pub fn insert(&mut self, inference: Inference<P, Rule>) -> Option<Inference<P, Rule>> {
-        self.inferences
-            .insert(inference.conclusion.clone(), inference)
+    /// Insert an inference into this proof, removing and returning any inferences which share a conclusion with it
+    /// An inference without conclusions contributes nothing to an [AbstractProof], so it is not inserted
+    pub fn insert(&mut self, inference: Inference<P, Rule>) -> Vec<Inference<P, Rule>> {
+        if inference.conclusions.is_empty() {
+            return vec![];
+        }
+        let mut displaced: Vec<usize> = inference
+            .conclusions
+            .iter()
+            .filter_map(|conclusion| self.concluded_by.get(conclusion).copied())
+            .collect();
+        displaced.sort_unstable();
+        displaced.dedup();
+        let removed = displaced
+            .into_iter()
+            .map(|slot| self.remove_at(slot))
+            .collect();
+        self.compact_if_sparse();
+        let slot = self.slots.len();
+        for conclusion in &inference.conclusions {
+            self.concluded_by.insert(conclusion.clone(), slot);
+        }
+        self.slots.push(Some(inference));
+        self.len += 1;
+        removed
     }
-    pub fn inner_map(&self) -> &HashMap<P, Inference<P, Rule>> {
-        &self.inferences
+    fn remove_at(&mut self, slot: usize) -> Inference<P, Rule> {
+        let removed = self.slots[slot]
+            .take()
+            .expect("Every slot referred to by a conclusion holds an inference");
+        for conclusion in &removed.conclusions {
+            self.concluded_by.remove(conclusion);
+        }
+        self.len -= 1;
+        removed
+    }
+    /// Drop the empty slots once they make up over half of all slots, so that their cost is shared among the removals which left them
+    fn compact_if_sparse(&mut self) {
+        if self.len * 2 >= self.slots.len() {
+            return;
+        }
+        self.slots.retain(Option::is_some);
+        for (slot, inference) in self.slots.iter().enumerate() {
+            for conclusion in &inference
+                .as_ref()
+                .expect("Empty slots were dropped")
+                .conclusions
+            {
+                self.concluded_by.insert(conclusion.clone(), slot);
+            }
+        }
+    }
+
+    /// Get every inference within this proof, in the order they were inserted
+    pub fn inferences(&self) -> impl Iterator<Item = &Inference<P, Rule>> {
+        self.slots.iter().flatten()
+    }
+    /// Get the slot of every inference within this proof, alongside that inference, in the order they were inserted
+    /// Every slot is below [AbstractProof::slot_count], though some slots below it may be empty
+    pub(crate) fn slotted_inferences(&self) -> impl Iterator<Item = (usize, &Inference<P, Rule>)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, inference)| Some((slot, inference.as_ref()?)))
+    }
+    pub(crate) fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+    pub(crate) fn get_slot(&self, slot: usize) -> Option<&Inference<P, Rule>> {
+        self.slots.get(slot)?.as_ref()
+    }
+    /// Get the inference within this proof which concludes the provided proposition, if there is one
+    pub fn get_inference_concluding(&self, conclusion: &P) -> Option<&Inference<P, Rule>> {
+        self.slot_concluding(conclusion)
+            .and_then(|slot| self.get_slot(slot))
+    }
+    /// Get the slot of the inference which concludes the provided proposition, if there is one
+    pub(crate) fn slot_concluding(&self, conclusion: &P) -> Option<usize> {
+        self.concluded_by.get(conclusion).copied()
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     pub fn assumptions(&self) -> HashSet<&P> {
-        self.inferences
-            .values()
-            .into_iter()
+        self.inferences()
             .map(|i| &i.assumptions)
             .flatten()
             .collect()
@@ -34,7 +120,7 @@ impl<P: Proposition, Rule: InferenceRule<P>> AbstractProof<P, Rule> {
             .collect()
     }
     pub fn conclusions(&self) -> HashSet<&P> {
-        self.inferences.keys().collect()
+        self.concluded_by.keys().collect()
     }
     pub fn final_conclusions(&self) -> HashSet<&P> {
         self.conclusions()
@@ -43,21 +129,42 @@ impl<P: Proposition, Rule: InferenceRule<P>> AbstractProof<P, Rule> {
             .collect()
     }
 }
+impl<P: Proposition, Rule: InferenceRule<P>> Default for AbstractProof<P, Rule> {
+    fn default() -> Self {
+        Self {
+            slots: Default::default(),
+            concluded_by: Default::default(),
+            len: 0,
+        }
+    }
+}
 impl<P: Proposition, Rule: InferenceRule<P>> FromIterator<Inference<P, Rule>>
     for AbstractProof<P, Rule>
 {
     fn from_iter<T: IntoIterator<Item = Inference<P, Rule>>>(iter: T) -> Self {
-        Self {
-            inferences: iter
-                .into_iter()
-                .map(|i| (i.conclusion.clone(), i))
-                .collect(),
+        let mut proof = Self::default();
+        for inference in iter {
+            proof.insert(inference);
         }
+        proof
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Identifies an inference within an [AbstractProof] by one of its conclusions
 pub struct InferenceInAbstractProofPath<P: Proposition>(pub P);
+impl<P: Proposition> InferenceInAbstractProofPath<P> {
+    /// Get the path of the provided inference, which is identified by its first conclusion
+    pub fn of<Rule: InferenceRule<P>>(inference: &Inference<P, Rule>) -> Self {
+        Self(
+            inference
+                .conclusions
+                .first()
+                .expect("Every inference within an AbstractProof has a conclusion")
+                .clone(),
+        )
+    }
+}
 generate_parent_of_children_trait! {
     Inference<P,Rule>, InferenceInAbstractProofPath<P>, (P: Proposition, Rule: InferenceRule<P>),
     "inference", "inferences", "Inferences"
@@ -67,64 +174,65 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfInferences<P, Rule>
     for AbstractProof<P, Rule>
 {
     fn get_inference_paths(&self) -> impl IntoIterator<Item = InferenceInAbstractProofPath<P>> {
-        self.inferences
-            .keys()
-            .map(|x| InferenceInAbstractProofPath(x.clone()))
+        self.inferences()
+            .map(|inference| InferenceInAbstractProofPath::of(inference))
     }
     fn get_inference(
         &self,
         path: &InferenceInAbstractProofPath<P>,
     ) -> Result<&Inference<P, Rule>, ()> {
-        self.inferences.get(&path.0).ok_or(())
+        self.get_inference_concluding(&path.0).ok_or(())
     }
 }
 
 // Feature: verification
 pub mod valid {
-    use std::collections::{HashMap, HashSet};
+    use std::collections::HashSet;
 
     use crate::{
-        proofs::{errors::ValidatableInferenceRule, inferences::verifiable::ValidInference},
+        proofs::{
+            r#abstract::AbstractProof,
+            errors::ValidatableInferenceRule,
+            inferences::{Inference, verifiable::ValidInference},
+        },
         propositions::types::assigned::Proposition,
     };
 
+    /// An [AbstractProof] whose inferences are all known to be valid
     pub struct ValidAbstractProof<P: Proposition, Rule: ValidatableInferenceRule<P>> {
-        inferences: HashMap<P, ValidInference<P, Rule>>,
+        inner: AbstractProof<P, Rule>,
     }
     impl<P: Proposition, Rule: ValidatableInferenceRule<P>> ValidAbstractProof<P, Rule> {
+        /// Insert an inference into this proof, removing and returning any inferences which share a conclusion with it
         pub fn insert(
             &mut self,
             inference: ValidInference<P, Rule>,
-        ) -> Option<ValidInference<P, Rule>> {
-            self.inferences
-                .insert(inference.inner().conclusion.clone(), inference)
+        ) -> Vec<ValidInference<P, Rule>> {
+            self.inner
+                .insert(inference.into())
+                .into_iter()
+                // Every inference within this proof was valid when it was inserted
+                .map(ValidInference::unchecked)
+                .collect()
+        }
+        pub fn inner(&self) -> &AbstractProof<P, Rule> {
+            &self.inner
         }
-        pub fn inner_map(&self) -> &HashMap<P, ValidInference<P, Rule>> {
-            &self.inferences
+        pub fn inferences(&self) -> impl Iterator<Item = &Inference<P, Rule>> {
+            self.inner.inferences()
         }
 
         pub fn assumptions(&self) -> HashSet<&P> {
-            self.inferences
-                .values()
-                .into_iter()
-                .map(|i| &i.inner().assumptions)
-                .flatten()
-                .collect()
+            self.inner.assumptions()
         }
         pub fn base_assumptions(&self) -> HashSet<&P> {
-            self.assumptions()
-                .difference(&self.conclusions())
-                .map(|x| *x)
-                .collect()
+            self.inner.base_assumptions()
         }
         pub fn conclusions(&self) -> HashSet<&P> {
-            self.inferences.keys().collect()
+            self.inner.conclusions()
         }
         pub fn final_conclusions(&self) -> HashSet<&P> {
-            self.conclusions()
-                .difference(&self.assumptions())
-                .map(|x| *x)
-                .collect()
+            self.inner.final_conclusions()
         }
     }
     impl<P: Proposition, Rule: ValidatableInferenceRule<P>> FromIterator<ValidInference<P, Rule>>
@@ -132,10 +240,7 @@ pub mod valid {
     {
         fn from_iter<T: IntoIterator<Item = ValidInference<P, Rule>>>(iter: T) -> Self {
             Self {
-                inferences: iter
-                    .into_iter()
-                    .map(|i| (i.inner().conclusion.clone(), i))
-                    .collect(),
+                inner: iter.into_iter().map(|i| i.into()).collect(),
             }
         }
     }
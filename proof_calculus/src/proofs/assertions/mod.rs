@@ -4,6 +4,7 @@ mod assumption_count;
 mod explicit_conclusion_count;
 
 pub use assumption_count::*;
+pub use explicit_conclusion_count::*;
 
 pub fn as_slice<I: IntoIterator>(into_iter: I) -> Box<[I::Item]> {
     into_iter.into_iter().collect()
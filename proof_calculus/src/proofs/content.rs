@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use crate::{
+    proofs::{
+        inferences::{Inference, InferenceRule},
+        sequential::{
+            SequentialProof,
+            citation::TheoremCitation,
+            composite::CompositeSequentialProof,
+            hypothetical::HypotheticalSequentialProof,
+            subproofs::{
+                SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
+            },
+        },
+    },
+    propositions::types::assigned::Proposition,
+    utils::traits::content_hash::{ContentHash, ContentHasher, ContentId},
+};
+
+// Each kind of step is written with a distinct tag, so that steps of different kinds never share a content ID
+const INFERENCE_TAG: u8 = 0;
+const COMPOSITE_TAG: u8 = 1;
+const CITATION_TAG: u8 = 2;
+const HYPOTHETICAL_TAG: u8 = 3;
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash> ContentHash
+    for Inference<P, Rule>
+{
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.inference_type.content_hash(hasher);
+        self.assumptions.content_hash(hasher);
+        self.conclusions.content_hash(hasher);
+    }
+}
+
+impl<P: Proposition + ContentHash> ContentHash for TheoremCitation<P> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.theorem.content_hash(hasher);
+        self.assumptions.content_hash(hasher);
+        self.conclusions.content_hash(hasher);
+    }
+}
+
+/// A composite is hashed from the content IDs of its steps, so that identical steps share an ID wherever they appear
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash> ContentHash
+    for CompositeSequentialProof<P, Rule>
+{
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.content_hash_helper(
+            hasher,
+            SequentialProofInProofPath(vec![]),
+            &mut |_, _, _| {},
+        );
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash> ContentHash
+    for HypotheticalSequentialProof<P, Rule>
+{
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.content_hash_helper(
+            hasher,
+            SequentialProofInProofPath(vec![]),
+            &mut |_, _, _| {},
+        );
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash> ContentHash
+    for SequentialProof<P, Rule>
+{
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_id(self.content_id());
+    }
+
+    fn content_id(&self) -> ContentId {
+        self.content_id_helper(SequentialProofInProofPath(vec![]), &mut |_, _, _| {})
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash> SequentialProof<P, Rule> {
+    /// Get the content ID of every subproof within this proof, including the proof itself, alongside its path
+    /// Subproofs are listed after the subproofs within them, so the proof itself is listed last
+    pub fn subproof_content_ids(&self) -> Vec<(SequentialProofInProofPath, ContentId)> {
+        let mut ids = Vec::new();
+        self.content_id_helper(SequentialProofInProofPath(vec![]), &mut |_, path, id| {
+            ids.push((path.clone(), id))
+        });
+        ids
+    }
+
+    /// Find the content ID of this proof, passing the content ID of every subproof within it to the provided function as it is found
+    fn content_id_helper<'a>(
+        &'a self,
+        path: SequentialProofInProofPath,
+        found: &mut impl FnMut(&'a Self, &SequentialProofInProofPath, ContentId),
+    ) -> ContentId {
+        let mut hasher = ContentHasher::new();
+        match self {
+            SequentialProof::Inference(inference) => {
+                hasher.write_u8(INFERENCE_TAG);
+                inference.content_hash(&mut hasher);
+            }
+            SequentialProof::Composite(composite) => {
+                hasher.write_u8(COMPOSITE_TAG);
+                composite.content_hash_helper(&mut hasher, path.clone(), found);
+            }
+            SequentialProof::Citation(citation) => {
+                hasher.write_u8(CITATION_TAG);
+                citation.content_hash(&mut hasher);
+            }
+            SequentialProof::Hypothetical(hypothetical) => {
+                hasher.write_u8(HYPOTHETICAL_TAG);
+                hypothetical.content_hash_helper(&mut hasher, path.clone(), found);
+            }
+        }
+        let id = hasher.finish();
+        found(self, &path, id);
+        id
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash>
+    CompositeSequentialProof<P, Rule>
+{
+    fn content_hash_helper<'a>(
+        &'a self,
+        hasher: &mut ContentHasher,
+        path: SequentialProofInProofPath,
+        found: &mut impl FnMut(&'a SequentialProof<P, Rule>, &SequentialProofInProofPath, ContentId),
+    ) {
+        self.assumptions.content_hash(hasher);
+        hasher.write_length(self.subproofs.len());
+        for (index, subproof) in self.subproofs.iter().enumerate() {
+            let subproof_path = (path.clone(), ImmediateSequentialProofInProofPath(index)).into();
+            hasher.write_id(subproof.content_id_helper(subproof_path, found));
+        }
+        self.explicit_conclusions.content_hash(hasher);
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash>
+    HypotheticalSequentialProof<P, Rule>
+{
+    fn content_hash_helper<'a>(
+        &'a self,
+        hasher: &mut ContentHasher,
+        path: SequentialProofInProofPath,
+        found: &mut impl FnMut(&'a SequentialProof<P, Rule>, &SequentialProofInProofPath, ContentId),
+    ) {
+        self.hypotheses.content_hash(hasher);
+        self.body.content_hash_helper(hasher, path, found);
+        self.discharge_rule.content_hash(hasher);
+        self.conclusions.content_hash(hasher);
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Where a subproof appears within the proofs of a [ProofContentIndex]
+pub struct SubproofOccurrence {
+    /// The position of the proof containing the subproof, in the order proofs were inserted into the index
+    pub proof: usize,
+    pub path: SequentialProofInProofPath,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// Two different subproofs share a content ID
+/// Content IDs are not collision-resistant, so a [ProofContentIndex] compares subproofs in full rather than trusting their IDs
+pub struct ContentIdCollisionError<P: Proposition, Rule: InferenceRule<P>> {
+    pub id: ContentId,
+    pub existing: SequentialProof<P, Rule>,
+    pub colliding: SequentialProof<P, Rule>,
+}
+
+/// Records every distinct subproof within a collection of proofs by its [ContentId], so that identical subproofs are only stored once
+#[derive(Clone, Debug)]
+pub struct ProofContentIndex<P: Proposition, Rule: InferenceRule<P>> {
+    subproofs: HashMap<ContentId, SequentialProof<P, Rule>>,
+    occurrences: HashMap<ContentId, Vec<SubproofOccurrence>>,
+    proof_count: usize,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Default for ProofContentIndex<P, Rule> {
+    fn default() -> Self {
+        Self {
+            subproofs: HashMap::new(),
+            occurrences: HashMap::new(),
+            proof_count: 0,
+        }
+    }
+}
+
+impl<P: Proposition + ContentHash, Rule: InferenceRule<P> + ContentHash>
+    ProofContentIndex<P, Rule>
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the provided proof and every subproof within it, returning the proof's content ID
+    /// Returns an error, leaving the index unchanged, if a subproof shares its content ID with a different subproof
+    pub fn insert(
+        &mut self,
+        proof: &SequentialProof<P, Rule>,
+    ) -> Result<ContentId, ContentIdCollisionError<P, Rule>> {
+        let mut found = Vec::new();
+        let proof_id = proof.content_id_helper(
+            SequentialProofInProofPath(vec![]),
+            &mut |subproof, path, id| found.push((subproof, path.clone(), id)),
+        );
+        // Every subproof is checked before any are recorded, including against the other subproofs of this proof
+        let mut seen: HashMap<ContentId, &SequentialProof<P, Rule>> = HashMap::new();
+        for (subproof, _, id) in &found {
+            let existing = self.subproofs.get(id).or_else(|| seen.get(id).copied());
+            match existing {
+                Some(existing) if existing != *subproof => {
+                    return Err(ContentIdCollisionError {
+                        id: *id,
+                        existing: existing.clone(),
+                        colliding: (*subproof).clone(),
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    seen.insert(*id, subproof);
+                }
+            }
+        }
+
+        let index = self.proof_count;
+        self.proof_count += 1;
+        for (subproof, path, id) in found {
+            self.subproofs.entry(id).or_insert_with(|| subproof.clone());
+            self.occurrences
+                .entry(id)
+                .or_default()
+                .push(SubproofOccurrence { proof: index, path });
+        }
+        Ok(proof_id)
+    }
+
+    /// The number of proofs which have been inserted into this index
+    pub fn proof_count(&self) -> usize {
+        self.proof_count
+    }
+    /// The number of distinct subproofs within the proofs of this index
+    pub fn len(&self) -> usize {
+        self.subproofs.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.subproofs.is_empty()
+    }
+
+    pub fn get(&self, id: &ContentId) -> Option<&SequentialProof<P, Rule>> {
+        self.subproofs.get(id)
+    }
+    pub fn contains(&self, id: &ContentId) -> bool {
+        self.subproofs.contains_key(id)
+    }
+
+    /// Get every place where the subproof with the provided content ID appears, in the order they were inserted
+    pub fn occurrences(&self, id: &ContentId) -> &[SubproofOccurrence] {
+        self.occurrences.get(id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get every subproof which appears more than once, alongside the places where it appears
+    pub fn duplicates(
+        &self,
+    ) -> impl Iterator<Item = (&SequentialProof<P, Rule>, &[SubproofOccurrence])> {
+        self.occurrences
+            .iter()
+            .filter(|(_, occurrences)| occurrences.len() > 1)
+            .map(|(id, occurrences)| (&self.subproofs[id], occurrences.as_slice()))
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule, validate_inference,
+            walk::{
+                ProofValidityVisitor, WalkedValidityStepErr, locate_error, walk_proof_validity,
+            },
+        },
+        inferences::{Inference, InferenceRule},
+        sequential::{SequentialProof, subproofs::SequentialProofInProofPath},
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, Proposition, collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+type CachedValidityStepErr<P, IE> = WalkedValidityStepErr<P, IE>;
+
+/// A record of subproofs which have already been found to be internally valid
+/// Verifying a proof through a cache skips any subproof which has been validated before, so only edited parts of a proof need to be checked again
+/// Subproofs are compared in full rather than by hash, so a subproof is only skipped if it is exactly one which was validated before
+#[derive(Clone, Debug)]
+pub struct ProofValidityCache<P: Proposition, Rule: InferenceRule<P>> {
+    /// Each validated subproof, alongside every set of available propositions, limited to those it could make use of, under which it was validated
+    validated: HashMap<SequentialProof<P, Rule>, Vec<HashSet<P>>>,
+}
+impl<P: Proposition, Rule: InferenceRule<P>> Default for ProofValidityCache<P, Rule> {
+    fn default() -> Self {
+        Self {
+            validated: HashMap::new(),
+        }
+    }
+}
+
+/// The outcome of verifying a proof through a [ProofValidityCache]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CachedProofValidityReport<P: Proposition, InferenceErr: Clone> {
+    /// Every error found within the proof, in the same order as by [get_proof_validity_errors](super::get_proof_validity_errors)
+    pub errors: Vec<CachedValidityStepErr<P, InferenceErr>>,
+    /// The path of every subproof which was not found in the cache, and so was checked again
+    pub rechecked: Vec<SequentialProofInProofPath>,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> ProofValidityCache<P, Rule> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of subproofs which this cache records as valid, counting a subproof once for each set of available propositions it was validated under
+    pub fn len(&self) -> usize {
+        self.validated.values().map(Vec::len).sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.validated.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.validated.clear()
+    }
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P> + Eq + Hash> ProofValidityCache<P, Rule> {
+    /// Verify the validity of the provided proof, skipping any subproofs which this cache has already seen to be valid
+    pub fn verify_proof_validity(
+        &mut self,
+        proof: &SequentialProof<P, Rule>,
+    ) -> Result<(), CachedValidityStepErr<P, Rule::Err>> {
+        match self
+            .get_proof_validity_errors(proof)
+            .errors
+            .into_iter()
+            .next()
+        {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Get every validity error within the provided proof, skipping any subproofs which this cache has already seen to be valid
+    /// Every subproof which is found to be valid is added to the cache
+    pub fn get_proof_validity_errors(
+        &mut self,
+        proof: &SequentialProof<P, Rule>,
+    ) -> CachedProofValidityReport<P, Rule::Err> {
+        let mut report = CachedProofValidityReport {
+            errors: Vec::new(),
+            rechecked: Vec::new(),
+        };
+        walk_proof_validity(
+            proof,
+            &mut CachedValidityVisitor {
+                cache: self,
+                report: &mut report,
+                entered: Vec::new(),
+            },
+        );
+        report
+    }
+
+    fn is_validated(&self, proof: &SequentialProof<P, Rule>, available: &HashSet<P>) -> bool {
+        self.validated
+            .get(proof)
+            .is_some_and(|validated| validated.contains(available))
+    }
+}
+
+/// Walks a proof on behalf of a [ProofValidityCache], skipping subproofs it has already validated and recording those it finds valid
+struct CachedValidityVisitor<'a, 'c, P: Proposition, Rule: ValidatableInferenceRule<P>> {
+    cache: &'c mut ProofValidityCache<P, Rule>,
+    report: &'c mut CachedProofValidityReport<P, Rule::Err>,
+    /// Each subproof being walked, alongside the available propositions it could make use of and the number of errors found before it
+    entered: Vec<(&'a SequentialProof<P, Rule>, HashSet<P>, usize)>,
+}
+impl<'a, 'c, P, Rule> ProofValidityVisitor<'a, P, Rule> for CachedValidityVisitor<'a, 'c, P, Rule>
+where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P> + Eq + Hash,
+{
+    fn enter(
+        &mut self,
+        proof: &'a SequentialProof<P, Rule>,
+        path: &SequentialProofInProofPath,
+        available: &HashPropSet1O<P>,
+    ) -> bool {
+        // A subproof can only make use of the available propositions which it assumes
+        let available: HashSet<P> = proof
+            .get_assumptions()
+            .into_iter()
+            .filter(|assumption| available.contains(*assumption))
+            .cloned()
+            .collect();
+        if self.cache.is_validated(proof, &available) {
+            return false;
+        }
+        self.report.rechecked.push(path.clone());
+        self.entered
+            .push((proof, available, self.report.errors.len()));
+        true
+    }
+    fn exit(&mut self) {
+        let (proof, available, error_count) = self
+            .entered
+            .pop()
+            .expect("Every subproof exited must have been entered");
+        if self.report.errors.len() == error_count {
+            self.cache
+                .validated
+                .entry(proof.clone())
+                .or_default()
+                .push(available);
+        }
+    }
+    fn inference(
+        &mut self,
+        inference: &'a Inference<P, Rule>,
+        path: SequentialProofInProofPath,
+        is_root: bool,
+    ) {
+        if let Err(err) = validate_inference(inference) {
+            self.report.errors.push(locate_error(err, path, is_root));
+        }
+    }
+    fn error(&mut self, err: CachedValidityStepErr<P, Rule::Err>) {
+        self.report.errors.push(err);
+    }
+}
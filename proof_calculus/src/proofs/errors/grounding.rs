@@ -1,12 +1,19 @@
 use crate::{
     proofs::{inferences::InferenceRule, sequential::SequentialProof},
-    propositions::types::assigned::{
-        ParentOfAssumptions, Proposition, collections::sets::PropSet1O,
+    propositions::{
+        assignments::PropositionalAssignment,
+        types::{
+            assigned::{ParentOfAssumptions, Proposition, collections::sets::PropSet1O},
+            unassigned::{
+                UnassignedProposition, binding::binders::GetBinderForUpropsSubsumingProp,
+            },
+        },
     },
 };
 
 /// Check that all of the premises of a given [Proof] are contained within some [PropositionSet]
 /// Used to check the "grounding" of a proof - that is, are all of the proof's premises assumed to be true? If they are, we can trust the proof's conclusions
+/// The hypotheses of a hypothetical subproof are not premises, as they are discharged within the proof
 pub fn verify_proof_grounding<'a, P: Proposition, PS: PropSet1O<P>, Rule: InferenceRule<P>>(
     proof: &'a SequentialProof<P, Rule>,
     assumptions: &PS,
@@ -36,3 +43,72 @@ fn proof_grounding_helper<'a, P: Proposition, PS: PropSet1O<P>, Rule: InferenceR
         }
     })
 }
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A premise of a proof, alongside the axiom schema which grounds it
+/// Instantiating the schema with the assignment gives the premise
+pub struct SchemaGrounding<'a, P: Proposition, U: UnassignedProposition, Assignment> {
+    pub premise: &'a P,
+    pub schema: &'a U,
+    pub assignment: Assignment,
+}
+
+/// Check that all of the premises of a given [Proof] are instances of some axiom schema within the provided binder
+/// If they are, return the schema which grounds each premise, and the assignment which instantiates it
+pub fn verify_proof_grounding_by_schemas<
+    'a,
+    P: Proposition,
+    U: UnassignedProposition + 'a,
+    Assignment: PropositionalAssignment<U, P>,
+    B: GetBinderForUpropsSubsumingProp<P, Value = U>,
+    Rule: InferenceRule<P>,
+>(
+    proof: &'a SequentialProof<P, Rule>,
+    schemas: &'a B,
+) -> Result<Vec<SchemaGrounding<'a, P, U, Assignment>>, &'a P> {
+    proof_schema_grounding_helper(proof, schemas).collect()
+}
+
+pub fn get_proof_grounding_errors_by_schemas<
+    'a,
+    P: Proposition,
+    U: UnassignedProposition + 'a,
+    Assignment: PropositionalAssignment<U, P>,
+    B: GetBinderForUpropsSubsumingProp<P, Value = U>,
+    Rule: InferenceRule<P>,
+>(
+    proof: &'a SequentialProof<P, Rule>,
+    schemas: &'a B,
+) -> impl Iterator<Item = &'a P> {
+    proof_schema_grounding_helper::<P, U, Assignment, B, Rule>(proof, schemas)
+        .filter_map(Result::err)
+}
+
+fn proof_schema_grounding_helper<
+    'a,
+    P: Proposition,
+    U: UnassignedProposition + 'a,
+    Assignment: PropositionalAssignment<U, P>,
+    B: GetBinderForUpropsSubsumingProp<P, Value = U>,
+    Rule: InferenceRule<P>,
+>(
+    proof: &'a SequentialProof<P, Rule>,
+    schemas: &'a B,
+) -> impl Iterator<Item = Result<SchemaGrounding<'a, P, U, Assignment>, &'a P>> {
+    proof.get_assumptions().into_iter().map(|premise| {
+        // The binder may return schemas which do not subsume the premise, so each one must be checked by constructing its assignment
+        schemas
+            .get_subsumers_of(premise)
+            .into_iter()
+            .find_map(|schema| {
+                Assignment::reverse_assign(schema, premise)
+                    .ok()
+                    .map(|assignment| SchemaGrounding {
+                        premise,
+                        schema,
+                        assignment,
+                    })
+            })
+            .ok_or(premise)
+    })
+}
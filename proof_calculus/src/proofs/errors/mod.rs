@@ -14,25 +14,37 @@ use crate::{
         inferences::{Inference, InferenceRule, located::InferenceAtPath},
         sequential::{
             SequentialProof,
+            hypothetical::HypotheticalSequentialProof,
             subproofs::{
                 SequentialProofAtPath, SequentialProofInProofPath,
                 immediate::ImmediateSequentialProofInProofPath,
             },
         },
     },
-    propositions::types::assigned::Proposition,
+    propositions::types::assigned::{
+        Proposition, collections::sets::implementations::hash::HashPropSet1O,
+    },
 };
 
+pub mod cache;
 pub mod grounding;
+pub mod parallel;
 pub mod soundness;
 pub mod stepper;
 pub mod validity;
+mod walk;
 
 pub trait ValidatableInferenceRule<P: Proposition>: InferenceRule<P> {
     type Err: Clone;
 
     fn validate(inference: &Inference<P, Self>) -> Result<(), Self::Err>;
     //fn validate_located<Path>(located_inference: InferenceAtPath<P,Self,Path>)
+
+    /// Get the conclusions which a hypothetical subproof discharged by this rule proves in its parent
+    /// Returns None if this rule cannot discharge hypotheses, which is the default
+    fn discharge(_hypothetical: &HypotheticalSequentialProof<P, Self>) -> Option<HashPropSet1O<P>> {
+        None
+    }
 }
 
 pub fn validate_inference<P: Proposition, Rule: ValidatableInferenceRule<P>>(
@@ -40,6 +52,22 @@ pub fn validate_inference<P: Proposition, Rule: ValidatableInferenceRule<P>>(
 ) -> Result<(), ProofValidityError<P, Rule::Err>> {
     Rule::validate(inference).map_err(|err| ProofValidityError::InvalidInference(err, PhantomData))
 }
+/// Check that the discharge rule of a hypothetical subproof proves each of its conclusions
+/// The body of the subproof is not checked here
+pub fn validate_discharge<P: Proposition, Rule: ValidatableInferenceRule<P>>(
+    hypothetical: &HypotheticalSequentialProof<P, Rule>,
+) -> Result<(), ProofValidityError<P, Rule::Err>> {
+    let Some(discharged) = Rule::discharge(hypothetical) else {
+        return Err(ProofValidityError::CannotDischarge);
+    };
+    let conclusions = HashPropSet1O::from_iter(hypothetical.conclusions.iter().cloned());
+    let not_discharged = &conclusions - &discharged;
+    if not_discharged.len() > 0 {
+        Err(ProofValidityError::ConclusionsNotDischarged(not_discharged))
+    } else {
+        Ok(())
+    }
+}
 pub fn validate_located_inference<'a, P: Proposition, Rule: ValidatableInferenceRule<P>, Path>(
     inference: InferenceAtPath<'a, P, Rule, Path>,
 ) -> Result<(), OwnedProofValidityErrorAtPath<P, Rule::Err, Path>> {
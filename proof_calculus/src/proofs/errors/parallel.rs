@@ -0,0 +1,237 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use path_lib::obj_at_path::ObjAtPath;
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule, validate_inference,
+            validity::ProofValidityError,
+            walk::{
+                ProofValidityVisitor, WalkedValidityStepErr, locate_error, walk_proof_validity,
+            },
+        },
+        inferences::{Inference, located::InferenceInProof},
+        sequential::{SequentialProof, subproofs::SequentialProofInProofPath},
+    },
+    propositions::types::assigned::Proposition,
+};
+
+/// The number of inferences a worker thread claims at once
+const CHUNK_SIZE: usize = 64;
+
+type ParallelValidityStepErr<P, IE> = WalkedValidityStepErr<P, IE>;
+
+/// A single entry in the order that [ProofValidityStepper](super::stepper::ProofValidityStepper) would report errors in
+enum ValidityCheck<'a, P: Proposition, Rule: ValidatableInferenceRule<P>> {
+    /// An error found while checking which propositions are available to each step; these are cheap, so they are found up front
+    Bookkeeping(ParallelValidityStepErr<P, Rule::Err>),
+    /// An inference which still needs to be validated, and whether it is the whole proof
+    Inference(InferenceInProof<'a, P, Rule>, bool),
+}
+impl<'a, P: Proposition, Rule: ValidatableInferenceRule<P>> ProofValidityVisitor<'a, P, Rule>
+    for Vec<ValidityCheck<'a, P, Rule>>
+{
+    fn inference(
+        &mut self,
+        inference: &'a Inference<P, Rule>,
+        path: SequentialProofInProofPath,
+        is_root: bool,
+    ) {
+        self.push(ValidityCheck::Inference(
+            ObjAtPath {
+                obj: inference,
+                path,
+            },
+            is_root,
+        ));
+    }
+    fn error(&mut self, err: ParallelValidityStepErr<P, Rule::Err>) {
+        self.push(ValidityCheck::Bookkeeping(err));
+    }
+}
+
+/// Verify the validity of the provided proof, validating its inferences across multiple threads
+/// Returns the same error as [verify_proof_validity](super::verify_proof_validity) would
+pub fn verify_proof_validity_parallel<P, Rule>(
+    proof: &SequentialProof<P, Rule>,
+    threads: Option<NonZeroUsize>,
+) -> Result<(), ParallelValidityStepErr<P, Rule::Err>>
+where
+    P: Proposition + Send + Sync,
+    Rule: ValidatableInferenceRule<P> + Sync,
+    Rule::Err: Send,
+{
+    match get_proof_validity_errors_parallel(proof, threads).next() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Get every validity error within the provided proof, validating its inferences across multiple threads
+/// Errors are returned in the same order as by [get_proof_validity_errors](super::get_proof_validity_errors)
+/// If no thread count is provided, [std::thread::available_parallelism] is used
+pub fn get_proof_validity_errors_parallel<P, Rule>(
+    proof: &SequentialProof<P, Rule>,
+    threads: Option<NonZeroUsize>,
+) -> impl Iterator<Item = ParallelValidityStepErr<P, Rule::Err>>
+where
+    P: Proposition + Send + Sync,
+    Rule: ValidatableInferenceRule<P> + Sync,
+    Rule::Err: Send,
+{
+    let mut checks = Vec::new();
+    walk_proof_validity(proof, &mut checks);
+
+    let inferences: Vec<&Inference<P, Rule>> = checks
+        .iter()
+        .filter_map(|check| match check {
+            ValidityCheck::Inference(inference, _) => Some(inference.obj),
+            ValidityCheck::Bookkeeping(_) => None,
+        })
+        .collect();
+    let threads = threads
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+    let mut inference_errors = validate_inferences_parallel(&inferences, threads).into_iter();
+
+    checks.into_iter().filter_map(move |check| match check {
+        ValidityCheck::Bookkeeping(err) => Some(err),
+        ValidityCheck::Inference(inference, is_root) => {
+            let err = inference_errors
+                .next()
+                .expect("Every inference check must have a corresponding validation result")?;
+            Some(locate_error(err, inference.path, is_root))
+        }
+    })
+}
+
+/// Validate every provided inference, returning the result of each in the same order as the inferences were provided
+fn validate_inferences_parallel<P, Rule>(
+    inferences: &[&Inference<P, Rule>],
+    threads: usize,
+) -> Vec<Option<ProofValidityError<P, Rule::Err>>>
+where
+    P: Proposition + Send + Sync,
+    Rule: ValidatableInferenceRule<P> + Sync,
+    Rule::Err: Send,
+{
+    let next_chunk = AtomicUsize::new(0);
+    let worker = || {
+        let mut errors = Vec::new();
+        loop {
+            let start = next_chunk.fetch_add(CHUNK_SIZE, Ordering::Relaxed);
+            if start >= inferences.len() {
+                break errors;
+            }
+            let end = (start + CHUNK_SIZE).min(inferences.len());
+            for index in start..end {
+                if let Err(err) = validate_inference(inferences[index]) {
+                    errors.push((index, err));
+                }
+            }
+        }
+    };
+
+    let worker_count = threads.min(inferences.len().div_ceil(CHUNK_SIZE)).max(1);
+    let found: Vec<(usize, ProofValidityError<P, Rule::Err>)> = thread::scope(|scope| {
+        let handles: Vec<_> = (1..worker_count).map(|_| scope.spawn(worker)).collect();
+        let mut found = worker();
+        for handle in handles {
+            found.extend(handle.join().expect("Inference validation thread panicked"));
+        }
+        found
+    });
+
+    let mut results: Vec<_> = (0..inferences.len()).map(|_| None).collect();
+    for (index, err) in found {
+        results[index] = Some(err);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proofs::errors::{get_proof_validity_errors, verify_proof_validity},
+        test_helpers::{TestProp, TestRule, composite_step, inference_step},
+    };
+
+    /// Check that the parallel walk agrees with the sequential one at several thread counts, returning the number of errors
+    fn assert_matches_sequential(proof: &SequentialProof<TestProp, TestRule>) -> usize {
+        let sequential: Vec<_> = get_proof_validity_errors(proof)
+            .filter_map(Result::err)
+            .collect();
+        for threads in [1, 2, 7] {
+            let threads = NonZeroUsize::new(threads);
+            assert_eq!(
+                verify_proof_validity_parallel(proof, threads),
+                verify_proof_validity(proof)
+            );
+            assert_eq!(
+                get_proof_validity_errors_parallel(proof, threads).collect::<Vec<_>>(),
+                sequential
+            );
+        }
+        sequential.len()
+    }
+
+    #[test]
+    fn test_single_inference() {
+        assert_eq!(
+            assert_matches_sequential(&inference_step(TestRule::Valid, &[1], 2)),
+            0
+        );
+        assert_eq!(
+            assert_matches_sequential(&inference_step(TestRule::Invalid, &[1], 2)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_first_error_matches_sequential() {
+        // The first error is a missing assumption, which precedes an invalid inference found by a worker thread
+        let proof = composite_step(
+            &[1],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                inference_step(TestRule::Valid, &[9], 3),
+                composite_step(
+                    &[2],
+                    vec![
+                        inference_step(TestRule::Invalid, &[2], 4),
+                        inference_step(TestRule::Valid, &[4], 5),
+                    ],
+                    &[5, 6],
+                ),
+                inference_step(TestRule::Invalid, &[5], 7),
+            ],
+            &[7, 8],
+        );
+        assert_eq!(assert_matches_sequential(&proof), 5);
+    }
+
+    #[test]
+    fn test_many_chunks() {
+        let proof = composite_step(
+            &[0],
+            (0..1000)
+                .map(|step| {
+                    let rule = if step % 37 == 0 {
+                        TestRule::Invalid
+                    } else {
+                        TestRule::Valid
+                    };
+                    inference_step(rule, &[step], step + 1)
+                })
+                .collect(),
+            &[1000],
+        );
+        assert_eq!(assert_matches_sequential(&proof), 28);
+    }
+}
@@ -1,16 +1,34 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Display},
+};
+
+use path_lib::obj_at_path::OwnedObjAtPath;
+
 use crate::{
     proofs::{
         errors::{
             ValidatableInferenceRule, get_proof_validity_errors,
             grounding::{get_proof_grounding_errors, verify_proof_grounding},
             stepper::result::ProofValidityStepErr,
+            validity::{OwnedValidityErrorInProof, ProofValidityError},
             verify_proof_validity,
         },
-        sequential::{SequentialProof, subproofs::SequentialProofInProofPath},
+        inferences::InferenceRule,
+        sequential::{
+            SequentialProof,
+            errors::OwnedErrorInProof,
+            subproofs::{
+                SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
+            },
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, Proposition, collections::sets::PropSet1O,
     },
-    propositions::types::assigned::{Proposition, collections::sets::PropSet1O},
 };
 
+#[derive(Clone, PartialEq, Eq, Debug)]
 pub enum ProofSoundnessError<P: Proposition, Err: Clone> {
     LacksGrounding(P),
     Invalid(ProofValidityStepErr<P, Err, (), SequentialProofInProofPath>),
@@ -49,3 +67,183 @@ pub fn get_proof_soundness_errors<
             Err(e) => Some(ProofSoundnessError::Invalid(e)),
         }))
 }
+
+impl<P: Proposition + Debug, Err: Clone + Debug> Display for ProofSoundnessError<P, Err> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofSoundnessError::LacksGrounding(premise) => {
+                write!(f, "the premise {:?} is not grounded", premise)
+            }
+            ProofSoundnessError::Invalid(err) => {
+                let err = locate_validity_error(err.clone());
+                write!(f, "{} {}", err.path.describe(), err.obj)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+/// The kinds of error which can make a proof unsound, in the order a [SoundnessReport] lists them
+pub enum SoundnessErrorKind {
+    LacksGrounding,
+    AssumptionsNotFound,
+    ConclusionsNotFound,
+    InvalidInference,
+    InvalidCitation,
+    InvalidDischarge,
+}
+impl<P: Proposition, InferenceErr> ProofValidityError<P, InferenceErr> {
+    pub fn kind(&self) -> SoundnessErrorKind {
+        match self {
+            ProofValidityError::AssumptionsNotFound(_) => SoundnessErrorKind::AssumptionsNotFound,
+            ProofValidityError::ConclusionsNotFound(_) => SoundnessErrorKind::ConclusionsNotFound,
+            ProofValidityError::InvalidInference(_, _) => SoundnessErrorKind::InvalidInference,
+            ProofValidityError::InvalidCitation(_) => SoundnessErrorKind::InvalidCitation,
+            ProofValidityError::CannotDischarge
+            | ProofValidityError::ConclusionsNotDischarged(_) => {
+                SoundnessErrorKind::InvalidDischarge
+            }
+        }
+    }
+}
+impl Display for SoundnessErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            SoundnessErrorKind::LacksGrounding => "Ungrounded premises",
+            SoundnessErrorKind::AssumptionsNotFound => "Unavailable assumptions",
+            SoundnessErrorKind::ConclusionsNotFound => "Unproved conclusions",
+            SoundnessErrorKind::InvalidInference => "Invalid inferences",
+            SoundnessErrorKind::InvalidCitation => "Invalid citations",
+            SoundnessErrorKind::InvalidDischarge => "Invalid discharges",
+        };
+        write!(f, "{}", description)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// Every error which makes a proof unsound, each located at the step it occurred at
+pub struct SoundnessReport<P: Proposition, Err: Clone> {
+    /// Premises of the proof which are not grounded, located at the first step which assumes them
+    pub ungrounded: Vec<OwnedErrorInProof<P>>,
+    /// Validity errors within the proof
+    pub invalid: Vec<OwnedValidityErrorInProof<P, Err>>,
+}
+
+impl<P: Proposition, Err: Clone> SoundnessReport<P, Err> {
+    /// Check the soundness of the provided proof, given some set of starting assumptions, and collect every error found
+    pub fn new<PS: PropSet1O<P>, Rule: ValidatableInferenceRule<P, Err = Err>>(
+        proof: &SequentialProof<P, Rule>,
+        assumptions: &PS,
+    ) -> Self {
+        let root = SequentialProofInProofPath(vec![]);
+        let ungrounded = get_proof_grounding_errors(proof, assumptions)
+            .map(|premise| OwnedObjAtPath {
+                obj: premise.clone(),
+                path: first_step_assuming(proof, premise, root.clone())
+                    .unwrap_or_else(|| root.clone()),
+            })
+            .collect();
+        let invalid = get_proof_validity_errors(proof)
+            .filter_map(Result::err)
+            .map(locate_validity_error)
+            .collect();
+        Self {
+            ungrounded,
+            invalid,
+        }
+    }
+
+    pub fn is_sound(&self) -> bool {
+        self.ungrounded.is_empty() && self.invalid.is_empty()
+    }
+    pub fn error_count(&self) -> usize {
+        self.ungrounded.len() + self.invalid.len()
+    }
+
+    /// Get the number of errors of each kind within this report, omitting kinds with no errors
+    pub fn count_by_kind(&self) -> BTreeMap<SoundnessErrorKind, usize> {
+        let mut counts = BTreeMap::new();
+        if !self.ungrounded.is_empty() {
+            counts.insert(SoundnessErrorKind::LacksGrounding, self.ungrounded.len());
+        }
+        for err in &self.invalid {
+            *counts.entry(err.obj.kind()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+impl<P: Proposition + Debug, Err: Clone + Debug> Display for SoundnessReport<P, Err> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_sound() {
+            return writeln!(f, "The proof is sound");
+        }
+        writeln!(
+            f,
+            "The proof is not sound; {} errors were found",
+            self.error_count()
+        )?;
+        for (kind, count) in self.count_by_kind() {
+            writeln!(f, "{} ({}):", kind, count)?;
+            if kind == SoundnessErrorKind::LacksGrounding {
+                for premise in &self.ungrounded {
+                    writeln!(
+                        f,
+                        "  {} assumes {:?}, which is not grounded",
+                        premise.path.describe(),
+                        premise.obj
+                    )?;
+                }
+                continue;
+            }
+            for err in self.invalid.iter().filter(|err| err.obj.kind() == kind) {
+                writeln!(f, "  {} {}", err.path.describe(), err.obj)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Place a validity error at its path within the proof, rather than its path relative to the proof's root
+fn locate_validity_error<P: Proposition, Err: Clone>(
+    err: ProofValidityStepErr<P, Err, (), SequentialProofInProofPath>,
+) -> OwnedValidityErrorInProof<P, Err> {
+    match err {
+        ProofValidityStepErr::InParent(err) => {
+            err.replace_path(|_| SequentialProofInProofPath(vec![]))
+        }
+        ProofValidityStepErr::InChild(err) => err,
+    }
+}
+
+/// Find the first inference or citation within the provided proof which assumes the provided premise
+fn first_step_assuming<P: Proposition, Rule: InferenceRule<P>>(
+    proof: &SequentialProof<P, Rule>,
+    premise: &P,
+    path: SequentialProofInProofPath,
+) -> Option<SequentialProofInProofPath> {
+    // Steps within a hypothetical subproof which assume one of its hypotheses do not assume the premise from outside it
+    if let SequentialProof::Hypothetical(hypothetical) = proof {
+        if hypothetical.hypotheses.contains(premise) {
+            return None;
+        }
+    }
+    match proof.body() {
+        Some(body) => body
+            .subproofs
+            .iter()
+            .enumerate()
+            .find_map(|(index, subproof)| {
+                first_step_assuming(
+                    subproof,
+                    premise,
+                    (path.clone(), ImmediateSequentialProofInProofPath(index)).into(),
+                )
+            }),
+        None => proof
+            .get_assumptions()
+            .into_iter()
+            .any(|assumption| assumption == premise)
+            .then_some(path),
+    }
+}
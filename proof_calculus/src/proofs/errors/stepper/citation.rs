@@ -0,0 +1,51 @@
+use path_lib::obj_at_path::OwnedObjAtPath;
+
+use crate::{
+    proofs::{
+        errors::{
+            stepper::result::ProofValidityStepResultWrapper,
+            validity::{CitationValidityError, ProofValidityError},
+        },
+        sequential::citation::TheoremCitationAtPath,
+    },
+    propositions::types::assigned::Proposition,
+};
+
+/// Citations cannot be checked without a [ProofLibrary](crate::proofs::library::ProofLibrary), so this stepper reports each of them as [unchecked](CitationValidityError::Unchecked)
+pub struct CitationValidityStepper<'a, P: Proposition, Path>(
+    Option<TheoremCitationAtPath<'a, P, Path>>,
+);
+
+impl<'a, P: Proposition, Path> CitationValidityStepper<'a, P, Path> {
+    pub fn new(citation: TheoremCitationAtPath<'a, P, Path>) -> Self {
+        Self(Some(citation))
+    }
+
+    pub fn step<IE: Clone, JoinedPath>(
+        &mut self,
+    ) -> ProofValidityStepResultWrapper<P, IE, Path, JoinedPath> {
+        if let Some(citation) = self.0.take() {
+            ProofValidityStepResultWrapper::finished_parent_err(OwnedObjAtPath {
+                obj: ProofValidityError::InvalidCitation(CitationValidityError::Unchecked(
+                    citation.obj.clone(),
+                )),
+                path: citation.path,
+            })
+        } else {
+            ProofValidityStepResultWrapper::finished_no_err()
+        }
+    }
+
+    /// Create a stepper which has already checked the provided citation if it is finished
+    pub fn resume(citation: TheoremCitationAtPath<'a, P, Path>, finished: bool) -> Self {
+        if finished {
+            Self(None)
+        } else {
+            Self::new(citation)
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.0.is_none()
+    }
+}
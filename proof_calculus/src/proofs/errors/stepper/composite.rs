@@ -1,21 +1,28 @@
 use itertools::Either;
-use path_lib::obj_at_path::OwnedObjAtPath;
+use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
 
 use crate::{
     proofs::{
         errors::{
             ValidatableInferenceRule,
-            stepper::result::{ProofValidityStepErr, ProofValidityStepResultWrapper},
-            validate_inference,
-            validity::ProofValidityError,
+            stepper::{
+                control::{CompositeProofValidityStep, ProofValidityResumeError},
+                observer::ProofValidityObserver,
+                result::{ProofValidityStepErr, ProofValidityStepResultWrapper},
+            },
+            validate_discharge, validate_inference,
+            validity::{CitationValidityError, ProofValidityError},
         },
         sequential::{
+            SequentialProof,
             at_path_enum::SequentialProofAtPathEnum,
             composite::CompositeSequentialProofAtPath,
+            hypothetical::HypotheticalSequentialProof,
             subproofs::{
-                ParentOfSubproofs, SequentialProofAtPath,
+                SequentialProofAtPath,
                 immediate::{
                     ImmediateSequentialProofInProofPath, LocatedParentOfImmediateSubproofs,
+                    ParentOfImmediateSubproofs,
                 },
             },
         },
@@ -26,14 +33,6 @@ use crate::{
     },
 };
 
-#[derive(PartialEq, Eq, Debug)]
-enum CompositeProofValidityStep {
-    CheckAssumptionsFound(usize),
-    CheckInternalValidity(usize),
-    CheckConclusionsFound,
-    Finished,
-}
-
 pub struct CompositeProofValidityStepper<
     'a,
     P: Proposition,
@@ -50,6 +49,7 @@ pub struct CompositeProofValidityStepper<
     proved: HashPropSet1O<P>,
 
     current_step: CompositeProofValidityStep,
+    entered: bool,
     inner: Option<Box<CompositeProofValidityStepper<'a, P, Rule, JoinedPath, JoinedPath>>>,
 }
 
@@ -66,24 +66,54 @@ impl<
 {
     pub fn new(proof: CompositeSequentialProofAtPath<'a, P, Rule, ParentPath>) -> Self {
         let proof_obj = proof.obj;
+        let step_count = proof_obj.get_immediate_subproof_count();
         Self {
-            current_step: CompositeProofValidityStep::CheckAssumptionsFound(0),
+            current_step: if step_count == 0 {
+                CompositeProofValidityStep::CheckConclusionsFound
+            } else {
+                CompositeProofValidityStep::CheckAssumptionsFound(0)
+            },
             proved: HashPropSet1O::from_iter(proof_obj.get_assumptions_owned()),
-            step_count: proof_obj.get_subproof_count(),
+            step_count,
             proof,
+            entered: false,
             inner: None,
         }
     }
 
-    pub fn step(&mut self) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
+    /// Take a single step, informing the provided observer of what is checked
+    /// Errors are not passed to the observer here, as the outermost [ProofValidityStepper](super::ProofValidityStepper) reports them
+    pub fn step_observed<
+        O: ProofValidityObserver<P, Rule, ParentPath> + ProofValidityObserver<P, Rule, JoinedPath>,
+    >(
+        &mut self,
+        observer: &mut O,
+    ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
+        if !self.entered && !self.is_finished() {
+            self.entered = true;
+            ProofValidityObserver::<P, Rule, ParentPath>::enter_composite(
+                observer,
+                &self.proof.path,
+            );
+        }
         match self.current_step {
             CompositeProofValidityStep::CheckAssumptionsFound(step_number) => {
-                self.check_assumptions_step(step_number)
+                self.check_assumptions_step(step_number, observer)
             }
             CompositeProofValidityStep::CheckInternalValidity(step_number) => {
-                self.internal_validation_step(step_number)
+                self.internal_validation_step(step_number, observer)
+            }
+            CompositeProofValidityStep::CheckDischarge(step_number) => {
+                self.discharge_step(step_number)
+            }
+            CompositeProofValidityStep::CheckConclusionsFound => {
+                let result = self.check_conclusions_step();
+                ProofValidityObserver::<P, Rule, ParentPath>::leave_composite(
+                    observer,
+                    &self.proof.path,
+                );
+                result
             }
-            CompositeProofValidityStep::CheckConclusionsFound => self.check_conclusions_step(),
             CompositeProofValidityStep::Finished => {
                 return ProofValidityStepResultWrapper::finished_no_err();
             }
@@ -94,18 +124,89 @@ impl<
         self.current_step == CompositeProofValidityStep::Finished
     }
 
+    /// Record the position of this stepper, followed by the positions of any composite steppers within it
+    pub fn checkpoint_into(&self, position: &mut Vec<CompositeProofValidityStep>) {
+        position.push(self.current_step);
+        if let Some(inner) = &self.inner {
+            inner.checkpoint_into(position);
+        }
+    }
+
+    /// Create a stepper which continues from the provided position, as recorded by [CompositeProofValidityStepper::checkpoint_into]
+    pub fn resume(
+        proof: CompositeSequentialProofAtPath<'a, P, Rule, ParentPath>,
+        position: &[CompositeProofValidityStep],
+    ) -> Result<Self, ProofValidityResumeError> {
+        let Some((current_step, inner_position)) = position.split_first() else {
+            return Err(ProofValidityResumeError::UnexpectedComposite);
+        };
+        let mut stepper = Self::new(proof);
+        // Every step before the current one has been checked, so its conclusions have been proved
+        let checked_count = match *current_step {
+            CompositeProofValidityStep::CheckAssumptionsFound(step)
+            | CompositeProofValidityStep::CheckInternalValidity(step)
+            | CompositeProofValidityStep::CheckDischarge(step) => {
+                if step >= stepper.step_count {
+                    return Err(ProofValidityResumeError::StepNotFound {
+                        step,
+                        step_count: stepper.step_count,
+                    });
+                }
+                step
+            }
+            CompositeProofValidityStep::CheckConclusionsFound
+            | CompositeProofValidityStep::Finished => stepper.step_count,
+        };
+        for step in 0..checked_count {
+            let subproof = stepper
+                .proof
+                .obj
+                .get_immediate_subproof(&ImmediateSequentialProofInProofPath(step))
+                .expect("Attempted to call get_subproof when step was not within range");
+            stepper
+                .proved
+                .extend(subproof.get_explicit_conclusions_owned());
+        }
+        stepper.current_step = *current_step;
+
+        if inner_position.len() > 0 {
+            let CompositeProofValidityStep::CheckInternalValidity(step) = *current_step else {
+                return Err(ProofValidityResumeError::NotComposite);
+            };
+            let composite = match stepper
+                .proof
+                .get_located_immediate_subproof(ImmediateSequentialProofInProofPath(step))
+                .expect("Attempted to call get_subproof when step was not within range")
+                .into()
+            {
+                SequentialProofAtPathEnum::Composite(composite) => composite,
+                SequentialProofAtPathEnum::Hypothetical(hypothetical) => ObjAtPath {
+                    obj: &hypothetical.obj.body,
+                    path: hypothetical.path,
+                },
+                _ => return Err(ProofValidityResumeError::NotComposite),
+            };
+            stepper.inner = Some(Box::new(CompositeProofValidityStepper::resume(
+                composite,
+                inner_position,
+            )?));
+        }
+        Ok(stepper)
+    }
+
     fn next_step(&self) -> CompositeProofValidityStep {
         match self.current_step {
             CompositeProofValidityStep::CheckAssumptionsFound(step_no) => {
                 CompositeProofValidityStep::CheckInternalValidity(step_no)
             }
             CompositeProofValidityStep::CheckInternalValidity(step_no) => {
-                if step_no >= self.step_count {
-                    CompositeProofValidityStep::CheckConclusionsFound
+                if self.hypothetical_at(step_no).is_some() {
+                    CompositeProofValidityStep::CheckDischarge(step_no)
                 } else {
-                    CompositeProofValidityStep::CheckAssumptionsFound(step_no + 1)
+                    self.step_after(step_no)
                 }
             }
+            CompositeProofValidityStep::CheckDischarge(step_no) => self.step_after(step_no),
             CompositeProofValidityStep::CheckConclusionsFound => {
                 CompositeProofValidityStep::Finished
             }
@@ -113,17 +214,40 @@ impl<
         }
     }
 
-    fn check_assumptions_step(
+    /// Get the first step which checks the subproof following the provided one
+    fn step_after(&self, step_no: usize) -> CompositeProofValidityStep {
+        if step_no + 1 >= self.step_count {
+            CompositeProofValidityStep::CheckConclusionsFound
+        } else {
+            CompositeProofValidityStep::CheckAssumptionsFound(step_no + 1)
+        }
+    }
+
+    /// Get the subproof at the provided step, if it is a hypothetical subproof
+    fn hypothetical_at(&self, step_no: usize) -> Option<&'a HypotheticalSequentialProof<P, Rule>> {
+        match self
+            .proof
+            .obj
+            .get_immediate_subproof(&ImmediateSequentialProofInProofPath(step_no))
+        {
+            Ok(SequentialProof::Hypothetical(hypothetical)) => Some(hypothetical),
+            _ => None,
+        }
+    }
+
+    fn check_assumptions_step<O: ProofValidityObserver<P, Rule, JoinedPath>>(
         &mut self,
         step_number: usize,
+        observer: &mut O,
     ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
         let subproof: SequentialProofAtPath<'_, P, Rule, JoinedPath> = self
             .proof
             .get_located_immediate_subproof(ImmediateSequentialProofInProofPath(step_number))
             .expect("Attempted to call get_subproof when step was not within range");
         let premises = HashPropSet1O::from_iter(subproof.obj.get_assumptions_owned());
+        observer.check_assumptions(&subproof.path, &premises);
         // Determine if an error is present
-        let assumptions_not_found = &self.proved - &premises;
+        let assumptions_not_found = &premises - &self.proved;
         let result = if assumptions_not_found.len() > 0 {
             ProofValidityStepResultWrapper::unfinished_child_err(OwnedObjAtPath {
                 obj: ProofValidityError::AssumptionsNotFound(assumptions_not_found),
@@ -138,9 +262,12 @@ impl<
         result
     }
 
-    fn internal_validation_step(
+    fn internal_validation_step<
+        O: ProofValidityObserver<P, Rule, ParentPath> + ProofValidityObserver<P, Rule, JoinedPath>,
+    >(
         &mut self,
         step_number: usize,
+        observer: &mut O,
     ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
         let next_step = self.next_step();
         // Get internal value
@@ -161,6 +288,29 @@ impl<
                         self.inner = Some(Box::new(CompositeProofValidityStepper::new(composite)));
                         Either::Right(&mut self.inner)
                     }
+                    SequentialProofAtPathEnum::Hypothetical(hypothetical) => {
+                        // The body is checked as a composite at the same path, with its hypotheses among its assumptions
+                        self.inner =
+                            Some(Box::new(CompositeProofValidityStepper::new(ObjAtPath {
+                                obj: &hypothetical.obj.body,
+                                path: hypothetical.path,
+                            })));
+                        Either::Right(&mut self.inner)
+                    }
+                    SequentialProofAtPathEnum::Citation(citation) => {
+                        // Citations can only be checked against a library, so they are reported as unchecked
+                        self.current_step = next_step;
+                        self.proved
+                            .extend(citation.obj.get_explicit_conclusions_owned());
+                        return ProofValidityStepResultWrapper::unfinished_child_err(
+                            OwnedObjAtPath {
+                                obj: ProofValidityError::InvalidCitation(
+                                    CitationValidityError::Unchecked(citation.obj.clone()),
+                                ),
+                                path: citation.path,
+                            },
+                        );
+                    }
                 }
             }
         };
@@ -169,7 +319,18 @@ impl<
                 self.current_step = next_step;
                 self.proved
                     .extend(inference.get_explicit_conclusions_owned());
-                match validate_inference(inference.obj) {
+                ProofValidityObserver::<P, Rule, JoinedPath>::start_inference(
+                    observer,
+                    &inference.path,
+                    inference.obj,
+                );
+                let result = validate_inference(inference.obj);
+                ProofValidityObserver::<P, Rule, JoinedPath>::finish_inference(
+                    observer,
+                    &inference.path,
+                    inference.obj,
+                );
+                match result {
                     Ok(_) => ProofValidityStepResultWrapper::unfinished_no_err(),
                     Err(err) => {
                         ProofValidityStepResultWrapper::unfinished_child_err(OwnedObjAtPath {
@@ -181,11 +342,15 @@ impl<
             }
             Either::Right(Some(composite)) => {
                 let cloned_value = composite.proof.clone();
-                let inner_result = composite.step();
+                let inner_result = composite.step_observed(observer);
                 if inner_result.is_finished {
+                    self.inner = None;
                     self.current_step = next_step;
-                    let conclusions = cloned_value.get_explicit_conclusions_owned();
-                    self.proved.extend(conclusions);
+                    // The conclusions of a hypothetical subproof's body are only proved in the parent once discharged
+                    if self.hypothetical_at(step_number).is_none() {
+                        let conclusions = cloned_value.get_explicit_conclusions_owned();
+                        self.proved.extend(conclusions);
+                    }
                 }
                 match inner_result.next_result {
                     Ok(()) => ProofValidityStepResultWrapper::unfinished_no_err(),
@@ -203,6 +368,30 @@ impl<
         }
     }
 
+    fn discharge_step(
+        &mut self,
+        step_number: usize,
+    ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
+        let hypothetical = self
+            .hypothetical_at(step_number)
+            .expect("Attempted to discharge a subproof which is not hypothetical");
+        let path: JoinedPath = (
+            self.proof.path.clone(),
+            ImmediateSequentialProofInProofPath(step_number),
+        )
+            .into();
+        self.proved.extend(hypothetical.conclusions.iter().cloned());
+        // Move to the next step
+        self.current_step = self.next_step();
+        match validate_discharge(hypothetical) {
+            Ok(()) => ProofValidityStepResultWrapper::unfinished_no_err(),
+            Err(err) => ProofValidityStepResultWrapper::unfinished_child_err(OwnedObjAtPath {
+                obj: err,
+                path,
+            }),
+        }
+    }
+
     fn check_conclusions_step(
         &mut self,
     ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
@@ -222,3 +411,109 @@ impl<
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        proofs::{
+            errors::{
+                get_proof_validity_errors, stepper::result::ProofValidityStepErr,
+                validity::ProofValidityError,
+            },
+            sequential::SequentialProof,
+        },
+        propositions::types::assigned::collections::sets::implementations::hash::HashPropSet1O,
+        test_helpers::{TestProp, TestRule, composite_step, inference_step},
+    };
+
+    /// Get each error in the provided proof, alongside the path of the subproof it is within (or None for the proof itself)
+    fn get_errors(
+        proof: &SequentialProof<TestProp, TestRule>,
+    ) -> Vec<(Option<Vec<usize>>, ProofValidityError<TestProp, TestProp>)> {
+        get_proof_validity_errors(proof)
+            .filter_map(Result::err)
+            .map(|err| match err {
+                ProofValidityStepErr::InParent(err) => (None, err.obj),
+                ProofValidityStepErr::InChild(err) => (
+                    Some(err.path.0.into_iter().map(|step| step.0).collect()),
+                    err.obj,
+                ),
+            })
+            .collect()
+    }
+    fn not_found(ids: &[u32]) -> HashPropSet1O<TestProp> {
+        HashPropSet1O::from_iter(ids.iter().map(|id| TestProp(*id)))
+    }
+
+    #[test]
+    fn test_steps_over_immediate_subproofs_only() {
+        // The nested steps must not be counted as steps of the outer proof
+        let proof = composite_step(
+            &[1],
+            vec![composite_step(
+                &[1],
+                vec![
+                    inference_step(TestRule::Valid, &[1], 2),
+                    inference_step(TestRule::Valid, &[2], 3),
+                    inference_step(TestRule::Valid, &[3], 4),
+                ],
+                &[4],
+            )],
+            &[4],
+        );
+        assert_eq!(get_errors(&proof), vec![]);
+    }
+
+    #[test]
+    fn test_empty_composite() {
+        assert_eq!(get_errors(&composite_step(&[1], vec![], &[1])), vec![]);
+        assert_eq!(
+            get_errors(&composite_step(&[1], vec![], &[2])),
+            vec![(
+                None,
+                ProofValidityError::ConclusionsNotFound(not_found(&[2]))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_reports_missing_assumptions() {
+        // Only the assumption which has not been proved is reported, not those which have
+        let proof = composite_step(
+            &[1],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                inference_step(TestRule::Valid, &[2, 9], 3),
+            ],
+            &[3],
+        );
+        assert_eq!(
+            get_errors(&proof),
+            vec![(
+                Some(vec![1]),
+                ProofValidityError::AssumptionsNotFound(not_found(&[9]))
+            )]
+        );
+    }
+
+    #[test]
+    fn test_leaves_finished_nested_composite() {
+        // The step after a nested composite must be checked itself, rather than re-entering the nested composite
+        let proof = composite_step(
+            &[1],
+            vec![
+                composite_step(&[1], vec![inference_step(TestRule::Valid, &[1], 2)], &[2]),
+                inference_step(TestRule::Invalid, &[2], 3),
+                inference_step(TestRule::Valid, &[3], 4),
+            ],
+            &[4],
+        );
+        assert_eq!(
+            get_errors(&proof),
+            vec![(
+                Some(vec![1]),
+                ProofValidityError::InvalidInference(TestProp(3), Default::default())
+            )]
+        );
+    }
+}
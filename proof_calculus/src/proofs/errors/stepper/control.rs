@@ -0,0 +1,232 @@
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule,
+            stepper::{
+                ProofValidityStepper, ProofValidityStepperInner, citation::CitationValidityStepper,
+                composite::CompositeProofValidityStepper,
+                hypothetical::HypotheticalProofValidityStepper,
+                inference::InferenceValidityStepper, observer::ProofValidityObserver,
+                result::ProofValidityStepErr,
+            },
+        },
+        sequential::{
+            at_path_enum::SequentialProofAtPathEnum,
+            subproofs::{SequentialProofAtPath, immediate::ImmediateSequentialProofInProofPath},
+        },
+    },
+    propositions::types::assigned::Proposition,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// The position of a [CompositeProofValidityStepper] within the composite proof it is checking
+pub enum CompositeProofValidityStep {
+    CheckAssumptionsFound(usize),
+    CheckInternalValidity(usize),
+    /// Check that a hypothetical subproof's discharge rule proves its conclusions
+    CheckDischarge(usize),
+    CheckConclusionsFound,
+    Finished,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+/// Limits on how much work a [ProofValidityStepper] may do before returning control to its caller
+pub struct ProofValidityBudget {
+    pub max_steps: Option<usize>,
+    /// How long each run may take, measured from when that run starts, so that time spent paused is not counted
+    pub time_limit: Option<Duration>,
+}
+impl ProofValidityBudget {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+    pub fn steps(max_steps: usize) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            time_limit: None,
+        }
+    }
+    pub fn time(duration: Duration) -> Self {
+        Self {
+            max_steps: None,
+            time_limit: Some(duration),
+        }
+    }
+
+    fn deadline(&self) -> Option<Instant> {
+        self.time_limit
+            .map(|time_limit| Instant::now() + time_limit)
+    }
+    fn is_exhausted(&self, steps_taken: usize, deadline: Option<Instant>) -> bool {
+        self.max_steps
+            .is_some_and(|max_steps| steps_taken >= max_steps)
+            || deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+/// A flag which can be set from any thread to stop a running [ProofValidityStepper]
+/// Clones of a token share the same flag
+pub struct CancellationToken(Arc<AtomicBool>);
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ProofValidityRunStatus {
+    /// The whole proof has been checked
+    Finished,
+    /// The budget ran out before the whole proof was checked
+    BudgetExhausted,
+    /// The run was cancelled before the whole proof was checked
+    Cancelled,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The outcome of running a [ProofValidityStepper] until it finished, ran out of budget or was cancelled
+pub struct ProofValidityRun<P: Proposition, IE: Clone, ParentPath, JoinedPath> {
+    pub status: ProofValidityRunStatus,
+    pub steps_taken: usize,
+    /// Every error found during this run
+    pub errors: Vec<ProofValidityStepErr<P, IE, ParentPath, JoinedPath>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// A snapshot of how far a [ProofValidityStepper] has progressed through a proof, from which verification can be resumed
+/// The position holds the state of each composite being checked, outermost first, where the body of a hypothetical subproof counts as a composite; it is empty if the proof is a single inference or citation
+pub struct ProofValidityCheckpoint {
+    pub finished: bool,
+    pub position: Vec<CompositeProofValidityStep>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProofValidityResumeError {
+    /// The checkpoint refers to a step past the end of a composite proof with the contained number of steps
+    StepNotFound { step: usize, step_count: usize },
+    /// The checkpoint expects a composite proof, but the proof contains an inference or citation at that position
+    NotComposite,
+    /// The checkpoint was taken while checking an inference or citation, but the proof is a composite
+    UnexpectedComposite,
+}
+
+impl<
+    'a,
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    ParentPath: Clone,
+    JoinedPath: Clone
+        + From<(ParentPath, ImmediateSequentialProofInProofPath)>
+        + From<(JoinedPath, ImmediateSequentialProofInProofPath)>,
+> ProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>
+{
+    /// Step through the proof until it has been fully checked, the budget runs out, or the provided token is cancelled
+    /// The stepper can be run again afterwards to continue from where it stopped
+    pub fn run(
+        &mut self,
+        budget: &ProofValidityBudget,
+        cancellation: Option<&CancellationToken>,
+    ) -> ProofValidityRun<P, Rule::Err, ParentPath, JoinedPath> {
+        self.run_observed(budget, cancellation, &mut ())
+    }
+
+    /// Run this stepper as in [ProofValidityStepper::run], informing the provided observer of each step
+    pub fn run_observed<
+        O: ProofValidityObserver<P, Rule, ParentPath> + ProofValidityObserver<P, Rule, JoinedPath>,
+    >(
+        &mut self,
+        budget: &ProofValidityBudget,
+        cancellation: Option<&CancellationToken>,
+        observer: &mut O,
+    ) -> ProofValidityRun<P, Rule::Err, ParentPath, JoinedPath> {
+        let mut run = ProofValidityRun {
+            status: ProofValidityRunStatus::Finished,
+            steps_taken: 0,
+            errors: Vec::new(),
+        };
+        let deadline = budget.deadline();
+        while !self.is_finished() {
+            if cancellation.is_some_and(CancellationToken::is_cancelled) {
+                run.status = ProofValidityRunStatus::Cancelled;
+                break;
+            }
+            if budget.is_exhausted(run.steps_taken, deadline) {
+                run.status = ProofValidityRunStatus::BudgetExhausted;
+                break;
+            }
+            run.steps_taken += 1;
+            if let Err(err) = self.step_observed(observer).next_result {
+                run.errors.push(err);
+            }
+        }
+        run
+    }
+
+    /// Record how far this stepper has progressed, so that verification can later be resumed with [ProofValidityStepper::resume]
+    pub fn checkpoint(&self) -> ProofValidityCheckpoint {
+        let mut position = Vec::new();
+        match &self.0 {
+            ProofValidityStepperInner::Composite(composite_stepper) => {
+                composite_stepper.checkpoint_into(&mut position)
+            }
+            ProofValidityStepperInner::Hypothetical(hypothetical_stepper) => {
+                hypothetical_stepper.checkpoint_into(&mut position)
+            }
+            ProofValidityStepperInner::Inference(_) | ProofValidityStepperInner::Citation(_) => {}
+        }
+        ProofValidityCheckpoint {
+            finished: self.is_finished(),
+            position,
+        }
+    }
+
+    /// Create a stepper for the provided proof which continues from a checkpoint taken while checking that same proof
+    /// Errors found before the checkpoint was taken are not reported again
+    pub fn resume(
+        proof: SequentialProofAtPath<'a, P, Rule, ParentPath>,
+        checkpoint: &ProofValidityCheckpoint,
+    ) -> Result<Self, ProofValidityResumeError> {
+        Ok(Self(match proof.into() {
+            SequentialProofAtPathEnum::Composite(composite) => {
+                ProofValidityStepperInner::Composite(CompositeProofValidityStepper::resume(
+                    composite,
+                    &checkpoint.position,
+                )?)
+            }
+            SequentialProofAtPathEnum::Hypothetical(hypothetical) => {
+                ProofValidityStepperInner::Hypothetical(HypotheticalProofValidityStepper::resume(
+                    hypothetical,
+                    &checkpoint.position,
+                    checkpoint.finished,
+                )?)
+            }
+            _ if checkpoint.position.len() > 0 => {
+                return Err(ProofValidityResumeError::NotComposite);
+            }
+            SequentialProofAtPathEnum::Inference(inference) => {
+                ProofValidityStepperInner::Inference(InferenceValidityStepper::resume(
+                    inference,
+                    checkpoint.finished,
+                ))
+            }
+            SequentialProofAtPathEnum::Citation(citation) => ProofValidityStepperInner::Citation(
+                CitationValidityStepper::resume(citation, checkpoint.finished),
+            ),
+        }))
+    }
+}
@@ -0,0 +1,362 @@
+use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule,
+            stepper::{
+                composite::CompositeProofValidityStepper,
+                control::{CompositeProofValidityStep, ProofValidityResumeError},
+                observer::ProofValidityObserver,
+                result::ProofValidityStepResultWrapper,
+            },
+            validate_discharge,
+        },
+        sequential::{
+            hypothetical::HypotheticalSequentialProofAtPath,
+            subproofs::immediate::ImmediateSequentialProofInProofPath,
+        },
+    },
+    propositions::types::assigned::Proposition,
+};
+
+/// Checks the body of a hypothetical subproof as a composite, and then checks that its discharge rule proves its conclusions
+pub struct HypotheticalProofValidityStepper<
+    'a,
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    ParentPath: 'a + Clone,
+    JoinedPath: 'a
+        + Clone
+        + From<(ParentPath, ImmediateSequentialProofInProofPath)>
+        + From<(JoinedPath, ImmediateSequentialProofInProofPath)>,
+> {
+    proof: HypotheticalSequentialProofAtPath<'a, P, Rule, ParentPath>,
+    body: CompositeProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>,
+    discharged: bool,
+}
+
+impl<
+    'a,
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    ParentPath: 'a + Clone,
+    JoinedPath: 'a
+        + Clone
+        + From<(ParentPath, ImmediateSequentialProofInProofPath)>
+        + From<(JoinedPath, ImmediateSequentialProofInProofPath)>,
+> HypotheticalProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>
+{
+    pub fn new(proof: HypotheticalSequentialProofAtPath<'a, P, Rule, ParentPath>) -> Self {
+        Self {
+            body: CompositeProofValidityStepper::new(ObjAtPath {
+                obj: &proof.obj.body,
+                path: proof.path.clone(),
+            }),
+            proof,
+            discharged: false,
+        }
+    }
+
+    pub fn step_observed<
+        O: ProofValidityObserver<P, Rule, ParentPath> + ProofValidityObserver<P, Rule, JoinedPath>,
+    >(
+        &mut self,
+        observer: &mut O,
+    ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
+        if !self.body.is_finished() {
+            // The hypothetical subproof is not finished until it has been discharged
+            let mut result = self.body.step_observed(observer);
+            result.is_finished = false;
+            return result;
+        }
+        if self.discharged {
+            return ProofValidityStepResultWrapper::finished_no_err();
+        }
+        self.discharged = true;
+        match validate_discharge(self.proof.obj) {
+            Ok(()) => ProofValidityStepResultWrapper::finished_no_err(),
+            Err(err) => ProofValidityStepResultWrapper::finished_parent_err(OwnedObjAtPath {
+                obj: err,
+                path: self.proof.path.clone(),
+            }),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.discharged
+    }
+
+    /// Record the position of the composite stepper checking this subproof's body
+    pub fn checkpoint_into(&self, position: &mut Vec<CompositeProofValidityStep>) {
+        self.body.checkpoint_into(position);
+    }
+
+    /// Create a stepper which continues from the provided position within the body, having already been discharged if it is finished
+    pub fn resume(
+        proof: HypotheticalSequentialProofAtPath<'a, P, Rule, ParentPath>,
+        position: &[CompositeProofValidityStep],
+        finished: bool,
+    ) -> Result<Self, ProofValidityResumeError> {
+        Ok(Self {
+            body: CompositeProofValidityStepper::resume(
+                ObjAtPath {
+                    obj: &proof.obj.body,
+                    path: proof.path.clone(),
+                },
+                position,
+            )?,
+            proof,
+            discharged: finished,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use crate::{
+        proofs::{
+            errors::{
+                ValidatableInferenceRule, cache::ProofValidityCache, get_proof_validity_errors,
+                parallel::get_proof_validity_errors_parallel, soundness::verify_proof_soundness,
+                stepper::result::ProofValidityStepErr, validity::ProofValidityError,
+            },
+            inferences::{Inference, InferenceRule},
+            sequential::{
+                SequentialProof, composite::CompositeSequentialProof,
+                hypothetical::HypotheticalSequentialProof,
+            },
+        },
+        propositions::types::assigned::{
+            ParentOfAssumptions, collections::sets::implementations::hash::HashPropSet1O,
+        },
+        test_helpers::{TestProp, props},
+    };
+
+    /// A rule which discharges each hypothesis `a` and explicit conclusion `c` of a body as `100 * a + c`, standing in for `a -> c`
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    enum ConditionalRule {
+        Valid,
+        Invalid,
+        Conditional,
+    }
+    impl InferenceRule<TestProp> for ConditionalRule {}
+    impl ValidatableInferenceRule<TestProp> for ConditionalRule {
+        type Err = ();
+
+        fn validate(inference: &Inference<TestProp, Self>) -> Result<(), Self::Err> {
+            match inference.inference_type {
+                ConditionalRule::Invalid => Err(()),
+                _ => Ok(()),
+            }
+        }
+        fn discharge(
+            hypothetical: &HypotheticalSequentialProof<TestProp, Self>,
+        ) -> Option<HashPropSet1O<TestProp>> {
+            if hypothetical.discharge_rule != ConditionalRule::Conditional {
+                return None;
+            }
+            Some(HashPropSet1O::from_iter(
+                hypothetical.hypotheses.iter().flat_map(|hypothesis| {
+                    hypothetical
+                        .body
+                        .explicit_conclusions
+                        .iter()
+                        .map(|conclusion| TestProp(100 * hypothesis.0 + conclusion.0))
+                }),
+            ))
+        }
+    }
+
+    fn inference_step(
+        rule: ConditionalRule,
+        assumptions: &[u32],
+        conclusion: u32,
+    ) -> SequentialProof<TestProp, ConditionalRule> {
+        SequentialProof::Inference(Inference {
+            inference_type: rule,
+            assumptions: props(assumptions),
+            conclusions: props(&[conclusion]),
+        })
+    }
+    fn composite(
+        assumptions: &[u32],
+        subproofs: Vec<SequentialProof<TestProp, ConditionalRule>>,
+        conclusions: &[u32],
+    ) -> CompositeSequentialProof<TestProp, ConditionalRule> {
+        CompositeSequentialProof {
+            assumptions: props(assumptions),
+            subproofs: subproofs.into(),
+            explicit_conclusions: props(conclusions),
+        }
+    }
+    fn hypothetical_step(
+        hypotheses: &[u32],
+        body: CompositeSequentialProof<TestProp, ConditionalRule>,
+        discharge_rule: ConditionalRule,
+        conclusions: &[u32],
+    ) -> SequentialProof<TestProp, ConditionalRule> {
+        SequentialProof::Hypothetical(HypotheticalSequentialProof {
+            hypotheses: props(hypotheses),
+            body,
+            discharge_rule,
+            conclusions: props(conclusions),
+        })
+    }
+    fn get_errors(
+        proof: &SequentialProof<TestProp, ConditionalRule>,
+    ) -> Vec<ProofValidityError<TestProp, ()>> {
+        get_proof_validity_errors(proof)
+            .filter_map(Result::err)
+            .map(|err| match err {
+                ProofValidityStepErr::InParent(err) => err.obj,
+                ProofValidityStepErr::InChild(err) => err.obj,
+            })
+            .collect()
+    }
+    fn not_found(ids: &[u32]) -> HashPropSet1O<TestProp> {
+        HashPropSet1O::from_iter(ids.iter().map(|id| TestProp(*id)))
+    }
+
+    /// Proves `2 -> 3` (as 203) from 1 by assuming 2, then uses it to prove 4
+    fn conditional_proof() -> SequentialProof<TestProp, ConditionalRule> {
+        SequentialProof::Composite(composite(
+            &[1],
+            vec![
+                hypothetical_step(
+                    &[2],
+                    composite(
+                        &[1, 2],
+                        vec![inference_step(ConditionalRule::Valid, &[1, 2], 3)],
+                        &[3],
+                    ),
+                    ConditionalRule::Conditional,
+                    &[203],
+                ),
+                inference_step(ConditionalRule::Valid, &[203], 4),
+            ],
+            &[4],
+        ))
+    }
+
+    #[test]
+    fn test_discharge() {
+        let proof = conditional_proof();
+        assert_eq!(get_errors(&proof), vec![]);
+        // The hypothesis is not an assumption of the proof as a whole
+        assert_eq!(
+            proof
+                .get_assumptions_owned()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![TestProp(1)]
+        );
+        verify_proof_soundness(&proof, &not_found(&[1])).unwrap();
+    }
+
+    #[test]
+    fn test_body_conclusions_stay_within_body() {
+        let proof = SequentialProof::Composite(composite(
+            &[1],
+            vec![
+                hypothetical_step(
+                    &[2],
+                    composite(
+                        &[1, 2],
+                        vec![inference_step(ConditionalRule::Valid, &[1, 2], 3)],
+                        &[3],
+                    ),
+                    ConditionalRule::Conditional,
+                    &[203],
+                ),
+                inference_step(ConditionalRule::Valid, &[3], 5),
+            ],
+            &[5],
+        ));
+        assert_eq!(
+            get_errors(&proof),
+            vec![ProofValidityError::AssumptionsNotFound(not_found(&[3]))]
+        );
+    }
+
+    #[test]
+    fn test_discharge_errors() {
+        let proof = SequentialProof::Composite(composite(
+            &[1],
+            vec![
+                hypothetical_step(
+                    &[2],
+                    composite(&[2], vec![], &[2]),
+                    ConditionalRule::Conditional,
+                    &[202, 204],
+                ),
+                hypothetical_step(
+                    &[2],
+                    composite(&[2], vec![], &[2]),
+                    ConditionalRule::Valid,
+                    &[202],
+                ),
+                // 8 is neither a hypothesis nor available in the parent
+                hypothetical_step(
+                    &[7],
+                    composite(&[7, 8], vec![], &[7]),
+                    ConditionalRule::Conditional,
+                    &[707],
+                ),
+            ],
+            &[202, 707],
+        ));
+        assert_eq!(
+            get_errors(&proof),
+            vec![
+                ProofValidityError::ConclusionsNotDischarged(not_found(&[204])),
+                ProofValidityError::CannotDischarge,
+                ProofValidityError::AssumptionsNotFound(not_found(&[8])),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_body() {
+        let proof = hypothetical_step(
+            &[2],
+            composite(
+                &[1, 2],
+                vec![inference_step(ConditionalRule::Invalid, &[1, 2], 3)],
+                &[3],
+            ),
+            ConditionalRule::Conditional,
+            &[203, 9],
+        );
+        let errors = get_errors(&proof);
+        assert_eq!(
+            errors,
+            vec![
+                ProofValidityError::InvalidInference((), Default::default()),
+                ProofValidityError::ConclusionsNotDischarged(not_found(&[9])),
+            ]
+        );
+        // The other ways of checking validity agree
+        let sequential: Vec<_> = get_proof_validity_errors(&proof)
+            .filter_map(Result::err)
+            .collect();
+        assert_eq!(
+            get_proof_validity_errors_parallel(&proof, NonZeroUsize::new(2)).collect::<Vec<_>>(),
+            sequential
+        );
+        assert_eq!(
+            ProofValidityCache::new()
+                .get_proof_validity_errors(&proof)
+                .errors,
+            sequential
+        );
+    }
+
+    #[test]
+    fn test_minimize_keeps_hypotheses() {
+        let (minimized, _) = conditional_proof().minimize();
+        assert_eq!(minimized, conditional_proof());
+    }
+}
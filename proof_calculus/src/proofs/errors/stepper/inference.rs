@@ -1,8 +1,11 @@
+use path_lib::obj_at_path::OwnedObjAtPath;
+
 use crate::{
     proofs::{
         errors::{
-            ValidatableInferenceRule, stepper::result::ProofValidityStepResultWrapper,
-            validate_located_inference,
+            ValidatableInferenceRule,
+            stepper::{observer::ProofValidityObserver, result::ProofValidityStepResultWrapper},
+            validate_inference,
         },
         inferences::located::InferenceAtPath,
     },
@@ -20,11 +23,18 @@ impl<'a, P: Proposition, Rule: ValidatableInferenceRule<P>, Path>
         Self(Some(inference))
     }
 
-    pub fn step<JoinedPath>(
+    pub fn step_observed<JoinedPath, O: ProofValidityObserver<P, Rule, Path>>(
         &mut self,
+        observer: &mut O,
     ) -> ProofValidityStepResultWrapper<P, Rule::Err, Path, JoinedPath> {
         if let Some(inference) = self.0.take() {
-            match validate_located_inference(inference) {
+            observer.start_inference(&inference.path, inference.obj);
+            let result = validate_inference(inference.obj);
+            observer.finish_inference(&inference.path, inference.obj);
+            match result.map_err(|err| OwnedObjAtPath {
+                obj: err,
+                path: inference.path,
+            }) {
                 Ok(_) => ProofValidityStepResultWrapper::finished_no_err(),
                 Err(err) => ProofValidityStepResultWrapper::finished_parent_err(err),
             }
@@ -33,6 +43,15 @@ impl<'a, P: Proposition, Rule: ValidatableInferenceRule<P>, Path>
         }
     }
 
+    /// Create a stepper which has already checked the provided inference if it is finished
+    pub fn resume(inference: InferenceAtPath<'a, P, Rule, Path>, finished: bool) -> Self {
+        if finished {
+            Self(None)
+        } else {
+            Self::new(inference)
+        }
+    }
+
     pub fn is_finished(&self) -> bool {
         self.0.is_none()
     }
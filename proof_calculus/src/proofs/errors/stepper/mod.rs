@@ -1,13 +1,16 @@
-use itertools::Either;
-
 use crate::{
     proofs::{
         errors::{
             ValidatableInferenceRule,
             stepper::{
+                citation::CitationValidityStepper,
                 composite::CompositeProofValidityStepper,
+                hypothetical::HypotheticalProofValidityStepper,
                 inference::InferenceValidityStepper,
-                result::{ProofValidityStepResult, ProofValidityStepResultWrapper},
+                observer::ProofValidityObserver,
+                result::{
+                    ProofValidityStepErr, ProofValidityStepResult, ProofValidityStepResultWrapper,
+                },
             },
         },
         sequential::{
@@ -18,8 +21,12 @@ use crate::{
     propositions::types::assigned::Proposition,
 };
 
+mod citation;
 mod composite;
+pub mod control;
+mod hypothetical;
 mod inference;
+pub mod observer;
 pub mod result;
 
 pub struct ProofValidityStepper<
@@ -30,12 +37,22 @@ pub struct ProofValidityStepper<
     JoinedPath: Clone
         + From<(ParentPath, ImmediateSequentialProofInProofPath)>
         + From<(JoinedPath, ImmediateSequentialProofInProofPath)>,
->(
-    Either<
-        InferenceValidityStepper<'a, P, Rule, ParentPath>,
-        CompositeProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>,
-    >,
-);
+>(ProofValidityStepperInner<'a, P, Rule, ParentPath, JoinedPath>);
+
+enum ProofValidityStepperInner<
+    'a,
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    ParentPath: Clone,
+    JoinedPath: Clone
+        + From<(ParentPath, ImmediateSequentialProofInProofPath)>
+        + From<(JoinedPath, ImmediateSequentialProofInProofPath)>,
+> {
+    Inference(InferenceValidityStepper<'a, P, Rule, ParentPath>),
+    Composite(CompositeProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>),
+    Citation(CitationValidityStepper<'a, P, ParentPath>),
+    Hypothetical(HypotheticalProofValidityStepper<'a, P, Rule, ParentPath, JoinedPath>),
+}
 impl<
     'a,
     P: Proposition,
@@ -48,26 +65,70 @@ impl<
 {
     pub fn new(proof: SequentialProofAtPath<'a, P, Rule, ParentPath>) -> Self {
         match proof.into() {
-            SequentialProofAtPathEnum::Inference(inference) => {
-                Self(Either::Left(InferenceValidityStepper::new(inference)))
-            }
-            SequentialProofAtPathEnum::Composite(composite) => {
-                Self(Either::Right(CompositeProofValidityStepper::new(composite)))
+            SequentialProofAtPathEnum::Inference(inference) => Self(
+                ProofValidityStepperInner::Inference(InferenceValidityStepper::new(inference)),
+            ),
+            SequentialProofAtPathEnum::Composite(composite) => Self(
+                ProofValidityStepperInner::Composite(CompositeProofValidityStepper::new(composite)),
+            ),
+            SequentialProofAtPathEnum::Citation(citation) => Self(
+                ProofValidityStepperInner::Citation(CitationValidityStepper::new(citation)),
+            ),
+            SequentialProofAtPathEnum::Hypothetical(hypothetical) => {
+                Self(ProofValidityStepperInner::Hypothetical(
+                    HypotheticalProofValidityStepper::new(hypothetical),
+                ))
             }
         }
     }
 
     pub fn step(&mut self) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
-        match &mut self.0 {
-            Either::Left(inference_stepper) => inference_stepper.step(),
-            Either::Right(composite_stepper) => composite_stepper.step(),
+        self.step_observed(&mut ())
+    }
+
+    /// Take a single step, informing the provided observer of what is checked and of any error found
+    pub fn step_observed<
+        O: ProofValidityObserver<P, Rule, ParentPath> + ProofValidityObserver<P, Rule, JoinedPath>,
+    >(
+        &mut self,
+        observer: &mut O,
+    ) -> ProofValidityStepResultWrapper<P, Rule::Err, ParentPath, JoinedPath> {
+        let result = match &mut self.0 {
+            ProofValidityStepperInner::Inference(inference_stepper) => {
+                inference_stepper.step_observed(observer)
+            }
+            ProofValidityStepperInner::Composite(composite_stepper) => {
+                composite_stepper.step_observed(observer)
+            }
+            ProofValidityStepperInner::Citation(citation_stepper) => citation_stepper.step(),
+            ProofValidityStepperInner::Hypothetical(hypothetical_stepper) => {
+                hypothetical_stepper.step_observed(observer)
+            }
+        };
+        match &result.next_result {
+            Ok(()) => {}
+            Err(ProofValidityStepErr::InParent(err)) => {
+                ProofValidityObserver::<P, Rule, ParentPath>::error(observer, &err.path, &err.obj)
+            }
+            Err(ProofValidityStepErr::InChild(err)) => {
+                ProofValidityObserver::<P, Rule, JoinedPath>::error(observer, &err.path, &err.obj)
+            }
         }
+        result
     }
 
     pub fn is_finished(&self) -> bool {
         match &self.0 {
-            Either::Left(inference_stepper) => inference_stepper.is_finished(),
-            Either::Right(composite_stepper) => composite_stepper.is_finished(),
+            ProofValidityStepperInner::Inference(inference_stepper) => {
+                inference_stepper.is_finished()
+            }
+            ProofValidityStepperInner::Composite(composite_stepper) => {
+                composite_stepper.is_finished()
+            }
+            ProofValidityStepperInner::Citation(citation_stepper) => citation_stepper.is_finished(),
+            ProofValidityStepperInner::Hypothetical(hypothetical_stepper) => {
+                hypothetical_stepper.is_finished()
+            }
         }
     }
 }
@@ -88,12 +149,44 @@ impl<
         match result.next_result {
             Ok(()) => {
                 if result.is_finished {
-                    Some(Ok(()))
-                } else {
                     None
+                } else {
+                    Some(Ok(()))
                 }
             }
             error => Some(error),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use path_lib::obj_at_path::ObjAtPath;
+
+    use super::*;
+    use crate::{
+        proofs::sequential::subproofs::SequentialProofInProofPath,
+        test_helpers::{TestRule, composite_step, inference_step},
+    };
+
+    #[test]
+    fn test_iterator_yields_every_step_then_ends() {
+        let proof = composite_step(
+            &[1],
+            vec![
+                inference_step(TestRule::Invalid, &[1], 2),
+                inference_step(TestRule::Invalid, &[2], 3),
+            ],
+            &[3],
+        );
+        let stepper: ProofValidityStepper<_, _, _, SequentialProofInProofPath> =
+            ProofValidityStepper::new(ObjAtPath {
+                obj: &proof,
+                path: SequentialProofInProofPath(vec![]),
+            });
+        let results: Vec<_> = stepper.collect();
+        // Each inference is checked after its assumptions, and the final check of the conclusions ends iteration
+        assert_eq!(results.len(), 4);
+        assert_eq!(results.iter().filter(|result| result.is_err()).count(), 2);
+    }
+}
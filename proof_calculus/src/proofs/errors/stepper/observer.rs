@@ -0,0 +1,234 @@
+use std::{
+    io::Write,
+    time::{Duration, Instant},
+};
+
+use path_lib::obj_at_path::OwnedObjAtPath;
+
+use crate::{
+    proofs::{
+        errors::{ValidatableInferenceRule, validity::ProofValidityError},
+        inferences::Inference,
+    },
+    propositions::types::assigned::{
+        Proposition, collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+/// Callbacks which a [ProofValidityStepper](super::ProofValidityStepper) makes as it checks a proof, each given the path of the step concerned
+/// Every callback does nothing by default
+pub trait ProofValidityObserver<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> {
+    /// The stepper has started checking a composite proof; if it is resumed from a checkpoint, this is called again for each composite it is within
+    fn enter_composite(&mut self, _path: &Path) {}
+    /// The stepper has finished checking a composite proof
+    fn leave_composite(&mut self, _path: &Path) {}
+    /// The stepper has checked that the assumptions of a step have been proved
+    fn check_assumptions(&mut self, _path: &Path, _assumptions: &HashPropSet1O<P>) {}
+    /// The stepper is about to validate an inference
+    fn start_inference(&mut self, _path: &Path, _inference: &Inference<P, Rule>) {}
+    /// The stepper has validated an inference, whether or not it was valid
+    fn finish_inference(&mut self, _path: &Path, _inference: &Inference<P, Rule>) {}
+    /// The stepper has found an error
+    fn error(&mut self, _path: &Path, _error: &ProofValidityError<P, Rule::Err>) {}
+}
+/// Observes nothing
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> ProofValidityObserver<P, Rule, Path>
+    for ()
+{
+}
+
+/// Draws a progress bar, which advances as each inference is validated
+pub struct ProgressBarObserver<W: Write> {
+    writer: W,
+    inference_count: usize,
+    validated: usize,
+    width: usize,
+    drawn: usize,
+}
+impl<W: Write> ProgressBarObserver<W> {
+    /// Create a progress bar for a proof containing the provided number of inferences, which can be found with [SequentialProof::statistics](crate::proofs::sequential::SequentialProof::statistics)
+    pub fn new(writer: W, inference_count: usize, width: usize) -> Self {
+        Self {
+            writer,
+            inference_count,
+            validated: 0,
+            width,
+            drawn: usize::MAX,
+        }
+    }
+
+    pub fn validated(&self) -> usize {
+        self.validated
+    }
+
+    /// Redraw the bar, if it has grown since it was last drawn
+    fn draw(&mut self) {
+        let filled = match self.inference_count {
+            0 => self.width,
+            count => self.width * self.validated.min(count) / count,
+        };
+        if filled == self.drawn {
+            return;
+        }
+        self.drawn = filled;
+        // Progress reporting must not interrupt verification, so failures to write are ignored
+        let _ = write!(
+            self.writer,
+            "\r[{}{}] {}/{}",
+            "#".repeat(filled),
+            " ".repeat(self.width - filled),
+            self.validated,
+            self.inference_count
+        );
+        let _ = self.writer.flush();
+    }
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path, W: Write>
+    ProofValidityObserver<P, Rule, Path> for ProgressBarObserver<W>
+{
+    fn enter_composite(&mut self, _path: &Path) {
+        self.draw();
+    }
+    fn finish_inference(&mut self, _path: &Path, _inference: &Inference<P, Rule>) {
+        self.validated += 1;
+        self.draw();
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct RuleTiming {
+    pub inference_count: usize,
+    pub total: Duration,
+}
+
+/// Records how long the inferences of each [InferenceRule](crate::proofs::inferences::InferenceRule) take to validate
+pub struct RuleTimingObserver<Rule> {
+    timings: Vec<(Rule, RuleTiming)>,
+    started: Option<Instant>,
+}
+impl<Rule> Default for RuleTimingObserver<Rule> {
+    fn default() -> Self {
+        Self {
+            timings: Vec::new(),
+            started: None,
+        }
+    }
+}
+impl<Rule> RuleTimingObserver<Rule> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the timing of every rule used so far, in order of first use
+    pub fn timings(&self) -> &[(Rule, RuleTiming)] {
+        &self.timings
+    }
+    /// Get the timing of every rule used so far, with the rules which took the most time in total first
+    pub fn slowest_rules(&self) -> Vec<&(Rule, RuleTiming)> {
+        let mut timings: Vec<_> = self.timings.iter().collect();
+        timings.sort_by(|a, b| b.1.total.cmp(&a.1.total));
+        timings
+    }
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> ProofValidityObserver<P, Rule, Path>
+    for RuleTimingObserver<Rule>
+{
+    fn start_inference(&mut self, _path: &Path, _inference: &Inference<P, Rule>) {
+        self.started = Some(Instant::now());
+    }
+    fn finish_inference(&mut self, _path: &Path, inference: &Inference<P, Rule>) {
+        let Some(started) = self.started.take() else {
+            return;
+        };
+        let elapsed = started.elapsed();
+        let timing = match self
+            .timings
+            .iter_mut()
+            .find(|(rule, _)| rule == &inference.inference_type)
+        {
+            Some((_, timing)) => timing,
+            None => {
+                self.timings
+                    .push((inference.inference_type.clone(), RuleTiming::default()));
+                &mut self.timings.last_mut().unwrap().1
+            }
+        };
+        timing.inference_count += 1;
+        timing.total += elapsed;
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A single event within a [TraceObserver]'s log
+pub enum ProofValidityEvent<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> {
+    EnterComposite(Path),
+    LeaveComposite(Path),
+    CheckAssumptions {
+        path: Path,
+        assumption_count: usize,
+    },
+    ValidateInference {
+        path: Path,
+        rule: Rule,
+        elapsed: Duration,
+    },
+    Error(OwnedObjAtPath<ProofValidityError<P, Rule::Err>, Path>),
+}
+
+/// Records every event during verification, in the order they occurred
+pub struct TraceObserver<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> {
+    pub events: Vec<ProofValidityEvent<P, Rule, Path>>,
+    started: Option<Instant>,
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> Default
+    for TraceObserver<P, Rule, Path>
+{
+    fn default() -> Self {
+        Self {
+            events: Vec::new(),
+            started: None,
+        }
+    }
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path> TraceObserver<P, Rule, Path> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Path: Clone>
+    ProofValidityObserver<P, Rule, Path> for TraceObserver<P, Rule, Path>
+{
+    fn enter_composite(&mut self, path: &Path) {
+        self.events
+            .push(ProofValidityEvent::EnterComposite(path.clone()));
+    }
+    fn leave_composite(&mut self, path: &Path) {
+        self.events
+            .push(ProofValidityEvent::LeaveComposite(path.clone()));
+    }
+    fn check_assumptions(&mut self, path: &Path, assumptions: &HashPropSet1O<P>) {
+        self.events.push(ProofValidityEvent::CheckAssumptions {
+            path: path.clone(),
+            assumption_count: assumptions.len(),
+        });
+    }
+    fn start_inference(&mut self, _path: &Path, _inference: &Inference<P, Rule>) {
+        self.started = Some(Instant::now());
+    }
+    fn finish_inference(&mut self, path: &Path, inference: &Inference<P, Rule>) {
+        self.events.push(ProofValidityEvent::ValidateInference {
+            path: path.clone(),
+            rule: inference.inference_type.clone(),
+            elapsed: self
+                .started
+                .take()
+                .map_or(Duration::ZERO, |started| started.elapsed()),
+        });
+    }
+    fn error(&mut self, path: &Path, error: &ProofValidityError<P, Rule::Err>) {
+        self.events.push(ProofValidityEvent::Error(OwnedObjAtPath {
+            obj: error.clone(),
+            path: path.clone(),
+        }));
+    }
+}
@@ -1,9 +1,15 @@
-use std::marker::PhantomData;
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
 
 use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
 
 use crate::{
-    proofs::sequential::errors::{ErrorInProof, OwnedErrorInProof},
+    proofs::sequential::{
+        citation::TheoremCitation,
+        errors::{ErrorInProof, OwnedErrorInProof},
+    },
     propositions::types::assigned::{
         Proposition, collections::sets::implementations::hash::HashPropSet1O,
     },
@@ -14,6 +20,87 @@ pub enum ProofValidityError<P: Proposition, InferenceErr> {
     AssumptionsNotFound(HashPropSet1O<P>),
     ConclusionsNotFound(HashPropSet1O<P>),
     InvalidInference(InferenceErr, PhantomData<P>),
+    InvalidCitation(CitationValidityError<P>),
+    /// A hypothetical subproof's discharge rule cannot discharge hypotheses
+    CannotDischarge,
+    /// A hypothetical subproof concludes the contained propositions, but its discharge rule does not prove them
+    ConclusionsNotDischarged(HashPropSet1O<P>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum CitationValidityError<P: Proposition> {
+    /// The citation has not been checked, as no [ProofLibrary](crate::proofs::library::ProofLibrary) was provided
+    Unchecked(TheoremCitation<P>),
+    /// No theorem with the contained name exists
+    UnknownTheorem(String),
+    /// The cited theorem assumes the contained propositions, but the citation does not
+    AssumptionsNotStated(HashPropSet1O<P>),
+    /// The citation concludes the contained propositions, but the cited theorem does not
+    ConclusionsNotProved(HashPropSet1O<P>),
+}
+
+impl<P: Proposition + Debug, InferenceErr: Debug> Display for ProofValidityError<P, InferenceErr> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofValidityError::AssumptionsNotFound(assumptions) => write!(
+                f,
+                "assumes propositions which are not available: {}",
+                list_propositions(assumptions)
+            ),
+            ProofValidityError::ConclusionsNotFound(conclusions) => write!(
+                f,
+                "concludes propositions which it does not prove: {}",
+                list_propositions(conclusions)
+            ),
+            ProofValidityError::InvalidInference(err, _) => {
+                write!(f, "is not a valid inference: {:?}", err)
+            }
+            ProofValidityError::InvalidCitation(err) => write!(f, "{}", err),
+            ProofValidityError::CannotDischarge => {
+                write!(f, "uses a discharge rule which cannot discharge hypotheses")
+            }
+            ProofValidityError::ConclusionsNotDischarged(conclusions) => write!(
+                f,
+                "concludes propositions which its discharge rule does not prove: {}",
+                list_propositions(conclusions)
+            ),
+        }
+    }
+}
+
+impl<P: Proposition + Debug> Display for CitationValidityError<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CitationValidityError::Unchecked(citation) => write!(
+                f,
+                "cites {}, but was not checked against a library",
+                citation.theorem
+            ),
+            CitationValidityError::UnknownTheorem(theorem) => {
+                write!(f, "cites {}, which is not a known theorem", theorem)
+            }
+            CitationValidityError::AssumptionsNotStated(assumptions) => write!(
+                f,
+                "does not state assumptions of the theorem it cites: {}",
+                list_propositions(assumptions)
+            ),
+            CitationValidityError::ConclusionsNotProved(conclusions) => write!(
+                f,
+                "concludes propositions which the theorem it cites does not: {}",
+                list_propositions(conclusions)
+            ),
+        }
+    }
+}
+
+fn list_propositions<P: Proposition + Debug>(propositions: &HashPropSet1O<P>) -> String {
+    // The set has no fixed order, so the propositions are sorted to keep the report the same between runs
+    let mut formatted: Vec<_> = propositions
+        .iter()
+        .map(|proposition| format!("{:?}", proposition))
+        .collect();
+    formatted.sort();
+    formatted.join(", ")
 }
 
 pub type ProofValidityErrorAtPath<'a, P: Proposition, InferenceErr, Path> =
@@ -0,0 +1,154 @@
+use path_lib::obj_at_path::OwnedObjAtPath;
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule,
+            stepper::result::ProofValidityStepErr,
+            validate_discharge,
+            validity::{CitationValidityError, ProofValidityError},
+        },
+        inferences::Inference,
+        sequential::{
+            SequentialProof,
+            composite::CompositeSequentialProof,
+            subproofs::{
+                SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
+            },
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+pub(super) type WalkedValidityStepErr<P, IE> =
+    ProofValidityStepErr<P, IE, (), SequentialProofInProofPath>;
+
+/// Receives the checks found by [walk_proof_validity], in the order that [ProofValidityStepper](super::stepper::ProofValidityStepper) would perform them
+pub(super) trait ProofValidityVisitor<'a, P: Proposition, Rule: ValidatableInferenceRule<P>> {
+    /// Called before each subproof is walked, with the propositions proved before it; returning false skips the subproof
+    fn enter(
+        &mut self,
+        _proof: &'a SequentialProof<P, Rule>,
+        _path: &SequentialProofInProofPath,
+        _available: &HashPropSet1O<P>,
+    ) -> bool {
+        true
+    }
+    /// Called once each subproof which was not skipped has been walked
+    fn exit(&mut self) {}
+    /// Called with each inference, which still needs to be validated
+    fn inference(
+        &mut self,
+        inference: &'a Inference<P, Rule>,
+        path: SequentialProofInProofPath,
+        is_root: bool,
+    );
+    /// Called with each error which is found without validating an inference
+    fn error(&mut self, err: WalkedValidityStepErr<P, Rule::Err>);
+}
+
+/// Walk the provided proof, passing every check that it needs to the visitor
+pub(super) fn walk_proof_validity<'a, P, Rule, V>(
+    proof: &'a SequentialProof<P, Rule>,
+    visitor: &mut V,
+) where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    V: ProofValidityVisitor<'a, P, Rule>,
+{
+    let available = HashPropSet1O::from_iter(proof.get_assumptions_owned());
+    walk_subproof(
+        proof,
+        SequentialProofInProofPath(vec![]),
+        &available,
+        true,
+        visitor,
+    );
+}
+
+fn walk_subproof<'a, P, Rule, V>(
+    proof: &'a SequentialProof<P, Rule>,
+    path: SequentialProofInProofPath,
+    available: &HashPropSet1O<P>,
+    is_root: bool,
+    visitor: &mut V,
+) where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    V: ProofValidityVisitor<'a, P, Rule>,
+{
+    if !visitor.enter(proof, &path, available) {
+        return;
+    }
+    match proof {
+        SequentialProof::Inference(inference) => visitor.inference(inference, path, is_root),
+        SequentialProof::Composite(composite) => walk_composite(composite, path, is_root, visitor),
+        SequentialProof::Hypothetical(hypothetical) => {
+            walk_composite(&hypothetical.body, path.clone(), is_root, visitor);
+            if let Err(err) = validate_discharge(hypothetical) {
+                visitor.error(locate_error(err, path, is_root));
+            }
+        }
+        SequentialProof::Citation(citation) => visitor.error(locate_error(
+            ProofValidityError::InvalidCitation(CitationValidityError::Unchecked(citation.clone())),
+            path,
+            is_root,
+        )),
+    }
+    visitor.exit();
+}
+
+fn walk_composite<'a, P, Rule, V>(
+    composite: &'a CompositeSequentialProof<P, Rule>,
+    path: SequentialProofInProofPath,
+    is_root: bool,
+    visitor: &mut V,
+) where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    V: ProofValidityVisitor<'a, P, Rule>,
+{
+    let mut proved = HashPropSet1O::from_iter(composite.assumptions.iter().cloned());
+    for (index, subproof) in composite.subproofs.iter().enumerate() {
+        let subproof_path: SequentialProofInProofPath =
+            (path.clone(), ImmediateSequentialProofInProofPath(index)).into();
+        // Check that this step's assumptions have already been proved
+        let premises = HashPropSet1O::from_iter(subproof.get_assumptions_owned());
+        let assumptions_not_found = &premises - &proved;
+        if assumptions_not_found.len() > 0 {
+            visitor.error(ProofValidityStepErr::InChild(OwnedObjAtPath {
+                obj: ProofValidityError::AssumptionsNotFound(assumptions_not_found),
+                path: subproof_path.clone(),
+            }));
+        }
+        walk_subproof(subproof, subproof_path, &proved, false, visitor);
+        proved.extend(subproof.get_explicit_conclusions_owned());
+    }
+    // Check that this proof's conclusions have been proved
+    let conclusions = HashPropSet1O::from_iter(composite.explicit_conclusions.iter().cloned());
+    let conclusions_not_found = &conclusions - &proved;
+    if conclusions_not_found.len() > 0 {
+        visitor.error(locate_error(
+            ProofValidityError::ConclusionsNotFound(conclusions_not_found),
+            path,
+            is_root,
+        ));
+    }
+}
+
+/// Locate an error found within a subproof, matching the paths reported by [get_proof_validity_errors](super::get_proof_validity_errors)
+pub(super) fn locate_error<P: Proposition, IE: Clone>(
+    err: ProofValidityError<P, IE>,
+    path: SequentialProofInProofPath,
+    is_root: bool,
+) -> WalkedValidityStepErr<P, IE> {
+    let located = OwnedObjAtPath { obj: err, path };
+    if is_root {
+        ProofValidityStepErr::InParent(located.replace_path(|_| ()))
+    } else {
+        ProofValidityStepErr::InChild(located)
+    }
+}
@@ -12,13 +12,13 @@ pub mod located;
 
 pub trait InferenceRule<P: Proposition>: 'static + Clone + PartialEq {}
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 /// A struct representing a single inference step within a proof
 /// Every [Inference] must be an instance of a particular [InferenceRule]; The tbl_verification crate allows this to be validated.
 pub struct Inference<P: Proposition, Rule: InferenceRule<P>> {
     pub inference_type: Rule,
     pub assumptions: Box<[P]>,
-    pub conclusion: P,
+    pub conclusions: Box<[P]>,
 }
 
 impl<P: Proposition, Rule: InferenceRule<P>> ParentOfAssumptions<P> for Inference<P, Rule> {
@@ -59,25 +59,21 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfExplicitConclusions<P> for
     fn get_explicit_conclusion_paths(
         &self,
     ) -> impl IntoIterator<Item = ExplicitConclusionInSequentialProofStepPath> {
-        [ExplicitConclusionInSequentialProofStepPath(0)]
+        (0..self.conclusions.len()).map(|n| ExplicitConclusionInSequentialProofStepPath(n))
     }
 
     fn get_explicit_conclusion(
         &self,
         path: &ExplicitConclusionInSequentialProofStepPath,
     ) -> Result<&P, ()> {
-        if path.0 == 0 {
-            Ok(&self.conclusion)
-        } else {
-            Err(())
-        }
+        self.conclusions.get(path.0).ok_or(())
     }
 
     fn get_explicit_conclusions<'a>(&'a self) -> impl IntoIterator<Item = &'a P>
     where
         P: 'a,
     {
-        [&self.conclusion]
+        &self.conclusions
     }
 
     fn into_located_explicit_conclusions_owned(
@@ -86,10 +82,13 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfExplicitConclusions<P> for
     where
         Self: Sized,
     {
-        [OwnedObjAtPath {
-            obj: self.conclusion,
-            path: ExplicitConclusionInSequentialProofStepPath(0),
-        }]
+        self.conclusions
+            .into_iter()
+            .enumerate()
+            .map(|(id, conclusion)| OwnedObjAtPath {
+                obj: conclusion,
+                path: ExplicitConclusionInSequentialProofStepPath(id),
+            })
     }
 }
 
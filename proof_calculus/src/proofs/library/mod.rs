@@ -0,0 +1,480 @@
+use std::collections::HashMap;
+
+use path_lib::obj_at_path::OwnedObjAtPath;
+
+use crate::{
+    proofs::{
+        errors::{
+            ValidatableInferenceRule, get_proof_validity_errors,
+            stepper::result::ProofValidityStepErr,
+            validity::{CitationValidityError, ProofValidityError},
+        },
+        inferences::InferenceRule,
+        sequential::{
+            SequentialProof, citation::TheoremCitation, composite::CompositeSequentialProof,
+            hypothetical::HypotheticalSequentialProof, subproofs::SequentialProofInProofPath,
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+type LibraryValidityStepErr<P, IE> = ProofValidityStepErr<P, IE, (), SequentialProofInProofPath>;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A theorem stating that its conclusions follow from its assumptions, alongside a proof of this
+pub struct LibraryTheorem<P: Proposition, Rule: InferenceRule<P>> {
+    pub assumptions: Box<[P]>,
+    pub conclusions: Box<[P]>,
+    pub proof: SequentialProof<P, Rule>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LibraryInsertionError<P: Proposition, InferenceErr: Clone> {
+    /// The library already contains a theorem with the contained name
+    DuplicateName(String),
+    /// The theorem's proof makes use of the contained assumptions, but the theorem does not state them
+    AssumptionsNotStated(HashPropSet1O<P>),
+    /// The theorem states the contained conclusions, but its proof does not explicitly conclude them
+    ConclusionsNotProved(HashPropSet1O<P>),
+    /// The theorem's proof is not valid
+    InvalidProof(Box<[LibraryValidityStepErr<P, InferenceErr>]>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A collection of named theorems, each of which has been verified
+/// Proofs may cite theorems within the library through [SequentialProof::Citation] steps, rather than containing a proof of them
+pub struct ProofLibrary<P: Proposition, Rule: InferenceRule<P>> {
+    theorems: HashMap<String, LibraryTheorem<P, Rule>>,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Default for ProofLibrary<P, Rule> {
+    fn default() -> Self {
+        Self {
+            theorems: HashMap::new(),
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> ProofLibrary<P, Rule> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&LibraryTheorem<P, Rule>> {
+        self.theorems.get(name)
+    }
+    pub fn contains(&self, name: &str) -> bool {
+        self.theorems.contains_key(name)
+    }
+    pub fn len(&self) -> usize {
+        self.theorems.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.theorems.is_empty()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &LibraryTheorem<P, Rule>)> {
+        self.theorems.iter()
+    }
+
+    /// Check that the provided citation names a theorem within this library, and that the theorem justifies it
+    /// A theorem justifies a citation if the citation states every assumption of the theorem, and only concludes conclusions of the theorem
+    pub fn check_citation(
+        &self,
+        citation: &TheoremCitation<P>,
+    ) -> Result<(), CitationValidityError<P>> {
+        let theorem = self
+            .theorems
+            .get(&citation.theorem)
+            .ok_or_else(|| CitationValidityError::UnknownTheorem(citation.theorem.clone()))?;
+        let stated = HashPropSet1O::from_iter(citation.assumptions.iter().cloned());
+        let assumptions_not_stated: HashPropSet1O<P> = theorem
+            .assumptions
+            .iter()
+            .filter(|assumption| !stated.contains(*assumption))
+            .cloned()
+            .collect();
+        if assumptions_not_stated.len() > 0 {
+            return Err(CitationValidityError::AssumptionsNotStated(
+                assumptions_not_stated,
+            ));
+        }
+        let proved = HashPropSet1O::from_iter(theorem.conclusions.iter().cloned());
+        let conclusions_not_proved: HashPropSet1O<P> = citation
+            .conclusions
+            .iter()
+            .filter(|conclusion| !proved.contains(*conclusion))
+            .cloned()
+            .collect();
+        if conclusions_not_proved.len() > 0 {
+            return Err(CitationValidityError::ConclusionsNotProved(
+                conclusions_not_proved,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Replace every citation within the provided proof with a composite proof containing the cited theorem's proof, so that the result does not depend upon this library
+    pub fn expand_citations(
+        &self,
+        proof: &SequentialProof<P, Rule>,
+    ) -> Result<SequentialProof<P, Rule>, CitationValidityError<P>> {
+        match proof {
+            SequentialProof::Inference(_) => Ok(proof.clone()),
+            SequentialProof::Composite(composite) => Ok(SequentialProof::Composite(
+                self.expand_composite_citations(composite)?,
+            )),
+            SequentialProof::Hypothetical(hypothetical) => {
+                Ok(SequentialProof::Hypothetical(HypotheticalSequentialProof {
+                    hypotheses: hypothetical.hypotheses.clone(),
+                    body: self.expand_composite_citations(&hypothetical.body)?,
+                    discharge_rule: hypothetical.discharge_rule.clone(),
+                    conclusions: hypothetical.conclusions.clone(),
+                }))
+            }
+            SequentialProof::Citation(citation) => {
+                self.check_citation(citation)?;
+                let theorem = &self.theorems[&citation.theorem];
+                Ok(SequentialProof::Composite(CompositeSequentialProof {
+                    assumptions: citation.assumptions.clone(),
+                    subproofs: Box::new([self.expand_citations(&theorem.proof)?]),
+                    explicit_conclusions: citation.conclusions.clone(),
+                }))
+            }
+        }
+    }
+
+    fn expand_composite_citations(
+        &self,
+        composite: &CompositeSequentialProof<P, Rule>,
+    ) -> Result<CompositeSequentialProof<P, Rule>, CitationValidityError<P>> {
+        Ok(CompositeSequentialProof {
+            assumptions: composite.assumptions.clone(),
+            subproofs: composite
+                .subproofs
+                .iter()
+                .map(|subproof| self.expand_citations(subproof))
+                .collect::<Result<_, _>>()?,
+            explicit_conclusions: composite.explicit_conclusions.clone(),
+        })
+    }
+}
+
+// Feature: Verification
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>> ProofLibrary<P, Rule> {
+    /// Verify the validity of the provided proof, checking any citations it contains against this library
+    pub fn verify_proof_validity(
+        &self,
+        proof: &SequentialProof<P, Rule>,
+    ) -> Result<(), LibraryValidityStepErr<P, Rule::Err>> {
+        match self.get_proof_validity_errors(proof).next() {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Get every validity error within the provided proof, checking any citations it contains against this library
+    /// The theorems within this library were verified when they were inserted, so they are not verified again
+    pub fn get_proof_validity_errors<'a>(
+        &'a self,
+        proof: &'a SequentialProof<P, Rule>,
+    ) -> impl Iterator<Item = LibraryValidityStepErr<P, Rule::Err>> {
+        get_proof_validity_errors(proof)
+            .filter_map(Result::err)
+            .filter_map(|err| match err {
+                ProofValidityStepErr::InParent(err) => self
+                    .check_unchecked_citation(err)
+                    .map(ProofValidityStepErr::InParent),
+                ProofValidityStepErr::InChild(err) => self
+                    .check_unchecked_citation(err)
+                    .map(ProofValidityStepErr::InChild),
+            })
+    }
+
+    /// Check an error reporting an unchecked citation against this library, returning [None] if the citation is justified
+    fn check_unchecked_citation<Path>(
+        &self,
+        err: OwnedObjAtPath<ProofValidityError<P, Rule::Err>, Path>,
+    ) -> Option<OwnedObjAtPath<ProofValidityError<P, Rule::Err>, Path>> {
+        match err.obj {
+            ProofValidityError::InvalidCitation(CitationValidityError::Unchecked(citation)) => {
+                match self.check_citation(&citation) {
+                    Ok(()) => None,
+                    Err(citation_err) => Some(OwnedObjAtPath {
+                        obj: ProofValidityError::InvalidCitation(citation_err),
+                        path: err.path,
+                    }),
+                }
+            }
+            obj => Some(OwnedObjAtPath {
+                obj,
+                path: err.path,
+            }),
+        }
+    }
+
+    /// Verify the provided theorem, and add it to this library under the provided name if it is valid
+    /// The theorem's proof may cite any theorem already within the library
+    pub fn insert(
+        &mut self,
+        name: String,
+        theorem: LibraryTheorem<P, Rule>,
+    ) -> Result<(), LibraryInsertionError<P, Rule::Err>> {
+        if self.theorems.contains_key(&name) {
+            return Err(LibraryInsertionError::DuplicateName(name));
+        }
+        let stated = HashPropSet1O::from_iter(theorem.assumptions.iter().cloned());
+        let assumptions_not_stated =
+            &HashPropSet1O::from_iter(theorem.proof.get_assumptions_owned()) - &stated;
+        if assumptions_not_stated.len() > 0 {
+            return Err(LibraryInsertionError::AssumptionsNotStated(
+                assumptions_not_stated,
+            ));
+        }
+        let proved = HashPropSet1O::from_iter(theorem.proof.get_explicit_conclusions_owned());
+        let conclusions_not_proved =
+            &HashPropSet1O::from_iter(theorem.conclusions.iter().cloned()) - &proved;
+        if conclusions_not_proved.len() > 0 {
+            return Err(LibraryInsertionError::ConclusionsNotProved(
+                conclusions_not_proved,
+            ));
+        }
+        let errors: Box<[_]> = self.get_proof_validity_errors(&theorem.proof).collect();
+        if errors.len() > 0 {
+            return Err(LibraryInsertionError::InvalidProof(errors));
+        }
+        self.theorems.insert(name, theorem);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proofs::errors::verify_proof_validity,
+        test_helpers::{TestProp, TestRule, composite_step, inference_step, props},
+    };
+
+    fn citation_step(
+        theorem: &str,
+        assumptions: &[u32],
+        conclusions: &[u32],
+    ) -> SequentialProof<TestProp, TestRule> {
+        SequentialProof::Citation(TheoremCitation {
+            theorem: theorem.into(),
+            assumptions: props(assumptions),
+            conclusions: props(conclusions),
+        })
+    }
+    fn theorem(
+        assumptions: &[u32],
+        conclusions: &[u32],
+        proof: SequentialProof<TestProp, TestRule>,
+    ) -> LibraryTheorem<TestProp, TestRule> {
+        LibraryTheorem {
+            assumptions: props(assumptions),
+            conclusions: props(conclusions),
+            proof,
+        }
+    }
+    /// A library in which the second theorem cites the first
+    fn library() -> ProofLibrary<TestProp, TestRule> {
+        let mut library = ProofLibrary::new();
+        library
+            .insert(
+                "first".into(),
+                theorem(
+                    &[1],
+                    &[3],
+                    composite_step(
+                        &[1],
+                        vec![
+                            inference_step(TestRule::Valid, &[1], 2),
+                            inference_step(TestRule::Valid, &[2], 3),
+                        ],
+                        &[3],
+                    ),
+                ),
+            )
+            .unwrap();
+        library
+            .insert(
+                "second".into(),
+                theorem(
+                    &[1],
+                    &[4],
+                    composite_step(
+                        &[1],
+                        vec![
+                            citation_step("first", &[1], &[3]),
+                            inference_step(TestRule::Valid, &[3], 4),
+                        ],
+                        &[4],
+                    ),
+                ),
+            )
+            .unwrap();
+        library
+    }
+
+    #[test]
+    fn test_insert_errors() {
+        let mut library = library();
+        assert!(matches!(
+            library.insert(
+                "first".into(),
+                theorem(&[1], &[2], inference_step(TestRule::Valid, &[1], 2))
+            ),
+            Err(LibraryInsertionError::DuplicateName(name)) if name == "first"
+        ));
+        assert_eq!(
+            library.insert(
+                "unstated".into(),
+                theorem(&[], &[2], inference_step(TestRule::Valid, &[1], 2))
+            ),
+            Err(LibraryInsertionError::AssumptionsNotStated(
+                HashPropSet1O::from_iter([TestProp(1)])
+            ))
+        );
+        assert_eq!(
+            library.insert(
+                "unproved".into(),
+                theorem(&[1], &[2, 5], inference_step(TestRule::Valid, &[1], 2))
+            ),
+            Err(LibraryInsertionError::ConclusionsNotProved(
+                HashPropSet1O::from_iter([TestProp(5)])
+            ))
+        );
+        assert!(matches!(
+            library.insert(
+                "invalid".into(),
+                theorem(&[1], &[2], inference_step(TestRule::Invalid, &[1], 2))
+            ),
+            Err(LibraryInsertionError::InvalidProof(_))
+        ));
+        assert!(matches!(
+            library.insert(
+                "unknown".into(),
+                theorem(&[1], &[2], citation_step("missing", &[1], &[2]))
+            ),
+            Err(LibraryInsertionError::InvalidProof(_))
+        ));
+        assert_eq!(library.len(), 2);
+    }
+
+    #[test]
+    fn test_check_citation() {
+        let library = library();
+        let citation = |theorem: &str, assumptions: &[u32], conclusions: &[u32]| TheoremCitation {
+            theorem: theorem.into(),
+            assumptions: props(assumptions),
+            conclusions: props(conclusions),
+        };
+        assert_eq!(
+            library.check_citation(&citation("second", &[1, 5], &[4])),
+            Ok(())
+        );
+        assert_eq!(
+            library.check_citation(&citation("missing", &[1], &[4])),
+            Err(CitationValidityError::UnknownTheorem("missing".into()))
+        );
+        assert_eq!(
+            library.check_citation(&citation("second", &[5], &[4])),
+            Err(CitationValidityError::AssumptionsNotStated(
+                HashPropSet1O::from_iter([TestProp(1)])
+            ))
+        );
+        assert_eq!(
+            library.check_citation(&citation("second", &[1], &[4, 7])),
+            Err(CitationValidityError::ConclusionsNotProved(
+                HashPropSet1O::from_iter([TestProp(7)])
+            ))
+        );
+    }
+
+    #[test]
+    fn test_verify_with_citations() {
+        let library = library();
+        let proof = composite_step(
+            &[1, 5],
+            vec![
+                citation_step("second", &[1, 5], &[4]),
+                inference_step(TestRule::Valid, &[4], 6),
+            ],
+            &[6],
+        );
+        // Without a library, citations cannot be checked
+        assert!(matches!(
+            verify_proof_validity(&proof),
+            Err(ProofValidityStepErr::InChild(err))
+                if matches!(err.obj, ProofValidityError::InvalidCitation(CitationValidityError::Unchecked(_)))
+        ));
+        assert_eq!(library.verify_proof_validity(&proof), Ok(()));
+
+        let invalid = composite_step(
+            &[1],
+            vec![
+                citation_step("second", &[], &[4]),
+                citation_step("missing", &[], &[]),
+            ],
+            &[],
+        );
+        assert_eq!(library.get_proof_validity_errors(&invalid).count(), 2);
+    }
+
+    #[test]
+    fn test_expand_citations() {
+        let library = library();
+        let proof = composite_step(
+            &[1, 5],
+            vec![
+                citation_step("second", &[1, 5], &[4]),
+                inference_step(TestRule::Valid, &[4], 6),
+            ],
+            &[6],
+        );
+        let expanded = library.expand_citations(&proof).unwrap();
+        // Both the cited theorem and the theorem it cites are expanded, so nothing depends upon the library
+        assert_eq!(verify_proof_validity(&expanded), Ok(()));
+        assert_eq!(
+            expanded,
+            composite_step(
+                &[1, 5],
+                vec![
+                    composite_step(
+                        &[1, 5],
+                        vec![composite_step(
+                            &[1],
+                            vec![
+                                composite_step(
+                                    &[1],
+                                    vec![composite_step(
+                                        &[1],
+                                        vec![
+                                            inference_step(TestRule::Valid, &[1], 2),
+                                            inference_step(TestRule::Valid, &[2], 3),
+                                        ],
+                                        &[3],
+                                    )],
+                                    &[3],
+                                ),
+                                inference_step(TestRule::Valid, &[3], 4),
+                            ],
+                            &[4],
+                        )],
+                        &[4],
+                    ),
+                    inference_step(TestRule::Valid, &[4], 6),
+                ],
+                &[6],
+            )
+        );
+        assert_eq!(
+            library.expand_citations(&citation_step("missing", &[], &[])),
+            Err(CitationValidityError::UnknownTheorem("missing".into()))
+        );
+    }
+}
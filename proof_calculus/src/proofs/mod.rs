@@ -1,7 +1,12 @@
 pub mod r#abstract;
+pub mod content;
 pub mod inferences;
+pub mod library;
 pub mod sequential;
+pub mod statistics;
 // Feature: Verification
 pub mod assertions;
 // Feature: Verification
 pub mod errors;
+// Feature: Verification
+pub mod search;
@@ -0,0 +1,805 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    marker::PhantomData,
+};
+
+use crate::{
+    proofs::{
+        errors::{ValidatableInferenceRule, validate_inference},
+        inferences::{Inference, InferenceRule},
+        search::{ProofSearchBudget, ProofSearchError},
+        sequential::{SequentialProof, composite::CompositeSequentialProof},
+    },
+    propositions::{
+        assignments::{PartialPropositionalAssignment, UnifiableProposition},
+        types::{
+            assigned::{
+                Proposition,
+                collections::sets::{PropSet1O, implementations::hash::HashPropSet1O},
+            },
+            unassigned::UnassignedProposition,
+        },
+    },
+};
+
+/// Supplies the instances of inference rules which a [BackwardChainingProver] may use to prove a goal
+pub trait PremiseProposer<P: Proposition, Rule: ValidatableInferenceRule<P>> {
+    /// Get inferences which conclude the provided goal; the assumptions of each become goals in turn
+    /// The known facts are those the prover has assumed or proved so far, which may be used to fix assumptions the goal does not
+    /// Inferences which do not conclude the goal, or which are not valid, are ignored by the prover
+    fn propose<'a>(
+        &self,
+        goal: &P,
+        known: impl Iterator<Item = &'a P> + Clone,
+    ) -> impl IntoIterator<Item = Inference<P, Rule>>
+    where
+        P: 'a;
+}
+impl<P, Rule, F, I> PremiseProposer<P, Rule> for F
+where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    F: Fn(&P) -> I,
+    I: IntoIterator<Item = Inference<P, Rule>>,
+{
+    fn propose<'a>(
+        &self,
+        goal: &P,
+        _known: impl Iterator<Item = &'a P> + Clone,
+    ) -> impl IntoIterator<Item = Inference<P, Rule>>
+    where
+        P: 'a,
+    {
+        self(goal)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// An inference rule stated over unassigned propositions, which can conclude any proposition its conclusion can be assigned to
+pub struct InferenceSchema<Uprop: UnassignedProposition, Rule> {
+    pub inference_type: Rule,
+    pub assumptions: Box<[Uprop]>,
+    pub conclusion: Uprop,
+}
+
+impl<Uprop: UnassignedProposition, Rule: Clone> InferenceSchema<Uprop, Rule> {
+    /// Get the instances of this schema which conclude the provided goal
+    /// The conclusion is unified with the goal, and any assumption which that leaves partially assigned is an open goal, which is unified with each of the known facts in turn
+    /// Assumptions which are fully assigned are left for the prover to prove, so only those using variables the conclusion does not fix must already be known
+    pub fn instantiate<'a, P, Assignment>(
+        &self,
+        goal: &P,
+        known: impl Iterator<Item = &'a P> + Clone,
+    ) -> Vec<Inference<P, Rule>>
+    where
+        P: Proposition + TryFrom<Uprop> + 'a,
+        Uprop: From<P> + UnifiableProposition<Assignment>,
+        Assignment: PartialPropositionalAssignment<Uprop, Uprop>,
+        Rule: InferenceRule<P>,
+    {
+        let mut instances = Vec::new();
+        let Ok(assignment) = self.conclusion.unify(&Uprop::from(goal.clone())) else {
+            return instances;
+        };
+        if let Some(premises) = assign_all(&assignment, &self.assumptions) {
+            self.close_premises(premises, goal, known, &mut HashSet::new(), &mut instances);
+        }
+        instances
+    }
+
+    /// Unify the open premises with each of the known facts, closing the remaining premises under each unifier, and record an instance once every premise is fully assigned
+    /// Each set of premises is closed only once, however many orders of unification reach it
+    fn close_premises<'a, P, Assignment>(
+        &self,
+        premises: Vec<Uprop>,
+        goal: &P,
+        known: impl Iterator<Item = &'a P> + Clone,
+        closed: &mut HashSet<Vec<Uprop>>,
+        instances: &mut Vec<Inference<P, Rule>>,
+    ) where
+        P: Proposition + TryFrom<Uprop> + 'a,
+        Uprop: From<P> + UnifiableProposition<Assignment>,
+        Assignment: PartialPropositionalAssignment<Uprop, Uprop>,
+        Rule: InferenceRule<P>,
+    {
+        if !closed.insert(premises.clone()) {
+            return;
+        }
+        let mut assumptions = Vec::with_capacity(premises.len());
+        let mut open = Vec::new();
+        for (index, premise) in premises.iter().enumerate() {
+            match P::try_from(premise.clone()) {
+                Ok(assumption) => assumptions.push(assumption),
+                Err(_) => open.push(index),
+            }
+        }
+        if open.is_empty() {
+            instances.push(Inference {
+                inference_type: self.inference_type.clone(),
+                assumptions: assumptions.into(),
+                conclusions: Box::new([goal.clone()]),
+            });
+            return;
+        }
+        // Unifying one open premise may fix the variables of the others, in which case they become goals rather than needing to be known, so each is tried first in turn
+        for index in open {
+            for fact in known.clone() {
+                let Ok(unifier) = premises[index].unify(&Uprop::from(fact.clone())) else {
+                    continue;
+                };
+                if let Some(next) = assign_all(&unifier, &premises) {
+                    self.close_premises(next, goal, known.clone(), closed, instances);
+                }
+            }
+        }
+    }
+}
+
+/// Apply the assignment to each unassigned proposition, failing if it cannot be applied to any of them
+fn assign_all<
+    Uprop: UnassignedProposition,
+    Assignment: PartialPropositionalAssignment<Uprop, Uprop>,
+>(
+    assignment: &Assignment,
+    uprops: &[Uprop],
+) -> Option<Vec<Uprop>> {
+    uprops
+        .iter()
+        .map(|uprop| assignment.assign(uprop).ok())
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// Proposes the instances of each schema which conclude a goal, unifying with the provided kind of [PartialPropositionalAssignment]
+pub struct SchemaProposer<Uprop: UnassignedProposition, Rule, Assignment> {
+    pub schemas: Vec<InferenceSchema<Uprop, Rule>>,
+    assignment: PhantomData<Assignment>,
+}
+
+impl<Uprop: UnassignedProposition, Rule, Assignment> SchemaProposer<Uprop, Rule, Assignment> {
+    pub fn new(schemas: Vec<InferenceSchema<Uprop, Rule>>) -> Self {
+        Self {
+            schemas,
+            assignment: PhantomData,
+        }
+    }
+}
+
+impl<P, Rule, Uprop, Assignment> PremiseProposer<P, Rule>
+    for SchemaProposer<Uprop, Rule, Assignment>
+where
+    P: Proposition + TryFrom<Uprop>,
+    Rule: ValidatableInferenceRule<P>,
+    Uprop: From<P> + UnifiableProposition<Assignment>,
+    Assignment: PartialPropositionalAssignment<Uprop, Uprop>,
+{
+    fn propose<'a>(
+        &self,
+        goal: &P,
+        known: impl Iterator<Item = &'a P> + Clone,
+    ) -> impl IntoIterator<Item = Inference<P, Rule>>
+    where
+        P: 'a,
+    {
+        self.schemas
+            .iter()
+            .flat_map(move |schema| schema.instantiate(goal, known.clone()))
+    }
+}
+
+/// Why a goal could not be proved
+enum GoalFailure {
+    Unprovable {
+        /// Whether the depth limit prevented a premise from being searched
+        depth_limited: bool,
+        /// Whether a premise was not searched because it is already being searched for further up, in which case the failure depends upon the path taken to the goal
+        cyclic: bool,
+    },
+    BudgetExhausted,
+}
+
+/// Proves goals from a set of assumptions by searching for inferences which conclude them, and then proving their assumptions in turn, depth first
+/// Goals which have been proved, and goals which could not be proved, are remembered, so the prover can be asked to prove further goals from the same assumptions
+pub struct BackwardChainingProver<
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    Pr: PremiseProposer<P, Rule>,
+> {
+    assumptions: HashPropSet1O<P>,
+    /// For each goal which has been proved, the index of the inference which proved it, or [None] if it was assumed
+    proved: HashMap<P, Option<usize>>,
+    inferences: Vec<Inference<P, Rule>>,
+    /// For each goal which could not be proved, the greatest depth to which it was searched, or [usize::MAX] if it cannot be proved at any depth
+    failed: HashMap<P, usize>,
+    proposer: Pr,
+}
+
+impl<P: Proposition, Rule: ValidatableInferenceRule<P>, Pr: PremiseProposer<P, Rule>>
+    BackwardChainingProver<P, Rule, Pr>
+{
+    pub fn new<PS: PropSet1O<P>>(assumptions: &PS, proposer: Pr) -> Self {
+        Self {
+            assumptions: assumptions.iter().cloned().collect(),
+            proved: HashMap::new(),
+            inferences: Vec::new(),
+            failed: HashMap::new(),
+            proposer,
+        }
+    }
+
+    pub fn is_proved(&self, goal: &P) -> bool {
+        self.proved.contains_key(goal)
+    }
+    /// Whether the goal has been found not to be provable at any depth
+    pub fn is_unprovable(&self, goal: &P) -> bool {
+        self.failed.get(goal) == Some(&usize::MAX)
+    }
+
+    /// Search for a proof of the goal whose inferences are nested no more than the provided depth, or at any depth if there is no limit
+    /// The proof only contains the assumptions and inferences which the goal depends upon, so it passes [verify_proof_soundness](crate::proofs::errors::soundness::verify_proof_soundness) for the assumptions the prover started from
+    pub fn prove(
+        &mut self,
+        goal: &P,
+        max_depth: Option<usize>,
+        budget: &ProofSearchBudget,
+    ) -> Result<CompositeSequentialProof<P, Rule>, ProofSearchError> {
+        let mut steps_taken = 0;
+        self.prove_to_depth(
+            goal,
+            max_depth.unwrap_or(usize::MAX),
+            budget,
+            &mut steps_taken,
+        )
+    }
+
+    /// Search for a proof of the goal with increasing depth limits, up to the provided depth, so that the shallowest proof is found first
+    /// The budget is shared between every search, and failures found at shallower limits are remembered by deeper ones
+    pub fn prove_iteratively(
+        &mut self,
+        goal: &P,
+        max_depth: Option<usize>,
+        budget: &ProofSearchBudget,
+    ) -> Result<CompositeSequentialProof<P, Rule>, ProofSearchError> {
+        let mut steps_taken = 0;
+        let mut depth = 0;
+        loop {
+            match self.prove_to_depth(goal, depth, budget, &mut steps_taken) {
+                Err(ProofSearchError::DepthLimitReached)
+                    if max_depth.is_none_or(|max_depth| depth < max_depth) =>
+                {
+                    depth += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Get a proof of the provided goal from the assumptions, if it has already been proved
+    pub fn derivation(&self, goal: &P) -> Option<CompositeSequentialProof<P, Rule>> {
+        self.proved.get(goal)?;
+        // Inferences are recorded only once all of their assumptions are proved, so ordering them by index ensures each comes after those it depends upon
+        let mut assumptions = Vec::new();
+        let mut inferences = BTreeSet::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![goal];
+        while let Some(fact) = to_visit.pop() {
+            if !visited.insert(fact) {
+                continue;
+            }
+            match self.proved[fact] {
+                None => assumptions.push(fact.clone()),
+                Some(inference_index) => {
+                    if inferences.insert(inference_index) {
+                        to_visit.extend(self.inferences[inference_index].assumptions.iter());
+                    }
+                }
+            }
+        }
+        Some(CompositeSequentialProof {
+            assumptions: assumptions.into(),
+            subproofs: inferences
+                .into_iter()
+                .map(|index| SequentialProof::Inference(self.inferences[index].clone()))
+                .collect(),
+            explicit_conclusions: Box::new([goal.clone()]),
+        })
+    }
+
+    fn prove_to_depth(
+        &mut self,
+        goal: &P,
+        max_depth: usize,
+        budget: &ProofSearchBudget,
+        steps_taken: &mut usize,
+    ) -> Result<CompositeSequentialProof<P, Rule>, ProofSearchError> {
+        match self.search(goal, max_depth, budget, steps_taken, &mut HashSet::new()) {
+            Ok(()) => Ok(self
+                .derivation(goal)
+                .expect("Goals which have been searched for successfully are proved")),
+            Err(GoalFailure::Unprovable {
+                depth_limited: true,
+                ..
+            }) => Err(ProofSearchError::DepthLimitReached),
+            Err(GoalFailure::Unprovable { .. }) => Err(ProofSearchError::Saturated),
+            Err(GoalFailure::BudgetExhausted) => Err(ProofSearchError::BudgetExhausted),
+        }
+    }
+
+    /// Prove the goal using inferences nested no more than the provided depth, without searching for any goal in the provided path
+    fn search(
+        &mut self,
+        goal: &P,
+        depth: usize,
+        budget: &ProofSearchBudget,
+        steps_taken: &mut usize,
+        path: &mut HashSet<P>,
+    ) -> Result<(), GoalFailure> {
+        if self.proved.contains_key(goal) {
+            return Ok(());
+        }
+        if self.assumptions.contains(goal) {
+            self.proved.insert(goal.clone(), None);
+            return Ok(());
+        }
+        match self.failed.get(goal) {
+            Some(searched_depth) if *searched_depth >= depth => {
+                return Err(GoalFailure::Unprovable {
+                    depth_limited: *searched_depth != usize::MAX,
+                    cyclic: false,
+                });
+            }
+            _ => {}
+        }
+        if path.contains(goal) {
+            return Err(GoalFailure::Unprovable {
+                depth_limited: false,
+                cyclic: true,
+            });
+        }
+        if depth == 0 {
+            return Err(GoalFailure::Unprovable {
+                depth_limited: true,
+                cyclic: false,
+            });
+        }
+        if budget.is_exhausted(*steps_taken, self.proved.len()) {
+            return Err(GoalFailure::BudgetExhausted);
+        }
+        *steps_taken += 1;
+
+        let known = self.assumptions.iter().chain(self.proved.keys());
+        let candidates: Vec<_> = self.proposer.propose(goal, known).into_iter().collect();
+        path.insert(goal.clone());
+        let mut depth_limited = false;
+        let mut cyclic = false;
+        for inference in candidates {
+            if !inference.conclusions.contains(goal) || validate_inference(&inference).is_err() {
+                continue;
+            }
+            let mut premises_proved = true;
+            for premise in inference.assumptions.iter() {
+                match self.search(premise, depth - 1, budget, steps_taken, path) {
+                    Ok(()) => {}
+                    Err(GoalFailure::Unprovable {
+                        depth_limited: premise_depth_limited,
+                        cyclic: premise_cyclic,
+                    }) => {
+                        depth_limited |= premise_depth_limited;
+                        cyclic |= premise_cyclic;
+                        premises_proved = false;
+                        break;
+                    }
+                    Err(GoalFailure::BudgetExhausted) => {
+                        path.remove(goal);
+                        return Err(GoalFailure::BudgetExhausted);
+                    }
+                }
+            }
+            if premises_proved {
+                path.remove(goal);
+                let inference_index = self.inferences.len();
+                for conclusion in inference.conclusions.iter() {
+                    self.proved
+                        .entry(conclusion.clone())
+                        .or_insert(Some(inference_index));
+                }
+                self.inferences.push(inference);
+                return Ok(());
+            }
+        }
+        path.remove(goal);
+        // A failure which depends upon the path taken to the goal may not recur when the goal is reached another way, so it is not remembered
+        if !cyclic {
+            let searched_depth = if depth_limited { depth } else { usize::MAX };
+            let entry = self.failed.entry(goal.clone()).or_default();
+            *entry = searched_depth.max(*entry);
+        }
+        Err(GoalFailure::Unprovable {
+            depth_limited,
+            cyclic,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        proofs::errors::soundness::verify_proof_soundness,
+        propositions::types::normalised_unassigned::NormalisedUnassignedProposition,
+        test_helpers::{TestProp, TestRule, inference},
+        utils::traits::combinable::TryCombine,
+    };
+
+    /// Prove a goal from the one before it, from two copies of its half, or from the one after it, which leads to cycles
+    /// An invalid inference concluding the goal from nothing is proposed first, and must be ignored
+    fn count(goal: &TestProp) -> Vec<Inference<TestProp, TestRule>> {
+        let mut proposals = vec![inference(TestRule::Invalid, &[], goal.0)];
+        if goal.0 > 0 {
+            proposals.push(inference(TestRule::Valid, &[goal.0 - 1], goal.0));
+        }
+        if goal.0 > 0 && goal.0.is_multiple_of(2) {
+            proposals.push(inference(
+                TestRule::Valid,
+                &[goal.0 / 2, goal.0 / 2],
+                goal.0,
+            ));
+        }
+        proposals.push(inference(TestRule::Valid, &[goal.0 + 1], goal.0));
+        proposals
+    }
+    fn assumptions() -> HashPropSet1O<TestProp> {
+        HashPropSet1O::from_iter([TestProp(1)])
+    }
+
+    #[test]
+    fn test_prove_is_sound() {
+        let assumptions = assumptions();
+        let mut prover = BackwardChainingProver::new(&assumptions, count);
+        let proof = prover
+            .prove(&TestProp(8), Some(10), &ProofSearchBudget::unlimited())
+            .unwrap();
+        verify_proof_soundness(&SequentialProof::Composite(proof.clone()), &assumptions).unwrap();
+        assert!(prover.is_proved(&TestProp(8)));
+        assert_eq!(prover.derivation(&TestProp(8)), Some(proof));
+        // Every goal proved along the way has a sound derivation of its own
+        for goal in 1..8 {
+            let derivation = prover.derivation(&TestProp(goal)).unwrap();
+            verify_proof_soundness(&SequentialProof::Composite(derivation), &assumptions).unwrap();
+        }
+        assert_eq!(prover.derivation(&TestProp(9)), None);
+    }
+
+    #[test]
+    fn test_prove_iteratively_finds_shallowest_proof() {
+        let assumptions = assumptions();
+        // Searching depth first counts up one at a time
+        let deep = BackwardChainingProver::new(&assumptions, count)
+            .prove(&TestProp(8), None, &ProofSearchBudget::unlimited())
+            .unwrap();
+        assert_eq!(deep.subproofs.len(), 7);
+        // The shallowest proof doubles 1 up to 8
+        let shallow = BackwardChainingProver::new(&assumptions, count)
+            .prove_iteratively(&TestProp(8), None, &ProofSearchBudget::unlimited())
+            .unwrap();
+        assert_eq!(
+            shallow.subproofs.as_ref(),
+            [
+                SequentialProof::Inference(inference(TestRule::Valid, &[1], 2)),
+                SequentialProof::Inference(inference(TestRule::Valid, &[2, 2], 4)),
+                SequentialProof::Inference(inference(TestRule::Valid, &[4, 4], 8)),
+            ]
+        );
+        verify_proof_soundness(&SequentialProof::Composite(shallow), &assumptions).unwrap();
+    }
+
+    #[test]
+    fn test_limits() {
+        let assumptions = assumptions();
+        let mut prover = BackwardChainingProver::new(&assumptions, count);
+        assert_eq!(
+            prover.prove(&TestProp(8), Some(2), &ProofSearchBudget::unlimited()),
+            Err(ProofSearchError::DepthLimitReached)
+        );
+        assert_eq!(
+            prover.prove_iteratively(&TestProp(8), Some(2), &ProofSearchBudget::unlimited()),
+            Err(ProofSearchError::DepthLimitReached)
+        );
+        // A goal which failed at a shallower depth can still be proved at a deeper one
+        assert!(!prover.is_unprovable(&TestProp(8)));
+        assert_eq!(
+            prover.prove_iteratively(&TestProp(64), None, &ProofSearchBudget::steps(3)),
+            Err(ProofSearchError::BudgetExhausted)
+        );
+        assert!(
+            prover
+                .prove_iteratively(&TestProp(8), Some(3), &ProofSearchBudget::unlimited())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_saturated() {
+        let assumptions = HashPropSet1O::from_iter([TestProp(5)]);
+        let count_down = |goal: &TestProp| match goal.0 > 5 {
+            true => vec![inference(TestRule::Valid, &[goal.0 - 1], goal.0)],
+            false => vec![],
+        };
+        let mut prover = BackwardChainingProver::new(&assumptions, count_down);
+        assert_eq!(
+            prover.prove(&TestProp(3), None, &ProofSearchBudget::unlimited()),
+            Err(ProofSearchError::Saturated)
+        );
+        assert!(prover.is_unprovable(&TestProp(3)));
+        assert!(
+            prover
+                .prove(&TestProp(9), None, &ProofSearchBudget::unlimited())
+                .is_ok()
+        );
+        assert!(prover.is_proved(&TestProp(7)));
+    }
+
+    #[test]
+    fn test_cyclic_goal_is_not_remembered_as_failed() {
+        // 10 is proved from 20 or from 1, but 20 is only proved from 10
+        let propose = |goal: &TestProp| match goal.0 {
+            10 => vec![
+                inference(TestRule::Valid, &[20], 10),
+                inference(TestRule::Valid, &[1], 10),
+            ],
+            20 => vec![inference(TestRule::Valid, &[10], 20)],
+            _ => vec![],
+        };
+        let assumptions = assumptions();
+        let mut prover = BackwardChainingProver::new(&assumptions, propose);
+        // Searching for 10 searches for 20, which fails only because 10 is already being searched for
+        assert!(
+            prover
+                .prove(&TestProp(10), None, &ProofSearchBudget::unlimited())
+                .is_ok()
+        );
+        assert!(!prover.is_unprovable(&TestProp(20)));
+        let proof = prover
+            .prove(&TestProp(20), None, &ProofSearchBudget::unlimited())
+            .unwrap();
+        verify_proof_soundness(&SequentialProof::Composite(proof), &assumptions).unwrap();
+    }
+
+    /// A formula built from numbered atoms and implication
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    enum Formula {
+        Atom(u32),
+        Implies(Box<Formula>, Box<Formula>),
+    }
+    impl Proposition for Formula {}
+    /// A [Formula] which may also contain numbered variables
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    enum Pattern {
+        Variable(u32),
+        Atom(u32),
+        Implies(Box<Pattern>, Box<Pattern>),
+    }
+    impl UnassignedProposition for Pattern {
+        type DefaultNormalisation = Pattern;
+
+        fn normalise(self) -> Self::DefaultNormalisation {
+            self
+        }
+    }
+    impl NormalisedUnassignedProposition for Pattern {
+        type Inner = Pattern;
+
+        fn inner(&self) -> &Self::Inner {
+            self
+        }
+        fn into_inner(self) -> Self::Inner {
+            self
+        }
+    }
+    impl From<Formula> for Pattern {
+        fn from(formula: Formula) -> Self {
+            match formula {
+                Formula::Atom(atom) => Pattern::Atom(atom),
+                Formula::Implies(a, b) => {
+                    Pattern::Implies(Box::new(Pattern::from(*a)), Box::new(Pattern::from(*b)))
+                }
+            }
+        }
+    }
+    impl TryFrom<Pattern> for Formula {
+        type Error = ();
+
+        fn try_from(pattern: Pattern) -> Result<Self, Self::Error> {
+            match pattern {
+                Pattern::Variable(_) => Err(()),
+                Pattern::Atom(atom) => Ok(Formula::Atom(atom)),
+                Pattern::Implies(a, b) => Ok(Formula::Implies(
+                    Box::new(Formula::try_from(*a)?),
+                    Box::new(Formula::try_from(*b)?),
+                )),
+            }
+        }
+    }
+
+    #[derive(Default, Debug)]
+    struct Substitution(HashMap<u32, Pattern>);
+    impl Substitution {
+        fn apply(&self, pattern: &Pattern) -> Pattern {
+            match pattern {
+                Pattern::Variable(variable) => match self.0.get(variable) {
+                    Some(assigned) => self.apply(assigned),
+                    None => pattern.clone(),
+                },
+                Pattern::Atom(_) => pattern.clone(),
+                Pattern::Implies(a, b) => {
+                    Pattern::Implies(Box::new(self.apply(a)), Box::new(self.apply(b)))
+                }
+            }
+        }
+        fn unify(&mut self, a: &Pattern, b: &Pattern) -> bool {
+            match (self.apply(a), self.apply(b)) {
+                (a, b) if a == b => true,
+                (Pattern::Variable(variable), other) | (other, Pattern::Variable(variable)) => {
+                    self.0.insert(variable, other);
+                    true
+                }
+                (Pattern::Implies(a1, b1), Pattern::Implies(a2, b2)) => {
+                    self.unify(&a1, &a2) && self.unify(&b1, &b2)
+                }
+                _ => false,
+            }
+        }
+    }
+    impl TryCombine for Substitution {
+        type CombinationError = ();
+
+        fn try_combine<I: IntoIterator<Item = Self>>(_: I) -> Result<Self, Self::CombinationError> {
+            Err(())
+        }
+    }
+    impl PartialPropositionalAssignment<Pattern, Pattern> for Substitution {
+        type AssignmentError = ();
+        type ReverseAssignmentError = ();
+
+        fn assign(&self, pattern: &Pattern) -> Result<Pattern, Self::AssignmentError> {
+            Ok(self.apply(pattern))
+        }
+        fn reverse_assign(_: &Pattern, _: &Pattern) -> Result<Self, Self::ReverseAssignmentError> {
+            Err(())
+        }
+    }
+    impl UnifiableProposition<Substitution> for Pattern {
+        type UnificationError = ();
+
+        fn unify(&self, other: &Self) -> Result<Substitution, Self::UnificationError> {
+            let mut substitution = Substitution::default();
+            match substitution.unify(self, other) {
+                true => Ok(substitution),
+                false => Err(()),
+            }
+        }
+    }
+
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    struct ModusPonens;
+    impl InferenceRule<Formula> for ModusPonens {}
+    impl ValidatableInferenceRule<Formula> for ModusPonens {
+        type Err = ();
+
+        fn validate(inference: &Inference<Formula, Self>) -> Result<(), Self::Err> {
+            match (&*inference.assumptions, &*inference.conclusions) {
+                ([a, Formula::Implies(antecedent, consequent)], [conclusion])
+                    if **antecedent == *a && **consequent == *conclusion =>
+                {
+                    Ok(())
+                }
+                _ => Err(()),
+            }
+        }
+    }
+
+    fn implies(a: Formula, b: Formula) -> Formula {
+        Formula::Implies(Box::new(a), Box::new(b))
+    }
+    /// From `a` and `a -> b`, conclude `b`; the variable `a` does not appear in the conclusion
+    fn modus_ponens() -> InferenceSchema<Pattern, ModusPonens> {
+        InferenceSchema {
+            inference_type: ModusPonens,
+            assumptions: Box::new([
+                Pattern::Variable(0),
+                Pattern::Implies(
+                    Box::new(Pattern::Variable(0)),
+                    Box::new(Pattern::Variable(1)),
+                ),
+            ]),
+            conclusion: Pattern::Variable(1),
+        }
+    }
+
+    #[test]
+    fn test_modus_ponens() {
+        let assumptions = HashPropSet1O::from_iter([
+            Formula::Atom(0),
+            implies(Formula::Atom(0), Formula::Atom(1)),
+            implies(Formula::Atom(1), Formula::Atom(2)),
+        ]);
+        let mut prover = BackwardChainingProver::new(
+            &assumptions,
+            SchemaProposer::<_, _, Substitution>::new(vec![modus_ponens()]),
+        );
+        let proof = prover
+            .prove(&Formula::Atom(2), Some(5), &ProofSearchBudget::unlimited())
+            .unwrap();
+        assert_eq!(proof.subproofs.len(), 2);
+        verify_proof_soundness(&SequentialProof::Composite(proof), &assumptions).unwrap();
+        assert!(
+            prover
+                .prove(&Formula::Atom(3), Some(5), &ProofSearchBudget::unlimited())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_instantiate_closes_open_premises_from_known_facts() {
+        let known = [
+            Formula::Atom(0),
+            implies(Formula::Atom(0), Formula::Atom(1)),
+            implies(Formula::Atom(3), Formula::Atom(1)),
+        ];
+        let instances: Vec<Inference<Formula, ModusPonens>> =
+            modus_ponens().instantiate::<_, Substitution>(&Formula::Atom(1), known.iter());
+        // Either premise may be unified with a known fact first, but each instance is only found once
+        let assumptions: HashSet<_> = instances
+            .iter()
+            .map(|instance| instance.assumptions.clone())
+            .collect();
+        assert_eq!(instances.len(), assumptions.len());
+        assert_eq!(
+            assumptions,
+            HashSet::from([
+                Box::from([
+                    Formula::Atom(0),
+                    implies(Formula::Atom(0), Formula::Atom(1))
+                ]),
+                Box::from([
+                    Formula::Atom(3),
+                    implies(Formula::Atom(3), Formula::Atom(1))
+                ]),
+                Box::from([
+                    implies(Formula::Atom(0), Formula::Atom(1)),
+                    implies(
+                        implies(Formula::Atom(0), Formula::Atom(1)),
+                        Formula::Atom(1)
+                    ),
+                ]),
+                Box::from([
+                    implies(Formula::Atom(3), Formula::Atom(1)),
+                    implies(
+                        implies(Formula::Atom(3), Formula::Atom(1)),
+                        Formula::Atom(1)
+                    ),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_instantiate_many_open_premises() {
+        // Three premises whose variables are independent, so each is closed with each known fact
+        let schema = InferenceSchema {
+            inference_type: ModusPonens,
+            assumptions: Box::new([
+                Pattern::Variable(0),
+                Pattern::Variable(1),
+                Pattern::Variable(2),
+            ]),
+            conclusion: Pattern::Atom(9),
+        };
+        let known = [Formula::Atom(0), Formula::Atom(1), Formula::Atom(2)];
+        let instances: Vec<Inference<Formula, ModusPonens>> =
+            schema.instantiate::<_, Substitution>(&Formula::Atom(9), known.iter());
+        assert_eq!(instances.len(), 27);
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use crate::{
+    propositions::types::assigned::{Proposition, binding::binders::InsertBinderForProp},
+    utils::collections::binding::binders::Binder,
+};
+
+/// The facts known during a proof search, each identified by the order in which it became known
+/// Every fact is inserted into a [Binder] with its index as the value, so that facts of a particular shape can be found quickly
+pub struct KnownFacts<P: Proposition, B: Binder<Value = usize>> {
+    facts: Vec<P>,
+    indices: HashMap<P, usize>,
+    binder: B,
+}
+
+impl<P: Proposition, B: Binder<Value = usize>> KnownFacts<P, B> {
+    /// Create an empty set of facts, which will be indexed by the provided binder
+    pub fn new(binder: B) -> Self {
+        Self {
+            facts: Vec::new(),
+            indices: HashMap::new(),
+            binder,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.facts.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.facts.is_empty()
+    }
+    pub fn facts(&self) -> &[P] {
+        &self.facts
+    }
+    pub fn get(&self, index: usize) -> Option<&P> {
+        self.facts.get(index)
+    }
+    pub fn contains(&self, fact: &P) -> bool {
+        self.indices.contains_key(fact)
+    }
+    pub fn index_of(&self, fact: &P) -> Option<usize> {
+        self.indices.get(fact).copied()
+    }
+    /// Get the binder indexing these facts; each value it holds is the index of a fact
+    pub fn binder(&self) -> &B {
+        &self.binder
+    }
+}
+
+impl<P: Proposition, B: Binder<Value = usize> + for<'elem> InsertBinderForProp<'elem, P>>
+    KnownFacts<P, B>
+{
+    /// Add the provided fact, returning its index, or [None] if it was already known
+    pub fn insert(&mut self, fact: P) -> Option<usize> {
+        if self.contains(&fact) {
+            return None;
+        }
+        let index = self.facts.len();
+        self.binder.insert_prop(&fact, index);
+        self.indices.insert(fact.clone(), index);
+        self.facts.push(fact);
+        Some(index)
+    }
+}
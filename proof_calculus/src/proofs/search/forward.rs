@@ -0,0 +1,362 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use crate::{
+    proofs::{
+        errors::{ValidatableInferenceRule, validate_inference},
+        inferences::Inference,
+        search::{ProofSearchBudget, ProofSearchError, facts::KnownFacts},
+        sequential::{SequentialProof, composite::CompositeSequentialProof},
+    },
+    propositions::types::assigned::{
+        Proposition, binding::binders::InsertBinderForProp, collections::sets::PropSet1O,
+    },
+    utils::collections::binding::binders::Binder,
+};
+
+/// Supplies the instances of inference rules which a [ForwardChainingProver] may apply
+pub trait RuleApplicator<
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    B: Binder<Value = usize>,
+>
+{
+    /// Get inferences which assume the provided fact, and whose other assumptions are among the known facts
+    /// Inferences which assume anything not yet known, or which are not valid, are ignored by the prover
+    fn apply(
+        &self,
+        fact: &P,
+        known: &KnownFacts<P, B>,
+    ) -> impl IntoIterator<Item = Inference<P, Rule>>;
+}
+impl<P, Rule, B, F, I> RuleApplicator<P, Rule, B> for F
+where
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    B: Binder<Value = usize>,
+    F: Fn(&P, &KnownFacts<P, B>) -> I,
+    I: IntoIterator<Item = Inference<P, Rule>>,
+{
+    fn apply(
+        &self,
+        fact: &P,
+        known: &KnownFacts<P, B>,
+    ) -> impl IntoIterator<Item = Inference<P, Rule>> {
+        self(fact, known)
+    }
+}
+
+/// Derives new facts from a set of assumptions by repeatedly applying inference rules, until a goal is found
+/// Each known fact is explored once, in the order it became known, so the search is breadth first
+/// The prover keeps everything it has derived, so it can be asked to prove further goals from the same assumptions
+pub struct ForwardChainingProver<
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    B: Binder<Value = usize>,
+    A: RuleApplicator<P, Rule, B>,
+> {
+    known: KnownFacts<P, B>,
+    /// For each known fact, the index of the inference which first derived it, or [None] if it was assumed
+    derived_by: Vec<Option<usize>>,
+    inferences: Vec<Inference<P, Rule>>,
+    /// The indices of known facts whose consequences have not yet been explored
+    agenda: VecDeque<usize>,
+    applicator: A,
+}
+
+impl<
+    P: Proposition,
+    Rule: ValidatableInferenceRule<P>,
+    B: Binder<Value = usize> + for<'elem> InsertBinderForProp<'elem, P>,
+    A: RuleApplicator<P, Rule, B>,
+> ForwardChainingProver<P, Rule, B, A>
+{
+    /// Create a prover which starts from the provided assumptions, indexing the facts it knows with the provided empty binder
+    pub fn new<PS: PropSet1O<P>>(assumptions: &PS, binder: B, applicator: A) -> Self {
+        let mut prover = Self {
+            known: KnownFacts::new(binder),
+            derived_by: Vec::new(),
+            inferences: Vec::new(),
+            agenda: VecDeque::new(),
+            applicator,
+        };
+        for assumption in assumptions.iter() {
+            prover.learn(assumption.clone(), None);
+        }
+        prover
+    }
+
+    pub fn known(&self) -> &KnownFacts<P, B> {
+        &self.known
+    }
+    /// Whether every consequence of the known facts has been explored, so that nothing more can be derived
+    pub fn is_saturated(&self) -> bool {
+        self.agenda.is_empty()
+    }
+
+    /// Explore the consequences of the next known fact, returning the index of every fact newly derived
+    pub fn step(&mut self) -> Vec<usize> {
+        let Some(index) = self.agenda.pop_front() else {
+            return Vec::new();
+        };
+        let fact = self.known.facts()[index].clone();
+        let candidates: Vec<_> = self
+            .applicator
+            .apply(&fact, &self.known)
+            .into_iter()
+            .collect();
+        let mut derived = Vec::new();
+        for inference in candidates {
+            if !self.is_applicable(&inference) {
+                continue;
+            }
+            let inference_index = self.inferences.len();
+            for conclusion in inference.conclusions.iter() {
+                derived.extend(self.learn(conclusion.clone(), Some(inference_index)));
+            }
+            self.inferences.push(inference);
+        }
+        derived
+    }
+
+    /// Explore known facts until the goal is known, then return a proof of the goal from the assumptions
+    /// The proof only contains the assumptions and inferences which the goal depends upon, so it passes [verify_proof_soundness](crate::proofs::errors::soundness::verify_proof_soundness) for the assumptions the prover started from
+    pub fn prove(
+        &mut self,
+        goal: &P,
+        budget: &ProofSearchBudget,
+    ) -> Result<SequentialProof<P, Rule>, ProofSearchError> {
+        let mut steps_taken = 0;
+        loop {
+            if let Some(proof) = self.derivation(goal) {
+                return Ok(proof);
+            }
+            if self.is_saturated() {
+                return Err(ProofSearchError::Saturated);
+            }
+            if budget.is_exhausted(steps_taken, self.known.len()) {
+                return Err(ProofSearchError::BudgetExhausted);
+            }
+            self.step();
+            steps_taken += 1;
+        }
+    }
+
+    /// Explore known facts until nothing more can be derived, or the budget runs out
+    pub fn saturate(&mut self, budget: &ProofSearchBudget) -> Result<(), ProofSearchError> {
+        let mut steps_taken = 0;
+        while !self.is_saturated() {
+            if budget.is_exhausted(steps_taken, self.known.len()) {
+                return Err(ProofSearchError::BudgetExhausted);
+            }
+            self.step();
+            steps_taken += 1;
+        }
+        Ok(())
+    }
+
+    /// Get a proof of the provided fact from the assumptions, if it is already known
+    pub fn derivation(&self, goal: &P) -> Option<SequentialProof<P, Rule>> {
+        let goal_index = self.known.index_of(goal)?;
+        // Inferences are recorded only once all of their assumptions are known, so ordering them by index ensures each comes after those it depends upon
+        let mut assumptions = BTreeSet::new();
+        let mut inferences = BTreeSet::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![goal_index];
+        while let Some(index) = to_visit.pop() {
+            if !visited.insert(index) {
+                continue;
+            }
+            match self.derived_by[index] {
+                None => {
+                    assumptions.insert(index);
+                }
+                Some(inference_index) => {
+                    if inferences.insert(inference_index) {
+                        to_visit.extend(self.inferences[inference_index].assumptions.iter().map(
+                            |assumption| {
+                                self.known
+                                    .index_of(assumption)
+                                    .expect("Recorded inferences only assume known facts")
+                            },
+                        ));
+                    }
+                }
+            }
+        }
+        Some(SequentialProof::Composite(CompositeSequentialProof {
+            assumptions: assumptions
+                .into_iter()
+                .map(|index| self.known.facts()[index].clone())
+                .collect(),
+            subproofs: inferences
+                .into_iter()
+                .map(|index| SequentialProof::Inference(self.inferences[index].clone()))
+                .collect(),
+            explicit_conclusions: Box::new([goal.clone()]),
+        }))
+    }
+
+    /// Whether the provided inference can be applied; it must be valid, assume only known facts, and derive something new
+    fn is_applicable(&self, inference: &Inference<P, Rule>) -> bool {
+        inference
+            .assumptions
+            .iter()
+            .all(|assumption| self.known.contains(assumption))
+            && inference
+                .conclusions
+                .iter()
+                .any(|conclusion| !self.known.contains(conclusion))
+            && validate_inference(inference).is_ok()
+    }
+
+    /// Add a fact to those known, and to the agenda to be explored, returning its index if it was not already known
+    fn learn(&mut self, fact: P, derived_by: Option<usize>) -> Option<usize> {
+        let index = self.known.insert(fact)?;
+        self.derived_by.push(derived_by);
+        self.agenda.push_back(index);
+        Some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, time::Duration};
+
+    use super::*;
+    use crate::{
+        proofs::errors::soundness::verify_proof_soundness,
+        propositions::types::assigned::{
+            binding::bounds::InsertBoundsForProp,
+            collections::sets::implementations::hash::HashPropSet1O,
+        },
+        test_helpers::{TestProp, TestRule},
+        utils::collections::binding::binders::InsertBinder,
+    };
+
+    /// A binder which holds every fact under the same bounds
+    #[derive(Default)]
+    struct AllFactsBinder(HashSet<usize>);
+    impl Binder for AllFactsBinder {
+        type Value = usize;
+
+        fn get_all(&self) -> HashSet<&usize> {
+            self.0.iter().collect()
+        }
+    }
+    struct AnyFact;
+    impl From<&TestProp> for AnyFact {
+        fn from(_: &TestProp) -> Self {
+            AnyFact
+        }
+    }
+    impl InsertBinder<AnyFact> for AllFactsBinder {
+        fn insert_by_bounds(&mut self, _: &AnyFact, value: usize) {
+            self.0.insert(value);
+        }
+    }
+    impl<'elem> InsertBoundsForProp<'elem, TestProp, AllFactsBinder> for AnyFact {}
+    impl<'elem> InsertBinderForProp<'elem, TestProp> for AllFactsBinder {
+        type DefaultInsertionBounds = AnyFact;
+    }
+
+    /// Derive the sum of the fact with each known fact, up to 20, as well as an invalid inference which must be ignored
+    fn add(
+        fact: &TestProp,
+        known: &KnownFacts<TestProp, AllFactsBinder>,
+    ) -> Vec<Inference<TestProp, TestRule>> {
+        known
+            .binder()
+            .get_all()
+            .into_iter()
+            .map(|index| known.get(*index).unwrap())
+            .filter(|other| fact.0 + other.0 <= 20)
+            .map(|other| Inference {
+                inference_type: TestRule::Valid,
+                assumptions: Box::new([fact.clone(), other.clone()]),
+                conclusions: Box::new([TestProp(fact.0 + other.0)]),
+            })
+            .chain([Inference {
+                inference_type: TestRule::Invalid,
+                assumptions: Box::new([fact.clone()]),
+                conclusions: Box::new([TestProp(99)]),
+            }])
+            .collect()
+    }
+    fn assumptions() -> HashPropSet1O<TestProp> {
+        HashPropSet1O::from_iter([TestProp(3), TestProp(5), TestProp(40)])
+    }
+
+    #[test]
+    fn test_saturate() {
+        let assumptions = assumptions();
+        let mut prover = ForwardChainingProver::new(&assumptions, AllFactsBinder::default(), add);
+        assert_eq!(prover.saturate(&ProofSearchBudget::unlimited()), Ok(()));
+        assert!(prover.is_saturated());
+        // Every sum of threes and fives up to 20, alongside the assumption of 40
+        let mut known: Vec<u32> = prover.known().facts().iter().map(|fact| fact.0).collect();
+        known.sort();
+        assert_eq!(
+            known,
+            vec![
+                3, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 40
+            ]
+        );
+        assert_eq!(
+            prover.prove(&TestProp(7), &ProofSearchBudget::unlimited()),
+            Err(ProofSearchError::Saturated)
+        );
+        assert_eq!(
+            prover.prove(&TestProp(99), &ProofSearchBudget::unlimited()),
+            Err(ProofSearchError::Saturated)
+        );
+    }
+
+    #[test]
+    fn test_prove_is_sound() {
+        let assumptions = assumptions();
+        let mut prover = ForwardChainingProver::new(&assumptions, AllFactsBinder::default(), add);
+        for goal in [13, 19, 40] {
+            let proof = prover
+                .prove(&TestProp(goal), &ProofSearchBudget::unlimited())
+                .unwrap();
+            verify_proof_soundness(&proof, &assumptions).unwrap();
+        }
+        // 40 was assumed, so its proof needs no inferences
+        assert_eq!(
+            prover.derivation(&TestProp(40)),
+            Some(SequentialProof::Composite(CompositeSequentialProof {
+                assumptions: Box::new([TestProp(40)]),
+                subproofs: Box::new([]),
+                explicit_conclusions: Box::new([TestProp(40)]),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_budget_limits() {
+        let assumptions = assumptions();
+        for budget in [
+            ProofSearchBudget::steps(1),
+            ProofSearchBudget::facts(4),
+            ProofSearchBudget::time(Duration::ZERO),
+        ] {
+            let mut prover =
+                ForwardChainingProver::new(&assumptions, AllFactsBinder::default(), add);
+            assert_eq!(
+                prover.prove(&TestProp(20), &budget),
+                Err(ProofSearchError::BudgetExhausted),
+                "{budget:?}"
+            );
+            assert_eq!(
+                prover.saturate(&budget),
+                Err(ProofSearchError::BudgetExhausted),
+                "{budget:?}"
+            );
+            // Whatever was derived before the budget ran out is kept
+            let proof = prover
+                .prove(&TestProp(20), &ProofSearchBudget::unlimited())
+                .unwrap();
+            verify_proof_soundness(&proof, &assumptions).unwrap();
+        }
+    }
+}
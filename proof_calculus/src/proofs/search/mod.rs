@@ -0,0 +1,62 @@
+use std::time::{Duration, Instant};
+
+pub mod backward;
+pub mod facts;
+pub mod forward;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+/// Limits on how much work a proof search may do before giving up
+pub struct ProofSearchBudget {
+    /// The greatest number of steps which may be taken
+    /// Forward chaining takes a step for each known fact it explores, and backward chaining for each goal it expands
+    pub max_steps: Option<usize>,
+    /// The greatest number of facts which may be known at once, including the assumptions
+    /// For backward chaining, these are the assumptions and goals which have been proved
+    pub max_facts: Option<usize>,
+    pub deadline: Option<Instant>,
+}
+impl ProofSearchBudget {
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+    pub fn steps(max_steps: usize) -> Self {
+        Self {
+            max_steps: Some(max_steps),
+            ..Self::default()
+        }
+    }
+    pub fn facts(max_facts: usize) -> Self {
+        Self {
+            max_facts: Some(max_facts),
+            ..Self::default()
+        }
+    }
+    pub fn time(duration: Duration) -> Self {
+        Self {
+            deadline: Some(Instant::now() + duration),
+            ..Self::default()
+        }
+    }
+
+    fn is_exhausted(&self, steps_taken: usize, fact_count: usize) -> bool {
+        self.max_steps
+            .is_some_and(|max_steps| steps_taken >= max_steps)
+            || self
+                .max_facts
+                .is_some_and(|max_facts| fact_count >= max_facts)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ProofSearchError {
+    /// Every consequence of the known facts has been explored without finding the goal, so the goal cannot be proved with the rules available
+    /// For backward chaining, every way of proving the goal has been explored
+    Saturated,
+    /// The budget ran out before the goal was found; searching again with a larger budget may find it
+    BudgetExhausted,
+    /// The goal could not be proved within the depth limit; searching again with a greater limit may find a proof
+    DepthLimitReached,
+}
@@ -4,7 +4,8 @@ use crate::{
     proofs::{
         inferences::{Inference, InferenceRule},
         sequential::{
-            SequentialProof, composite::CompositeSequentialProof, subproofs::SequentialProofAtPath,
+            SequentialProof, citation::TheoremCitation, composite::CompositeSequentialProof,
+            hypothetical::HypotheticalSequentialProof, subproofs::SequentialProofAtPath,
         },
     },
     propositions::types::assigned::Proposition,
@@ -13,6 +14,8 @@ use crate::{
 pub enum SequentialProofAtPathEnum<'a, P: Proposition, Path, Rule: InferenceRule<P>> {
     Inference(ObjAtPath<'a, Inference<P, Rule>, Path>),
     Composite(ObjAtPath<'a, CompositeSequentialProof<P, Rule>, Path>),
+    Citation(ObjAtPath<'a, TheoremCitation<P>, Path>),
+    Hypothetical(ObjAtPath<'a, HypotheticalSequentialProof<P, Rule>, Path>),
 }
 impl<'a, P: Proposition, Path, Rule: InferenceRule<P>>
     SequentialProofAtPathEnum<'a, P, Path, Rule>
@@ -21,6 +24,8 @@ impl<'a, P: Proposition, Path, Rule: InferenceRule<P>>
         match self {
             SequentialProofAtPathEnum::Inference(obj_at_path) => &obj_at_path.path,
             SequentialProofAtPathEnum::Composite(obj_at_path) => &obj_at_path.path,
+            SequentialProofAtPathEnum::Citation(obj_at_path) => &obj_at_path.path,
+            SequentialProofAtPathEnum::Hypothetical(obj_at_path) => &obj_at_path.path,
         }
     }
 }
@@ -38,6 +43,14 @@ impl<'a, P: Proposition, Path, Rule: InferenceRule<P>>
                 obj: &composite,
                 path: value.path,
             }),
+            SequentialProof::Citation(citation) => Self::Citation(ObjAtPath {
+                obj: citation,
+                path: value.path,
+            }),
+            SequentialProof::Hypothetical(hypothetical) => Self::Hypothetical(ObjAtPath {
+                obj: hypothetical,
+                path: value.path,
+            }),
         }
     }
 }
@@ -51,6 +64,8 @@ impl<'a, P: Proposition, Path, Rule: InferenceRule<P>>
 pub enum OwnedSequentialProofAtPathEnum<P: Proposition, Path, Rule: InferenceRule<P>> {
     Inference(OwnedObjAtPath<Inference<P, Rule>, Path>),
     Composite(OwnedObjAtPath<CompositeSequentialProof<P, Rule>, Path>),
+    Citation(OwnedObjAtPath<TheoremCitation<P>, Path>),
+    Hypothetical(OwnedObjAtPath<HypotheticalSequentialProof<P, Rule>, Path>),
 }
 impl<P: Proposition, Path, Rule: InferenceRule<P>>
     From<OwnedObjAtPath<SequentialProof<P, Rule>, Path>>
@@ -66,6 +81,14 @@ impl<P: Proposition, Path, Rule: InferenceRule<P>>
                 obj: composite,
                 path: value.path,
             }),
+            SequentialProof::Citation(citation) => Self::Citation(OwnedObjAtPath {
+                obj: citation,
+                path: value.path,
+            }),
+            SequentialProof::Hypothetical(hypothetical) => Self::Hypothetical(OwnedObjAtPath {
+                obj: hypothetical,
+                path: value.path,
+            }),
         }
     }
 }
@@ -83,6 +106,14 @@ impl<P: Proposition, Path, Rule: InferenceRule<P>>
                 obj: SequentialProof::Composite(inner.obj),
                 path: inner.path,
             },
+            Self::Citation(inner) => OwnedObjAtPath {
+                obj: SequentialProof::Citation(inner.obj),
+                path: inner.path,
+            },
+            Self::Hypothetical(inner) => OwnedObjAtPath {
+                obj: SequentialProof::Hypothetical(inner.obj),
+                path: inner.path,
+            },
         }
     }
 }
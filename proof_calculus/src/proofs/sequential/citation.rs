@@ -0,0 +1,65 @@
+use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+
+use crate::{
+    proofs::sequential::subproofs::SequentialProofInProofPath,
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        paths::{AssumptionInSequentialProofStepPath, ExplicitConclusionInSequentialProofStepPath},
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// A step within a proof which cites a named theorem, rather than containing a proof of it
+/// The citation states which propositions it assumes and concludes; a [ProofLibrary](crate::proofs::library::ProofLibrary) can check these against the theorem
+pub struct TheoremCitation<P: Proposition> {
+    pub theorem: String,
+    pub assumptions: Box<[P]>,
+    pub conclusions: Box<[P]>,
+}
+
+impl<P: Proposition> ParentOfAssumptions<P> for TheoremCitation<P> {
+    fn get_assumption_paths(
+        &self,
+    ) -> impl IntoIterator<Item = AssumptionInSequentialProofStepPath> {
+        (0..self.assumptions.len()).map(|n| AssumptionInSequentialProofStepPath::from(n))
+    }
+
+    fn get_assumption(&self, path: &AssumptionInSequentialProofStepPath) -> Result<&P, ()> {
+        self.assumptions.get(path.0).ok_or(())
+    }
+
+    fn get_assumptions<'a>(&'a self) -> impl IntoIterator<Item = &'a P>
+    where
+        P: 'a,
+    {
+        &self.assumptions
+    }
+}
+
+impl<P: Proposition> ParentOfExplicitConclusions<P> for TheoremCitation<P> {
+    fn get_explicit_conclusion_paths(
+        &self,
+    ) -> impl IntoIterator<Item = ExplicitConclusionInSequentialProofStepPath> {
+        (0..self.conclusions.len()).map(|n| ExplicitConclusionInSequentialProofStepPath(n))
+    }
+
+    fn get_explicit_conclusion(
+        &self,
+        path: &ExplicitConclusionInSequentialProofStepPath,
+    ) -> Result<&P, ()> {
+        self.conclusions.get(path.0).ok_or(())
+    }
+
+    fn get_explicit_conclusions<'a>(&'a self) -> impl IntoIterator<Item = &'a P>
+    where
+        P: 'a,
+    {
+        &self.conclusions
+    }
+}
+
+pub type TheoremCitationAtPath<'a, P, Path> = ObjAtPath<'a, TheoremCitation<P>, Path>;
+pub type OwnedTheoremCitationAtPath<P, Path> = OwnedObjAtPath<TheoremCitation<P>, Path>;
+
+pub type TheoremCitationInProof<'a, P> = TheoremCitationAtPath<'a, P, SequentialProofInProofPath>;
+pub type OwnedTheoremCitationInProof<P> = OwnedTheoremCitationAtPath<P, SequentialProofInProofPath>;
@@ -6,7 +6,6 @@ use crate::{
         sequential::{
             AssumptionInSequentialProofStepPath, ParentOfAssumptions, ParentOfExplicitConclusions,
             SequentialProof,
-            at_path_enum::SequentialProofAtPathEnum,
             subproofs::{
                 ParentOfSubproofs, SequentialProofInProofPath,
                 immediate::{ImmediateSequentialProofInProofPath, ParentOfImmediateSubproofs},
@@ -17,7 +16,7 @@ use crate::{
     propositions::types::assigned::paths::ExplicitConclusionInSequentialProofStepPath,
 };
 
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct CompositeSequentialProof<P: Proposition, Rule: InferenceRule<P>> {
     pub assumptions: Box<[P]>,
     pub subproofs: Box<[SequentialProof<P, Rule>]>,
@@ -108,14 +107,12 @@ impl<P: Proposition, Rule: InferenceRule<P>> CompositeSequentialProof<P, Rule> {
     ) -> Result<&SequentialProof<P, Rule>, ()> {
         let immediate_path = path.0.get(index).ok_or(())?;
         let inner = self.get_immediate_subproof(immediate_path)?;
-        if index == path.0.len() {
+        if index + 1 == path.0.len() {
             Ok(inner)
         } else {
-            match inner {
-                SequentialProof::Inference(_) => Err(()),
-                SequentialProof::Composite(composite) => {
-                    composite.get_subproofs_helper(path, index + 1)
-                }
+            match inner.body() {
+                Some(body) => body.get_subproofs_helper(path, index + 1),
+                None => Err(()),
             }
         }
     }
@@ -131,14 +128,13 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfSubproofs<P, Rule>
         let deferred = self
             .get_located_immediate_subproofs()
             .into_iter()
-            .map(|x| match x.into() {
-                SequentialProofAtPathEnum::Inference(_) => vec![],
-                SequentialProofAtPathEnum::Composite(composite) => composite
-                    .obj
+            .map(|x| match x.obj.body() {
+                Some(body) => body
                     .get_subproof_paths()
                     .into_iter()
-                    .map(|p| (composite.path, p).into())
+                    .map(|p| (x.path, p).into())
                     .collect(),
+                None => vec![],
             })
             .flatten();
         immediate.chain(deferred)
@@ -161,3 +157,34 @@ pub type CompositeSequentialProofInProof<'a, P, Rule> =
     CompositeSequentialProofAtPath<'a, P, Rule, SequentialProofInProofPath>;
 pub type OwnedCompositeSequentialProofInProof<P, Rule> =
     OwnedCompositeSequentialProofAtPath<P, Rule, SequentialProofInProofPath>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{TestRule, composite, composite_step, inference_step};
+
+    #[test]
+    fn test_get_nested_subproof() {
+        let nested = inference_step(TestRule::Valid, &[2], 3);
+        let proof = composite(
+            &[1],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                composite_step(&[2], vec![nested.clone()], &[3]),
+            ],
+            &[3],
+        );
+        let path = |steps: &[usize]| {
+            SequentialProofInProofPath(
+                steps
+                    .iter()
+                    .map(|step| ImmediateSequentialProofInProofPath(*step))
+                    .collect(),
+            )
+        };
+        assert_eq!(proof.get_subproof(&path(&[1, 0])), Ok(&nested));
+        assert_eq!(proof.get_subproof(&path(&[0])), Ok(&proof.subproofs[0]));
+        assert_eq!(proof.get_subproof(&path(&[0, 0])), Err(()));
+        assert_eq!(proof.get_subproof(&path(&[2])), Err(()));
+    }
+}
@@ -0,0 +1,352 @@
+use std::fmt::Display;
+
+use crate::{
+    proofs::{
+        inferences::InferenceRule,
+        sequential::{
+            SequentialProof,
+            composite::CompositeSequentialProof,
+            subproofs::{
+                SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
+            },
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A difference between one step of the old proof and one step of the new proof
+pub enum StepModification<P: Proposition, Rule: InferenceRule<P>> {
+    /// The step was an inference, composite, citation or hypothetical subproof, and is now a different one of these
+    KindChanged,
+    /// The inference, or the hypothetical subproof's discharge, uses a different [InferenceRule]
+    RuleChanged { old: Rule, new: Rule },
+    /// The citation cites a different theorem
+    TheoremChanged { old: String, new: String },
+    /// The hypothetical subproof has different hypotheses
+    HypothesesChanged {
+        added: HashPropSet1O<P>,
+        removed: HashPropSet1O<P>,
+    },
+    /// The step assumes different propositions
+    AssumptionsChanged {
+        added: HashPropSet1O<P>,
+        removed: HashPropSet1O<P>,
+    },
+    /// The step explicitly concludes different propositions
+    ConclusionsChanged {
+        added: HashPropSet1O<P>,
+        removed: HashPropSet1O<P>,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProofStepChange<P: Proposition, Rule: InferenceRule<P>> {
+    /// A step exists only in the new proof
+    Inserted {
+        new_path: SequentialProofInProofPath,
+    },
+    /// A step exists only in the old proof
+    Deleted {
+        old_path: SequentialProofInProofPath,
+    },
+    /// A step exists in both proofs, but its position relative to the other steps of its composite has changed
+    Moved {
+        old_path: SequentialProofInProofPath,
+        new_path: SequentialProofInProofPath,
+    },
+    /// A step exists in both proofs, but has been changed
+    Modified {
+        old_path: SequentialProofInProofPath,
+        new_path: SequentialProofInProofPath,
+        modifications: Vec<StepModification<P, Rule>>,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The structural differences between two [SequentialProofs](SequentialProof)
+/// Steps are matched between the proofs by what they conclude where possible, and by their position otherwise
+pub struct SequentialProofDiff<P: Proposition, Rule: InferenceRule<P>> {
+    pub changes: Vec<ProofStepChange<P, Rule>>,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> SequentialProofDiff<P, Rule> {
+    /// Find the structural differences between an old and a new version of a proof
+    pub fn new(old: &SequentialProof<P, Rule>, new: &SequentialProof<P, Rule>) -> Self {
+        let mut diff = Self { changes: vec![] };
+        let root = SequentialProofInProofPath(vec![]);
+        diff.compare_steps(old, new, root.clone(), root);
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Compare two steps which have been matched with one another, recording any changes between them
+    fn compare_steps(
+        &mut self,
+        old: &SequentialProof<P, Rule>,
+        new: &SequentialProof<P, Rule>,
+        old_path: SequentialProofInProofPath,
+        new_path: SequentialProofInProofPath,
+    ) {
+        let mut modifications = Vec::new();
+        match (old, new) {
+            (
+                SequentialProof::Inference(old_inference),
+                SequentialProof::Inference(new_inference),
+            ) => {
+                if old_inference.inference_type != new_inference.inference_type {
+                    modifications.push(StepModification::RuleChanged {
+                        old: old_inference.inference_type.clone(),
+                        new: new_inference.inference_type.clone(),
+                    });
+                }
+            }
+            (SequentialProof::Citation(old_citation), SequentialProof::Citation(new_citation)) => {
+                if old_citation.theorem != new_citation.theorem {
+                    modifications.push(StepModification::TheoremChanged {
+                        old: old_citation.theorem.clone(),
+                        new: new_citation.theorem.clone(),
+                    });
+                }
+            }
+            (SequentialProof::Composite(_), SequentialProof::Composite(_)) => {}
+            (
+                SequentialProof::Hypothetical(old_hypothetical),
+                SequentialProof::Hypothetical(new_hypothetical),
+            ) => {
+                if old_hypothetical.discharge_rule != new_hypothetical.discharge_rule {
+                    modifications.push(StepModification::RuleChanged {
+                        old: old_hypothetical.discharge_rule.clone(),
+                        new: new_hypothetical.discharge_rule.clone(),
+                    });
+                }
+                let (added, removed) = set_difference(
+                    old_hypothetical.hypotheses.iter(),
+                    new_hypothetical.hypotheses.iter(),
+                );
+                if added.len() > 0 || removed.len() > 0 {
+                    modifications.push(StepModification::HypothesesChanged { added, removed });
+                }
+            }
+            _ => modifications.push(StepModification::KindChanged),
+        }
+        let (added, removed) = set_difference(old.get_assumptions(), new.get_assumptions());
+        if added.len() > 0 || removed.len() > 0 {
+            modifications.push(StepModification::AssumptionsChanged { added, removed });
+        }
+        let (added, removed) = set_difference(
+            old.get_explicit_conclusions(),
+            new.get_explicit_conclusions(),
+        );
+        if added.len() > 0 || removed.len() > 0 {
+            modifications.push(StepModification::ConclusionsChanged { added, removed });
+        }
+        if modifications.len() > 0 {
+            self.changes.push(ProofStepChange::Modified {
+                old_path: old_path.clone(),
+                new_path: new_path.clone(),
+                modifications,
+            });
+        }
+        match (old, new) {
+            (SequentialProof::Composite(old), SequentialProof::Composite(new)) => {
+                self.compare_composites(old, new, old_path, new_path)
+            }
+            (SequentialProof::Hypothetical(old), SequentialProof::Hypothetical(new)) => {
+                self.compare_composites(&old.body, &new.body, old_path, new_path)
+            }
+            _ => {}
+        }
+    }
+
+    /// Match up the steps of two composites, recording which steps were inserted, deleted, moved or modified
+    fn compare_composites(
+        &mut self,
+        old: &CompositeSequentialProof<P, Rule>,
+        new: &CompositeSequentialProof<P, Rule>,
+        old_path: SequentialProofInProofPath,
+        new_path: SequentialProofInProofPath,
+    ) {
+        let old_steps = &old.subproofs;
+        let new_steps = &new.subproofs;
+        let mut old_taken = vec![false; old_steps.len()];
+        let mut new_match: Vec<Option<usize>> = vec![None; new_steps.len()];
+
+        // Match identical steps, then steps which conclude the same propositions
+        let old_conclusions: Vec<HashPropSet1O<P>> = old_steps
+            .iter()
+            .map(|step| HashPropSet1O::from_iter(step.get_explicit_conclusions_owned()))
+            .collect();
+        let new_conclusions: Vec<HashPropSet1O<P>> = new_steps
+            .iter()
+            .map(|step| HashPropSet1O::from_iter(step.get_explicit_conclusions_owned()))
+            .collect();
+        let matchers: [&dyn Fn(usize, usize) -> bool; 2] =
+            [&|i, j| old_steps[i] == new_steps[j], &|i, j| {
+                old_conclusions[i].len() > 0 && old_conclusions[i] == new_conclusions[j]
+            }];
+        for matcher in matchers {
+            for j in 0..new_steps.len() {
+                if new_match[j].is_some() {
+                    continue;
+                }
+                if let Some(i) = (0..old_steps.len()).find(|i| !old_taken[*i] && matcher(*i, j)) {
+                    old_taken[i] = true;
+                    new_match[j] = Some(i);
+                }
+            }
+        }
+        // Fall back on matching unmatched steps at the same position
+        for j in 0..new_steps.len().min(old_steps.len()) {
+            if new_match[j].is_none() && !old_taken[j] {
+                old_taken[j] = true;
+                new_match[j] = Some(j);
+            }
+        }
+
+        // Steps are moved if they are not part of the longest run of matches which keeps its order
+        let matches: Vec<(usize, usize)> = new_match
+            .iter()
+            .enumerate()
+            .filter_map(|(j, i)| i.map(|i| (i, j)))
+            .collect();
+        let in_order =
+            longest_increasing_subsequence(&matches.iter().map(|(i, _)| *i).collect::<Vec<_>>());
+
+        let old_child = |i: usize| -> SequentialProofInProofPath {
+            (old_path.clone(), ImmediateSequentialProofInProofPath(i)).into()
+        };
+        let new_child = |j: usize| -> SequentialProofInProofPath {
+            (new_path.clone(), ImmediateSequentialProofInProofPath(j)).into()
+        };
+        for (i, taken) in old_taken.iter().enumerate() {
+            if !taken {
+                self.changes.push(ProofStepChange::Deleted {
+                    old_path: old_child(i),
+                });
+            }
+        }
+        for (index, (i, j)) in matches.into_iter().enumerate() {
+            if !in_order[index] {
+                self.changes.push(ProofStepChange::Moved {
+                    old_path: old_child(i),
+                    new_path: new_child(j),
+                });
+            }
+            self.compare_steps(&old_steps[i], &new_steps[j], old_child(i), new_child(j));
+        }
+        for (j, matched) in new_match.iter().enumerate() {
+            if matched.is_none() {
+                self.changes.push(ProofStepChange::Inserted {
+                    new_path: new_child(j),
+                });
+            }
+        }
+    }
+}
+
+/// Find the propositions which are only in the new collection, and those which are only in the old collection
+fn set_difference<'a, P: Proposition + 'a>(
+    old: impl IntoIterator<Item = &'a P>,
+    new: impl IntoIterator<Item = &'a P>,
+) -> (HashPropSet1O<P>, HashPropSet1O<P>) {
+    let old = HashPropSet1O::from_iter(old.into_iter().cloned());
+    let new = HashPropSet1O::from_iter(new.into_iter().cloned());
+    (&new - &old, &old - &new)
+}
+
+/// Mark which of the provided values are part of a longest strictly increasing subsequence
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+    // For each length, the index of the value ending the best subsequence of that length found so far
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+    for (index, value) in values.iter().enumerate() {
+        let length = tails.partition_point(|tail| values[*tail] < *value);
+        previous[index] = length.checked_sub(1).map(|l| tails[l]);
+        if length == tails.len() {
+            tails.push(index);
+        } else {
+            tails[length] = index;
+        }
+    }
+    let mut in_sequence = vec![false; values.len()];
+    let mut current = tails.last().copied();
+    while let Some(index) = current {
+        in_sequence[index] = true;
+        current = previous[index];
+    }
+    in_sequence
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Display for StepModification<P, Rule> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepModification::KindChanged => write!(f, "is now a different kind of step"),
+            StepModification::RuleChanged { .. } => write!(f, "now uses a different rule"),
+            StepModification::TheoremChanged { old, new } => {
+                write!(f, "now cites {} instead of {}", new, old)
+            }
+            StepModification::HypothesesChanged { added, removed } => write!(
+                f,
+                "now has {} added and {} removed hypotheses",
+                added.len(),
+                removed.len()
+            ),
+            StepModification::AssumptionsChanged { added, removed } => write!(
+                f,
+                "now has {} added and {} removed assumptions",
+                added.len(),
+                removed.len()
+            ),
+            StepModification::ConclusionsChanged { added, removed } => write!(
+                f,
+                "now has {} added and {} removed conclusions",
+                added.len(),
+                removed.len()
+            ),
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Display for ProofStepChange<P, Rule> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProofStepChange::Inserted { new_path } => {
+                write!(f, "{} was inserted", new_path.describe())
+            }
+            ProofStepChange::Deleted { old_path } => {
+                write!(f, "{} was deleted", old_path.describe())
+            }
+            ProofStepChange::Moved { old_path, new_path } => write!(
+                f,
+                "{} was moved to {}",
+                old_path.describe(),
+                new_path.describe()
+            ),
+            ProofStepChange::Modified {
+                new_path,
+                modifications,
+                ..
+            } => {
+                let descriptions: Vec<String> =
+                    modifications.iter().map(|m| m.to_string()).collect();
+                write!(f, "{} {}", new_path.describe(), descriptions.join(", and "))
+            }
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Display for SequentialProofDiff<P, Rule> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+
+use crate::{
+    proofs::{
+        inferences::InferenceRule,
+        sequential::{composite::CompositeSequentialProof, subproofs::SequentialProofInProofPath},
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        paths::{AssumptionInSequentialProofStepPath, ExplicitConclusionInSequentialProofStepPath},
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// A subproof which temporarily assumes some hypotheses, as in conditional proof or reductio
+/// The hypotheses are only available within the subproof's body; its parent instead receives the conclusions which the discharge rule derives from the body
+pub struct HypotheticalSequentialProof<P: Proposition, Rule: InferenceRule<P>> {
+    /// Propositions which the body assumes without them being proved in the parent
+    pub hypotheses: Box<[P]>,
+    /// The steps of this subproof; any of its assumptions which are not hypotheses must be proved in the parent
+    pub body: CompositeSequentialProof<P, Rule>,
+    /// The rule which turns the hypotheses and the body's conclusions into conclusions valid in the parent
+    pub discharge_rule: Rule,
+    pub conclusions: Box<[P]>,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> HypotheticalSequentialProof<P, Rule> {
+    /// Get the assumptions of the body which must be proved in the parent
+    fn parent_assumptions(&self) -> impl Iterator<Item = &P> {
+        self.body
+            .assumptions
+            .iter()
+            .filter(|assumption| !self.hypotheses.contains(assumption))
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> ParentOfAssumptions<P>
+    for HypotheticalSequentialProof<P, Rule>
+{
+    fn get_assumption_paths(
+        &self,
+    ) -> impl IntoIterator<Item = AssumptionInSequentialProofStepPath> {
+        (0..self.parent_assumptions().count()).map(|n| AssumptionInSequentialProofStepPath(n))
+    }
+
+    fn get_assumption(&self, path: &AssumptionInSequentialProofStepPath) -> Result<&P, ()> {
+        self.parent_assumptions().nth(path.0).ok_or(())
+    }
+
+    fn get_assumptions<'a>(&'a self) -> impl IntoIterator<Item = &'a P>
+    where
+        P: 'a,
+    {
+        self.parent_assumptions()
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> ParentOfExplicitConclusions<P>
+    for HypotheticalSequentialProof<P, Rule>
+{
+    fn get_explicit_conclusion_paths(
+        &self,
+    ) -> impl IntoIterator<Item = ExplicitConclusionInSequentialProofStepPath> {
+        (0..self.conclusions.len()).map(|n| ExplicitConclusionInSequentialProofStepPath(n))
+    }
+
+    fn get_explicit_conclusion(
+        &self,
+        path: &ExplicitConclusionInSequentialProofStepPath,
+    ) -> Result<&P, ()> {
+        self.conclusions.get(path.0).ok_or(())
+    }
+
+    fn get_explicit_conclusions<'a>(&'a self) -> impl IntoIterator<Item = &'a P>
+    where
+        P: 'a,
+    {
+        &self.conclusions
+    }
+}
+
+pub type HypotheticalSequentialProofAtPath<'a, P, Rule, Path> =
+    ObjAtPath<'a, HypotheticalSequentialProof<P, Rule>, Path>;
+pub type OwnedHypotheticalSequentialProofAtPath<P, Rule, Path> =
+    OwnedObjAtPath<HypotheticalSequentialProof<P, Rule>, Path>;
+
+pub type HypotheticalSequentialProofInProof<'a, P, Rule> =
+    HypotheticalSequentialProofAtPath<'a, P, Rule, SequentialProofInProofPath>;
+pub type OwnedHypotheticalSequentialProofInProof<P, Rule> =
+    OwnedHypotheticalSequentialProofAtPath<P, Rule, SequentialProofInProofPath>;
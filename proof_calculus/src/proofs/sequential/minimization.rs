@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+
+use crate::{
+    proofs::{
+        inferences::InferenceRule,
+        sequential::{
+            SequentialProof,
+            composite::CompositeSequentialProof,
+            hypothetical::HypotheticalSequentialProof,
+            subproofs::{
+                SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
+            },
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProofMinimizationReason<P: Proposition> {
+    /// None of the step's conclusions are used by any later step, or by the explicit conclusions of the proof containing it
+    UnusedConclusions,
+    /// Every one of the step's conclusions had already been proved before it
+    DuplicateDerivation,
+    /// The contained assumptions of the composite proof at this path are not needed by any of its steps, or by its explicit conclusions
+    UnneededAssumptions(HashPropSet1O<P>),
+}
+
+/// A record of what was removed when minimizing a [SequentialProof]
+/// Paths refer to the proof before minimization
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ProofMinimizationReport<P: Proposition> {
+    pub removed: HashMap<SequentialProofInProofPath, ProofMinimizationReason<P>>,
+}
+
+impl<P: Proposition> Default for ProofMinimizationReport<P> {
+    fn default() -> Self {
+        Self {
+            removed: HashMap::new(),
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> SequentialProof<P, Rule> {
+    /// Remove redundant work from this proof; steps whose conclusions are never used, steps which only prove what was already proved, and composite assumptions which no step needs
+    /// The explicit conclusions of every composite are kept, so if this proof is valid then so is the result
+    pub fn minimize(&self) -> (Self, ProofMinimizationReport<P>) {
+        let mut report = ProofMinimizationReport::default();
+        let minimized = self.minimize_step(SequentialProofInProofPath(vec![]), &mut report);
+        (minimized, report)
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> SequentialProof<P, Rule> {
+    /// Minimize the body of this step if it has one
+    /// The hypotheses of a hypothetical subproof are always kept, as its discharge rule may depend upon them
+    fn minimize_step(
+        &self,
+        path: SequentialProofInProofPath,
+        report: &mut ProofMinimizationReport<P>,
+    ) -> Self {
+        match self {
+            SequentialProof::Composite(composite) => {
+                SequentialProof::Composite(composite.minimize_helper(path, &[], report))
+            }
+            SequentialProof::Hypothetical(hypothetical) => {
+                SequentialProof::Hypothetical(HypotheticalSequentialProof {
+                    hypotheses: hypothetical.hypotheses.clone(),
+                    body: hypothetical
+                        .body
+                        .minimize_helper(path, &hypothetical.hypotheses, report),
+                    discharge_rule: hypothetical.discharge_rule.clone(),
+                    conclusions: hypothetical.conclusions.clone(),
+                })
+            }
+            SequentialProof::Inference(_) | SequentialProof::Citation(_) => self.clone(),
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> CompositeSequentialProof<P, Rule> {
+    /// Remove redundant work from this proof, as described in [SequentialProof::minimize]
+    pub fn minimize(&self) -> (Self, ProofMinimizationReport<P>) {
+        let mut report = ProofMinimizationReport::default();
+        let minimized = self.minimize_helper(SequentialProofInProofPath(vec![]), &[], &mut report);
+        (minimized, report)
+    }
+
+    /// Minimize this proof, without removing any of the provided assumptions even if no step needs them
+    fn minimize_helper(
+        &self,
+        path: SequentialProofInProofPath,
+        kept_assumptions: &[P],
+        report: &mut ProofMinimizationReport<P>,
+    ) -> Self {
+        let subproof_path = |index| -> SequentialProofInProofPath {
+            (path.clone(), ImmediateSequentialProofInProofPath(index)).into()
+        };
+
+        // Find the steps which prove nothing new
+        let mut proved = HashPropSet1O::from_iter(self.assumptions.iter().cloned());
+        let mut candidates = Vec::with_capacity(self.subproofs.len());
+        for (index, subproof) in self.subproofs.iter().enumerate() {
+            let conclusions = HashPropSet1O::from_iter(subproof.get_explicit_conclusions_owned());
+            if !conclusions.is_empty() && conclusions.is_subset(&proved) {
+                report.removed.insert(
+                    subproof_path(index),
+                    ProofMinimizationReason::DuplicateDerivation,
+                );
+            } else {
+                proved.extend(conclusions);
+                candidates.push(index);
+            }
+        }
+
+        // Working backwards, find the steps whose conclusions are needed
+        let mut needed = HashPropSet1O::from_iter(self.explicit_conclusions.iter().cloned());
+        let mut kept = Vec::with_capacity(candidates.len());
+        for index in candidates.into_iter().rev() {
+            let subproof = &self.subproofs[index];
+            let is_used = subproof
+                .get_explicit_conclusions()
+                .into_iter()
+                .any(|conclusion| needed.contains(conclusion));
+            if !is_used {
+                report.removed.insert(
+                    subproof_path(index),
+                    ProofMinimizationReason::UnusedConclusions,
+                );
+                continue;
+            }
+            // Minimizing a composite step may reduce the assumptions it needs
+            let minimized = subproof.minimize_step(subproof_path(index), report);
+            needed.extend(minimized.get_assumptions_owned());
+            kept.push(minimized);
+        }
+        kept.reverse();
+
+        // Remove any assumptions which are not needed
+        let (assumptions, unneeded): (Vec<P>, Vec<P>) =
+            self.assumptions.iter().cloned().partition(|assumption| {
+                needed.contains(assumption) || kept_assumptions.contains(assumption)
+            });
+        if !unneeded.is_empty() {
+            report.removed.insert(
+                path,
+                ProofMinimizationReason::UnneededAssumptions(HashPropSet1O::from_iter(unneeded)),
+            );
+        }
+
+        Self {
+            assumptions: assumptions.into(),
+            subproofs: kept.into(),
+            explicit_conclusions: self.explicit_conclusions.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proofs::errors::soundness::verify_proof_soundness,
+        test_helpers::{TestProp, TestRule, composite_step, inference_step},
+    };
+
+    fn path(steps: &[usize]) -> SequentialProofInProofPath {
+        SequentialProofInProofPath(
+            steps
+                .iter()
+                .map(|step| ImmediateSequentialProofInProofPath(*step))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_minimize() {
+        let proof = composite_step(
+            &[1, 9],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                // Unused
+                inference_step(TestRule::Valid, &[1], 3),
+                // Duplicates the first step
+                inference_step(TestRule::Valid, &[2], 2),
+                // Assumes 3 without needing it, and proves 8 without using it
+                composite_step(
+                    &[2, 3],
+                    vec![
+                        inference_step(TestRule::Valid, &[2], 4),
+                        inference_step(TestRule::Valid, &[2], 8),
+                    ],
+                    &[4],
+                ),
+                inference_step(TestRule::Valid, &[4], 5),
+            ],
+            &[5],
+        );
+        let (minimized, report) = proof.minimize();
+        assert_eq!(
+            minimized,
+            composite_step(
+                &[1],
+                vec![
+                    inference_step(TestRule::Valid, &[1], 2),
+                    composite_step(&[2], vec![inference_step(TestRule::Valid, &[2], 4)], &[4]),
+                    inference_step(TestRule::Valid, &[4], 5),
+                ],
+                &[5],
+            )
+        );
+        assert_eq!(
+            report.removed,
+            HashMap::from([
+                (path(&[1]), ProofMinimizationReason::UnusedConclusions),
+                (path(&[2]), ProofMinimizationReason::DuplicateDerivation),
+                (path(&[3, 1]), ProofMinimizationReason::UnusedConclusions),
+                (
+                    path(&[3]),
+                    ProofMinimizationReason::UnneededAssumptions(HashPropSet1O::from_iter([
+                        TestProp(3)
+                    ]))
+                ),
+                (
+                    path(&[]),
+                    ProofMinimizationReason::UnneededAssumptions(HashPropSet1O::from_iter([
+                        TestProp(9)
+                    ]))
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_minimize_keeps_soundness() {
+        let assumptions = HashPropSet1O::from_iter([TestProp(1), TestProp(9)]);
+        let proof = composite_step(
+            &[1, 9],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                inference_step(TestRule::Valid, &[9], 3),
+                composite_step(
+                    &[2, 3],
+                    vec![
+                        inference_step(TestRule::Valid, &[2], 4),
+                        inference_step(TestRule::Valid, &[4, 3], 5),
+                        inference_step(TestRule::Valid, &[2], 6),
+                    ],
+                    &[5],
+                ),
+                inference_step(TestRule::Valid, &[2], 7),
+                inference_step(TestRule::Valid, &[5, 2], 8),
+            ],
+            &[8],
+        );
+        verify_proof_soundness(&proof, &assumptions).unwrap();
+        let (minimized, report) = proof.minimize();
+        assert_eq!(report.removed.len(), 2);
+        verify_proof_soundness(&minimized, &assumptions).unwrap();
+        // Minimizing again finds nothing more to remove
+        let (reminimized, report) = minimized.minimize();
+        assert_eq!(reminimized, minimized);
+        assert!(report.removed.is_empty());
+    }
+
+    #[test]
+    fn test_minimize_leaves_inference_unchanged() {
+        let proof = inference_step(TestRule::Valid, &[1, 2], 3);
+        let (minimized, report) = proof.minimize();
+        assert_eq!(minimized, proof);
+        assert!(report.removed.is_empty());
+    }
+}
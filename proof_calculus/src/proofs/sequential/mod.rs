@@ -1,6 +1,11 @@
 pub mod at_path_enum;
+pub mod citation;
 pub mod composite;
+pub mod diff;
 pub mod errors;
+pub mod hypothetical;
+pub mod minimization;
+pub mod restructuring;
 pub mod subproofs;
 
 use itertools::Itertools;
@@ -8,7 +13,10 @@ use itertools::Itertools;
 use crate::{
     proofs::{
         inferences::{Inference, InferenceRule},
-        sequential::composite::CompositeSequentialProof,
+        sequential::{
+            citation::TheoremCitation, composite::CompositeSequentialProof,
+            hypothetical::HypotheticalSequentialProof,
+        },
     },
     propositions::types::assigned::{
         ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
@@ -17,10 +25,30 @@ use crate::{
 };
 
 /// This struct represents a step within a larger proof
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum SequentialProof<P: Proposition, Rule: InferenceRule<P>> {
     Inference(Inference<P, Rule>),                // A single inference step
     Composite(CompositeSequentialProof<P, Rule>), // A composite proof made of further subproofs
+    Citation(TheoremCitation<P>),                 // A citation of a theorem proven elsewhere
+    Hypothetical(HypotheticalSequentialProof<P, Rule>), // A subproof whose hypotheses are discharged by a rule
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> SequentialProof<P, Rule> {
+    /// Get the composite proof containing the immediate subproofs of this step, if it has any
+    pub fn body(&self) -> Option<&CompositeSequentialProof<P, Rule>> {
+        match self {
+            SequentialProof::Composite(composite) => Some(composite),
+            SequentialProof::Hypothetical(hypothetical) => Some(&hypothetical.body),
+            SequentialProof::Inference(_) | SequentialProof::Citation(_) => None,
+        }
+    }
+    pub fn body_mut(&mut self) -> Option<&mut CompositeSequentialProof<P, Rule>> {
+        match self {
+            SequentialProof::Composite(composite) => Some(composite),
+            SequentialProof::Hypothetical(hypothetical) => Some(&mut hypothetical.body),
+            SequentialProof::Inference(_) | SequentialProof::Citation(_) => None,
+        }
+    }
 }
 
 impl<P: Proposition, Rule: InferenceRule<P>> ParentOfAssumptions<P> for SequentialProof<P, Rule> {
@@ -34,6 +62,12 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfAssumptions<P> for Sequenti
             SequentialProof::Composite(composite) => {
                 composite.get_assumption_paths().into_iter().collect()
             }
+            SequentialProof::Citation(citation) => {
+                citation.get_assumption_paths().into_iter().collect()
+            }
+            SequentialProof::Hypothetical(hypothetical) => {
+                hypothetical.get_assumption_paths().into_iter().collect()
+            }
         }
     }
 
@@ -41,6 +75,8 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfAssumptions<P> for Sequenti
         match self {
             SequentialProof::Inference(inference) => inference.get_assumption(path),
             SequentialProof::Composite(composite_proof) => composite_proof.get_assumption(path),
+            SequentialProof::Citation(citation) => citation.get_assumption(path),
+            SequentialProof::Hypothetical(hypothetical) => hypothetical.get_assumption(path),
         }
     }
 }
@@ -59,6 +95,14 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfExplicitConclusions<P>
                 .get_explicit_conclusion_paths()
                 .into_iter()
                 .collect(),
+            SequentialProof::Citation(citation) => citation
+                .get_explicit_conclusion_paths()
+                .into_iter()
+                .collect(),
+            SequentialProof::Hypothetical(hypothetical) => hypothetical
+                .get_explicit_conclusion_paths()
+                .into_iter()
+                .collect(),
         }
     }
 
@@ -71,6 +115,10 @@ impl<P: Proposition, Rule: InferenceRule<P>> ParentOfExplicitConclusions<P>
             SequentialProof::Composite(composite_proof) => {
                 composite_proof.get_explicit_conclusion(path)
             }
+            SequentialProof::Citation(citation) => citation.get_explicit_conclusion(path),
+            SequentialProof::Hypothetical(hypothetical) => {
+                hypothetical.get_explicit_conclusion(path)
+            }
         }
     }
 }
@@ -0,0 +1,302 @@
+use std::ops::Range;
+
+use crate::{
+    proofs::{
+        inferences::InferenceRule,
+        sequential::{
+            SequentialProof, composite::CompositeSequentialProof,
+            subproofs::SequentialProofInProofPath,
+        },
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ProofRestructuringError {
+    /// There is no subproof at the contained path
+    SubproofNotFound(SequentialProofInProofPath),
+    /// The subproof at the contained path is not a composite proof, but a composite proof was required
+    NotComposite(SequentialProofInProofPath),
+    /// A proof cannot be inlined into itself, so the path to inline must not be empty
+    CannotInlineRoot,
+    /// The range of steps to extract is empty, or extends past the end of the composite proof which contains them
+    InvalidRange {
+        range: Range<usize>,
+        step_count: usize,
+    },
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> CompositeSequentialProof<P, Rule> {
+    /// Get a mutable reference to the composite proof at the provided path within this one
+    /// The body of a hypothetical subproof is treated as the composite at that subproof's path
+    fn get_composite_mut(
+        &mut self,
+        path: &SequentialProofInProofPath,
+    ) -> Result<&mut Self, ProofRestructuringError> {
+        let mut current = self;
+        for (depth, step) in path.0.iter().enumerate() {
+            current = match current
+                .subproofs
+                .get_mut(step.0)
+                .map(SequentialProof::body_mut)
+            {
+                Some(Some(body)) => body,
+                Some(None) => {
+                    return Err(ProofRestructuringError::NotComposite(
+                        path.0[..=depth].to_vec().into(),
+                    ));
+                }
+                None => {
+                    return Err(ProofRestructuringError::SubproofNotFound(
+                        path.0[..=depth].to_vec().into(),
+                    ));
+                }
+            };
+        }
+        Ok(current)
+    }
+
+    /// Replace the composite subproof at the provided path with its own steps, placing them directly within its parent
+    /// If this proof is valid then it will remain valid, as the inlined steps only make use of propositions which were available to the composite they were part of
+    /// Hypothetical subproofs cannot be inlined, as their steps may use hypotheses which are not available to the parent
+    pub fn inline_subproof(
+        &mut self,
+        path: &SequentialProofInProofPath,
+    ) -> Result<(), ProofRestructuringError> {
+        let Some((step, parent_path)) = path.0.split_last() else {
+            return Err(ProofRestructuringError::CannotInlineRoot);
+        };
+        let parent = self.get_composite_mut(&parent_path.to_vec().into())?;
+        let inlined = match parent.subproofs.get(step.0) {
+            Some(SequentialProof::Composite(composite)) => composite.subproofs.clone(),
+            Some(
+                SequentialProof::Inference(_)
+                | SequentialProof::Citation(_)
+                | SequentialProof::Hypothetical(_),
+            ) => {
+                return Err(ProofRestructuringError::NotComposite(path.clone()));
+            }
+            None => return Err(ProofRestructuringError::SubproofNotFound(path.clone())),
+        };
+        let mut subproofs = std::mem::take(&mut parent.subproofs).into_vec();
+        subproofs.splice(step.0..=step.0, inlined);
+        parent.subproofs = subproofs.into();
+        Ok(())
+    }
+
+    /// Replace every composite subproof within this proof with its own steps, so that this proof contains no further composites
+    /// Hypothetical subproofs are kept, but their bodies are flattened
+    pub fn flatten(&mut self) {
+        let subproofs = std::mem::take(&mut self.subproofs).into_vec();
+        let mut flattened = Vec::with_capacity(subproofs.len());
+        for subproof in subproofs {
+            match subproof {
+                SequentialProof::Inference(_) | SequentialProof::Citation(_) => {
+                    flattened.push(subproof)
+                }
+                SequentialProof::Composite(mut composite) => {
+                    composite.flatten();
+                    flattened.extend(composite.subproofs.into_vec());
+                }
+                SequentialProof::Hypothetical(mut hypothetical) => {
+                    hypothetical.body.flatten();
+                    flattened.push(SequentialProof::Hypothetical(hypothetical));
+                }
+            }
+        }
+        self.subproofs = flattened.into();
+    }
+
+    /// Move a contiguous range of steps within the composite proof at the provided path into a new composite subproof, which takes their place
+    /// The new subproof assumes exactly those propositions which its steps use before proving them, and explicitly concludes exactly those propositions which it proves that are needed afterwards
+    /// If this proof is valid then it will remain valid
+    pub fn extract_lemma(
+        &mut self,
+        parent_path: &SequentialProofInProofPath,
+        range: Range<usize>,
+    ) -> Result<(), ProofRestructuringError> {
+        let parent = self.get_composite_mut(parent_path)?;
+        let step_count = parent.subproofs.len();
+        if range.is_empty() || range.end > step_count {
+            return Err(ProofRestructuringError::InvalidRange { range, step_count });
+        }
+
+        let steps = &parent.subproofs[range.clone()];
+        // Anything which a step uses before it has been proved by an earlier step in the range must be assumed by the lemma
+        let mut assumptions = Vec::new();
+        let mut available = HashPropSet1O::new();
+        for step in steps {
+            for assumption in step.get_assumptions() {
+                if available.insert(assumption.clone()) {
+                    assumptions.push(assumption.clone());
+                }
+            }
+            available.extend(step.get_explicit_conclusions_owned());
+        }
+        // Anything which the lemma proves and which is used afterwards must be concluded by the lemma
+        let mut needed_afterwards =
+            HashPropSet1O::from_iter(parent.explicit_conclusions.iter().cloned());
+        for step in &parent.subproofs[range.end..] {
+            needed_afterwards.extend(step.get_assumptions_owned());
+        }
+        let mut explicit_conclusions = Vec::new();
+        let mut concluded = HashPropSet1O::new();
+        for step in steps {
+            for conclusion in step.get_explicit_conclusions() {
+                if needed_afterwards.contains(conclusion) && concluded.insert(conclusion.clone()) {
+                    explicit_conclusions.push(conclusion.clone());
+                }
+            }
+        }
+
+        let lemma = CompositeSequentialProof {
+            assumptions: assumptions.into(),
+            subproofs: steps.into(),
+            explicit_conclusions: explicit_conclusions.into(),
+        };
+        let mut subproofs = std::mem::take(&mut parent.subproofs).into_vec();
+        subproofs.splice(range, [SequentialProof::Composite(lemma)]);
+        parent.subproofs = subproofs.into();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        proofs::{
+            errors::verify_proof_validity,
+            sequential::subproofs::immediate::ImmediateSequentialProofInProofPath,
+        },
+        test_helpers::{TestProp, TestRule, composite, composite_step, inference_step},
+    };
+
+    fn path(steps: &[usize]) -> SequentialProofInProofPath {
+        SequentialProofInProofPath(
+            steps
+                .iter()
+                .map(|step| ImmediateSequentialProofInProofPath(*step))
+                .collect(),
+        )
+    }
+    fn assert_valid(proof: &CompositeSequentialProof<TestProp, TestRule>) {
+        verify_proof_validity(&SequentialProof::Composite(proof.clone())).unwrap();
+    }
+    fn nested_proof() -> CompositeSequentialProof<TestProp, TestRule> {
+        composite(
+            &[1],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                composite_step(
+                    &[2],
+                    vec![
+                        inference_step(TestRule::Valid, &[2], 3),
+                        composite_step(&[3], vec![inference_step(TestRule::Valid, &[3], 4)], &[4]),
+                    ],
+                    &[4],
+                ),
+                inference_step(TestRule::Valid, &[4], 5),
+            ],
+            &[5],
+        )
+    }
+    fn flat_proof() -> CompositeSequentialProof<TestProp, TestRule> {
+        composite(
+            &[1],
+            vec![
+                inference_step(TestRule::Valid, &[1], 2),
+                inference_step(TestRule::Valid, &[2], 3),
+                inference_step(TestRule::Valid, &[3], 4),
+                inference_step(TestRule::Valid, &[4], 5),
+            ],
+            &[5],
+        )
+    }
+
+    #[test]
+    fn test_inline_subproof() {
+        let mut proof = nested_proof();
+        assert_valid(&proof);
+        proof.inline_subproof(&path(&[1, 1])).unwrap();
+        assert_valid(&proof);
+        proof.inline_subproof(&path(&[1])).unwrap();
+        assert_valid(&proof);
+        assert_eq!(proof, flat_proof());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut proof = nested_proof();
+        proof.flatten();
+        assert_valid(&proof);
+        assert_eq!(proof, flat_proof());
+    }
+
+    #[test]
+    fn test_inline_errors() {
+        let mut proof = nested_proof();
+        assert_eq!(
+            proof.inline_subproof(&path(&[])),
+            Err(ProofRestructuringError::CannotInlineRoot)
+        );
+        assert_eq!(
+            proof.inline_subproof(&path(&[0])),
+            Err(ProofRestructuringError::NotComposite(path(&[0])))
+        );
+        assert_eq!(
+            proof.inline_subproof(&path(&[9])),
+            Err(ProofRestructuringError::SubproofNotFound(path(&[9])))
+        );
+        assert_eq!(proof, nested_proof());
+    }
+
+    #[test]
+    fn test_extract_lemma() {
+        let mut proof = flat_proof();
+        proof.extract_lemma(&path(&[]), 1..3).unwrap();
+        assert_valid(&proof);
+        assert_eq!(
+            proof.subproofs[1],
+            composite_step(
+                &[2],
+                vec![
+                    inference_step(TestRule::Valid, &[2], 3),
+                    inference_step(TestRule::Valid, &[3], 4),
+                ],
+                &[4]
+            )
+        );
+        assert_eq!(
+            proof.extract_lemma(&path(&[]), 2..2),
+            Err(ProofRestructuringError::InvalidRange {
+                range: 2..2,
+                step_count: 3
+            })
+        );
+        assert_eq!(
+            proof.extract_lemma(&path(&[]), 1..4),
+            Err(ProofRestructuringError::InvalidRange {
+                range: 1..4,
+                step_count: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_then_inline_round_trip() {
+        let mut proof = flat_proof();
+        proof.extract_lemma(&path(&[]), 1..3).unwrap();
+        proof.inline_subproof(&path(&[1])).unwrap();
+        assert_eq!(proof, flat_proof());
+
+        let mut proof = nested_proof();
+        proof.extract_lemma(&path(&[1]), 0..2).unwrap();
+        assert_valid(&proof);
+        proof.inline_subproof(&path(&[1, 0])).unwrap();
+        assert_eq!(proof, nested_proof());
+    }
+}
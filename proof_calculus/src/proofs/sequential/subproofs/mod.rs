@@ -28,6 +28,16 @@ impl Display for SequentialProofInProofPath {
         write!(f, "{}", joined)
     }
 }
+impl SequentialProofInProofPath {
+    /// Describe the step at this path, referring to the empty path as the whole proof
+    pub fn describe(&self) -> String {
+        if self.0.is_empty() {
+            "the proof".to_string()
+        } else {
+            format!("step {}", self)
+        }
+    }
+}
 mod from {
     use crate::proofs::sequential::subproofs::{
         SequentialProofInProofPath, immediate::ImmediateSequentialProofInProofPath,
@@ -0,0 +1,291 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+};
+
+use crate::{
+    proofs::{
+        r#abstract::AbstractProof,
+        inferences::{Inference, InferenceRule},
+        sequential::{SequentialProof, composite::CompositeSequentialProof},
+    },
+    propositions::types::assigned::{
+        ParentOfAssumptions, ParentOfExplicitConclusions, Proposition,
+        collections::sets::implementations::hash::HashPropSet1O,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// Metrics describing the size and shape of a proof
+pub struct ProofStatistics<P: Proposition, Rule: InferenceRule<P>> {
+    /// The number of inferences within the proof
+    pub inference_count: usize,
+    /// The number of theorem citations within the proof
+    pub citation_count: usize,
+    /// The number of inferences within the proof which use each [InferenceRule], in order of first use
+    pub inferences_by_rule: Vec<(Rule, usize)>,
+    /// The greatest number of composite or hypothetical proofs which contain any one step, including the proof itself
+    pub max_nesting_depth: usize,
+    /// The greatest number of steps within the proof that each use a conclusion of the one before
+    pub longest_dependency_chain: usize,
+    /// The number of steps within the proof which assume each proposition
+    pub proposition_uses: HashMap<P, usize>,
+    /// The number of distinct propositions within the proof of each size
+    pub proposition_sizes: BTreeMap<usize, usize>,
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Default for ProofStatistics<P, Rule> {
+    fn default() -> Self {
+        Self {
+            inference_count: 0,
+            citation_count: 0,
+            inferences_by_rule: Vec::new(),
+            max_nesting_depth: 0,
+            longest_dependency_chain: 0,
+            proposition_uses: HashMap::new(),
+            proposition_sizes: BTreeMap::new(),
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> ProofStatistics<P, Rule> {
+    /// Get the number of inferences within the proof which use the provided [InferenceRule]
+    pub fn inferences_using(&self, rule: &Rule) -> usize {
+        self.inferences_by_rule
+            .iter()
+            .find(|(r, _)| r == rule)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Get the propositions which are assumed by more than one step, most used first
+    pub fn reused_propositions(&self) -> Vec<(&P, usize)> {
+        let mut reused: Vec<(&P, usize)> = self
+            .proposition_uses
+            .iter()
+            .filter(|(_, uses)| **uses > 1)
+            .map(|(prop, uses)| (prop, *uses))
+            .collect();
+        reused.sort_by(|a, b| b.1.cmp(&a.1));
+        reused
+    }
+
+    /// Record an inference, and that each of its assumptions has been used
+    fn record_inference(&mut self, inference: &Inference<P, Rule>) {
+        self.inference_count += 1;
+        match self
+            .inferences_by_rule
+            .iter_mut()
+            .find(|(rule, _)| rule == &inference.inference_type)
+        {
+            Some((_, count)) => *count += 1,
+            None => self
+                .inferences_by_rule
+                .push((inference.inference_type.clone(), 1)),
+        }
+        self.record_uses(&inference.assumptions);
+    }
+
+    fn record_uses(&mut self, assumptions: &[P]) {
+        for assumption in assumptions {
+            *self.proposition_uses.entry(assumption.clone()).or_insert(0) += 1;
+        }
+    }
+
+    fn record_sizes(&mut self, propositions: HashPropSet1O<P>, size: impl Fn(&P) -> usize) {
+        for proposition in &propositions {
+            *self.proposition_sizes.entry(size(proposition)).or_insert(0) += 1;
+        }
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> SequentialProof<P, Rule> {
+    /// Compute metrics describing this proof, measuring the size of each proposition with the provided function
+    /// Citations count as a single step, regardless of the size of the theorem they cite
+    pub fn statistics(&self, size: impl Fn(&P) -> usize) -> ProofStatistics<P, Rule> {
+        let mut statistics = ProofStatistics::default();
+        let mut propositions = HashPropSet1O::new();
+        statistics.longest_dependency_chain =
+            self.statistics_helper(&mut statistics, &mut propositions, &mut HashMap::new(), 0);
+        statistics.record_sizes(propositions, size);
+        statistics
+    }
+
+    /// Record the metrics of this step, returning the longest dependency chain it ends
+    /// The chains map holds, for every proposition available to this step, the longest dependency chain which proves it
+    fn statistics_helper(
+        &self,
+        statistics: &mut ProofStatistics<P, Rule>,
+        propositions: &mut HashPropSet1O<P>,
+        chains: &mut HashMap<P, usize>,
+        depth: usize,
+    ) -> usize {
+        propositions.extend(self.get_assumptions_owned());
+        propositions.extend(self.get_explicit_conclusions_owned());
+        let chain = match self {
+            SequentialProof::Inference(inference) => {
+                statistics.record_inference(inference);
+                longest_chain_through(&inference.assumptions, chains) + 1
+            }
+            SequentialProof::Citation(citation) => {
+                statistics.citation_count += 1;
+                statistics.record_uses(&citation.assumptions);
+                longest_chain_through(&citation.assumptions, chains) + 1
+            }
+            SequentialProof::Composite(composite) => {
+                let (longest, inner_chains) =
+                    composite_statistics(composite, statistics, propositions, chains, depth);
+                for conclusion in &composite.explicit_conclusions {
+                    let chain = inner_chains.get(conclusion).copied().unwrap_or(0);
+                    chains.insert(conclusion.clone(), chain);
+                }
+                return longest;
+            }
+            SequentialProof::Hypothetical(hypothetical) => {
+                let (longest, inner_chains) = composite_statistics(
+                    &hypothetical.body,
+                    statistics,
+                    propositions,
+                    chains,
+                    depth,
+                );
+                // Discharging the hypotheses is a further step, which uses the conclusions of the body
+                let chain =
+                    longest_chain_through(&hypothetical.body.explicit_conclusions, &inner_chains)
+                        + 1;
+                for conclusion in &hypothetical.conclusions {
+                    chains.insert(conclusion.clone(), chain);
+                }
+                return longest.max(chain);
+            }
+        };
+        for conclusion in self.get_explicit_conclusions() {
+            chains.insert(conclusion.clone(), chain);
+        }
+        chain
+    }
+}
+
+/// Record the metrics of the steps within a composite, or the body of a hypothetical subproof
+/// Returns the longest dependency chain within it, alongside the longest chain which proves each proposition available within it
+fn composite_statistics<P: Proposition, Rule: InferenceRule<P>>(
+    composite: &CompositeSequentialProof<P, Rule>,
+    statistics: &mut ProofStatistics<P, Rule>,
+    propositions: &mut HashPropSet1O<P>,
+    chains: &HashMap<P, usize>,
+    depth: usize,
+) -> (usize, HashMap<P, usize>) {
+    let depth = depth + 1;
+    statistics.max_nesting_depth = statistics.max_nesting_depth.max(depth);
+    // Steps within the composite can only see the outer chains through its assumptions
+    let mut inner_chains: HashMap<P, usize> = composite
+        .assumptions
+        .iter()
+        .map(|assumption| {
+            (
+                assumption.clone(),
+                chains.get(assumption).copied().unwrap_or(0),
+            )
+        })
+        .collect();
+    let mut longest = 0;
+    for subproof in &composite.subproofs {
+        longest = longest.max(subproof.statistics_helper(
+            statistics,
+            propositions,
+            &mut inner_chains,
+            depth,
+        ));
+    }
+    (longest, inner_chains)
+}
+
+/// Get the longest dependency chain which proves any of the provided assumptions
+fn longest_chain_through<P: Proposition>(assumptions: &[P], chains: &HashMap<P, usize>) -> usize {
+    assumptions
+        .iter()
+        .filter_map(|assumption| chains.get(assumption))
+        .max()
+        .copied()
+        .unwrap_or(0)
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> AbstractProof<P, Rule> {
+    /// Compute metrics describing this proof, measuring the size of each proposition with the provided function
+    /// An [AbstractProof] contains no composites, so its nesting depth is always zero
+    /// Dependencies which form a cycle are not followed when finding the longest dependency chain
+    pub fn statistics(&self, size: impl Fn(&P) -> usize) -> ProofStatistics<P, Rule> {
+        let mut statistics = ProofStatistics::default();
+        let mut propositions = HashPropSet1O::new();
+        for inference in self.inferences() {
+            statistics.record_inference(inference);
+            propositions.extend(inference.assumptions.iter().cloned());
+            propositions.extend(inference.conclusions.iter().cloned());
+        }
+        statistics.longest_dependency_chain = self.longest_dependency_chain();
+        statistics.record_sizes(propositions, size);
+        statistics
+    }
+
+    fn longest_dependency_chain(&self) -> usize {
+        // The chain length ending at the inference in each slot; zero while the inference is being visited
+        let mut chains: Vec<Option<usize>> = vec![None; self.slot_count()];
+        // The stack holds the index of each inference being visited, alongside the index of the next assumption to visit
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for (root, _) in self.slotted_inferences() {
+            if chains[root].is_some() {
+                continue;
+            }
+            chains[root] = Some(0);
+            stack.push((root, 0));
+            while let Some((index, next_assumption)) = stack.last_mut() {
+                let inference = self
+                    .get_slot(*index)
+                    .expect("Every visited slot holds an inference");
+                let Some(assumption) = inference.assumptions.get(*next_assumption) else {
+                    let longest = inference
+                        .assumptions
+                        .iter()
+                        .filter_map(|assumption| self.slot_concluding(assumption))
+                        .filter_map(|dependency| chains[dependency])
+                        .max()
+                        .unwrap_or(0);
+                    chains[*index] = Some(longest + 1);
+                    stack.pop();
+                    continue;
+                };
+                *next_assumption += 1;
+                if let Some(dependency) = self.slot_concluding(assumption) {
+                    if chains[dependency].is_none() {
+                        chains[dependency] = Some(0);
+                        stack.push((dependency, 0));
+                    }
+                }
+            }
+        }
+        chains.into_iter().flatten().max().unwrap_or(0)
+    }
+}
+
+impl<P: Proposition, Rule: InferenceRule<P>> Display for ProofStatistics<P, Rule> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "inferences: {}", self.inference_count)?;
+        writeln!(f, "citations: {}", self.citation_count)?;
+        writeln!(f, "rules used: {}", self.inferences_by_rule.len())?;
+        writeln!(f, "max nesting depth: {}", self.max_nesting_depth)?;
+        writeln!(
+            f,
+            "longest dependency chain: {}",
+            self.longest_dependency_chain
+        )?;
+        writeln!(
+            f,
+            "propositions used more than once: {}",
+            self.reused_propositions().len()
+        )?;
+        write!(f, "proposition sizes:")?;
+        for (size, count) in &self.proposition_sizes {
+            write!(f, " {}x{}", count, size)?;
+        }
+        writeln!(f)
+    }
+}
@@ -7,6 +7,8 @@ use crate::{
 
 mod partial;
 pub use partial::{PartialPropositionalAssignment, PartialPropositionalAssignmentConstructor};
+mod unification;
+pub use unification::UnifiableProposition;
 
 pub trait PropositionalAssignment<
     PreAssignmentUprop: UnassignedProposition,
@@ -0,0 +1,14 @@
+use crate::propositions::{
+    assignments::PartialPropositionalAssignment, types::unassigned::UnassignedProposition,
+};
+
+/// Unassigned propositions which can be unified with one another
+/// Unlike reverse assignment, which matches an unassigned proposition against a fixed one, unification may assign to variables within either proposition
+pub trait UnifiableProposition<Assignment: PartialPropositionalAssignment<Self, Self>>:
+    UnassignedProposition
+{
+    type UnificationError;
+    /// Find the most general assignment which makes this proposition and the other equal when applied to both
+    /// Every other assignment which makes them equal is an instance of the one returned
+    fn unify(&self, other: &Self) -> Result<Assignment, Self::UnificationError>;
+}
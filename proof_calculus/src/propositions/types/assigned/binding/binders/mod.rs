@@ -8,13 +8,13 @@ use crate::{
                 Proposition,
                 binding::bounds::{
                     GetBoundsForConstructiblePropsSubsumedByUprop, GetBoundsForPropIdenticalToProp,
-                    GetBoundsForPropsSubsumedByUprop, InsertBoundsForProp,
+                    GetBoundsForPropsSubsumedByUprop, InsertBoundsForProp, RemoveBoundsForProp,
                 },
             },
             unassigned::UnassignedProposition,
         },
     },
-    utils::collections::binding::binders::{Binder, InsertBinder},
+    utils::collections::binding::binders::{Binder, InsertBinder, RemoveBinder},
 };
 
 pub trait GetBinderForPropIdenticalToProp<ElemProp: Proposition>: Binder {
@@ -66,3 +66,14 @@ pub trait InsertBinderForProp<'elem, ElemProp: 'elem + Proposition>:
         self.insert_by_bounds(&Self::DefaultInsertionBounds::from(prop), value)
     }
 }
+
+pub trait RemoveBinderForProp<'elem, ElemProp: 'elem + Proposition>:
+    RemoveBinder<Self::DefaultRemovalBounds>
+{
+    type DefaultRemovalBounds: RemoveBoundsForProp<'elem, ElemProp, Self>;
+
+    /// Remove the value which was inserted for the provided proposition, returning whether it was removed
+    fn remove_prop(&mut self, prop: &'elem ElemProp, value: &Self::Value) -> bool {
+        self.remove_by_bounds(&Self::DefaultRemovalBounds::from(prop), value)
+    }
+}
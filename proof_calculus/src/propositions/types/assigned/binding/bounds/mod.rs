@@ -6,8 +6,8 @@ use crate::{
         types::{assigned::Proposition, unassigned::UnassignedProposition},
     },
     utils::collections::binding::{
-        binders::{Binder, InsertBinder},
-        bounds::{GetBounds, InsertBounds, UniqueGetBounds},
+        binders::{Binder, InsertBinder, RemoveBinder},
+        bounds::{GetBounds, InsertBounds, RemoveBounds, UniqueGetBounds},
     },
 };
 
@@ -19,6 +19,10 @@ pub trait InsertBoundsForProp<'elem, ElemProp: 'elem + Proposition, B: InsertBin
     InsertBounds<B> + From<&'elem ElemProp>
 {
 }
+pub trait RemoveBoundsForProp<'elem, ElemProp: 'elem + Proposition, B: RemoveBinder<Self>>:
+    RemoveBounds<B> + From<&'elem ElemProp>
+{
+}
 
 pub trait GetBoundsForPropsSubsumedByUprop<
     'elem,
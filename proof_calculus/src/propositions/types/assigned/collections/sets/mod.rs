@@ -1,7 +1,7 @@
 use crate::propositions::types::assigned::Proposition;
 
 pub mod implementations;
-// pub mod normal_forms;
+pub mod normal_forms;
 
 pub trait PropSet2O<P: Proposition>:
     Clone + PartialEq + Eq + IntoIterator<Item = Self::I> + FromIterator<Self::I>
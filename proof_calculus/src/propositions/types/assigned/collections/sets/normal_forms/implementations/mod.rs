@@ -1 +1 @@
-pub mod tiny;
\ No newline at end of file
+pub mod tiny;
@@ -1,78 +1,124 @@
 use std::marker::PhantomData;
 
-use crate::structures::{propositions::Proposition, propsets::{PropSet1O, PropSet2O}};
+use crate::{
+    normal_forms::{
+        Conjunctive, Disjunctive, NormalFormKind,
+        clauses::{
+            ComplementaryProposition, NormalFormClause, distribute, remove_complementary,
+            remove_subsumed,
+        },
+    },
+    propositions::types::assigned::{Proposition, collections::sets::PropSet2O},
+};
 
 pub mod implementations;
 
-// pub struct Cnf<P:Proposition,PS:PropSet2O<P>>(PS,PhantomData<P>);
-// impl <P: Proposition,PS:PropSet2O<P>> Cnf<P,PS> {
-//     pub fn get_clauses(&self) -> impl IntoIterator<Item=&CnfClause<P,PS::I>> {
-//         let x = self.0
-//             .iter()
-//             .map(|x| &CnfClause(x,PhantomData));
-//     }
-//     pub fn into_clauses(self) -> impl IntoIterator<Item=CnfClause<P,PS::I>> { self.0.into_iter().map(|x| x.into()) }
-    
-//     pub fn contains(&self, seek: &PS::I) -> bool { self.0.contains(seek) }
-//     pub fn len(&self) -> usize { self.0.len() }
-// }
-// impl <P: Proposition,PS:PropSet2O<P>> From<PS> for Cnf<P,PS> {
-//     fn from(value: PS) -> Self { Cnf(value,PhantomData) }
-// }
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// A collection of clauses, which is conjunctive or disjunctive depending on its kind
+pub struct NormalForm<P: Proposition, PS: PropSet2O<P>, Kind: NormalFormKind>(
+    PS,
+    PhantomData<(P, Kind)>,
+);
+/// A conjunction of clauses, each of which is a disjunction of propositions
+pub type Cnf<P, PS> = NormalForm<P, PS, Conjunctive>;
+/// A disjunction of clauses, each of which is a conjunction of propositions
+pub type Dnf<P, PS> = NormalForm<P, PS, Disjunctive>;
 
-pub struct CnfClause<P:Proposition,PS:PropSet1O<P>>(PS,PhantomData<P>);
-impl <P: Proposition,PS:PropSet1O<P>> CnfClause<P,PS> {
-    pub fn get_propositions(&self) -> &impl IntoIterator<Item=P> { &self.0 }
-    pub fn into_propositions(self) -> impl IntoIterator<Item=P> { self.0 }
-    
-    pub fn contains(&self, proposition: &P) -> bool { self.0.contains(proposition) }
-    pub fn len(&self) -> usize { self.0.len() }
-}
-impl <P: Proposition,PS:PropSet1O<P>> From<PS> for CnfClause<P,PS> {
-    fn from(value: PS) -> Self { CnfClause(value,PhantomData) }
-}
+impl<P: Proposition, PS: PropSet2O<P>, Kind: NormalFormKind> NormalForm<P, PS, Kind> {
+    pub fn new(clauses: PS) -> Self {
+        Self(clauses, PhantomData)
+    }
+    pub fn get_clauses(&self) -> impl Iterator<Item = &PS::I> {
+        self.0.iter()
+    }
+    pub fn into_clauses(self) -> PS {
+        self.0
+    }
 
-pub struct Dnf<P:Proposition,PS:PropSet2O<P>>(PS,PhantomData<P>);
-impl <P: Proposition,PS:PropSet2O<P>> Dnf<P,PS> {
-    pub fn get_clauses(&self) -> impl IntoIterator<Item=&DnfClause<P,PS::I>> { self.0.iter().map(|c| DnfClause(c,PhantomData)) }
-    pub fn into_clauses(self) -> impl IntoIterator<Item=PS::I> { self.0 }
-    
-    pub fn contains(&self, seek: &PS::I) -> bool { self.0.contains(seek) }
-    pub fn len(&self) -> usize { self.0.len() }
+    pub fn contains(&self, seek: &PS::I) -> bool {
+        self.0.contains(seek)
+    }
+    pub fn len(&self) -> usize {
+        self.0.count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
-impl <P: Proposition,PS:PropSet2O<P>> From<PS> for Dnf<P,PS> {
-    fn from(value: PS) -> Self { Dnf(value,PhantomData) }
+impl<P: Proposition, PS: PropSet2O<P>, Kind: NormalFormKind> NormalForm<P, PS, Kind>
+where
+    PS::I: NormalFormClause<P>,
+{
+    /// Remove every clause which contains another clause
+    /// In a CNF such a clause is implied by the clause it contains, and in a DNF it implies it
+    pub fn remove_subsumed(&self) -> Self {
+        Self::new(
+            remove_subsumed(self.0.iter().cloned())
+                .into_iter()
+                .collect(),
+        )
+    }
+    /// Remove every clause which contains a proposition alongside its complement
+    /// In a CNF such clauses always hold, and in a DNF they never do
+    pub fn remove_complementary(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        Self::new(
+            remove_complementary(self.0.iter().cloned())
+                .into_iter()
+                .collect(),
+        )
+    }
+    pub fn simplify(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        self.remove_complementary().remove_subsumed()
+    }
+    /// Convert to the equivalent normal form of the dual kind by distributing the clauses over each other
+    /// This may produce exponentially many clauses
+    pub fn to_dual(&self) -> NormalForm<P, PS, Kind::Dual> {
+        NormalForm::new(distribute(self.0.iter()).into_iter().collect())
+    }
+}
+impl<P: Proposition, PS: PropSet2O<P>, Kind: NormalFormKind> From<PS> for NormalForm<P, PS, Kind> {
+    fn from(value: PS) -> Self {
+        Self::new(value)
+    }
 }
 
-pub struct DnfClause<P:Proposition,PS:PropSet1O<P>>(PS,PhantomData<P>);
-impl <P: Proposition,PS:PropSet1O<P>> DnfClause<P,PS> {
-    pub fn get_propositions(&self) -> &impl IntoIterator<Item=P> { &self.0 }
-    pub fn into_propositions(self) -> impl IntoIterator<Item=P> { self.0 }
-    
-    pub fn contains(&self, proposition: &P) -> bool { self.0.contains(proposition) }
-    pub fn len(&self) -> usize { self.0.len() }
+impl<P: Proposition, PS: PropSet2O<P>> Cnf<P, PS>
+where
+    PS::I: NormalFormClause<P>,
+{
+    /// Remove every clause which contains a proposition alongside its complement, as such clauses always hold
+    pub fn remove_tautologies(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        self.remove_complementary()
+    }
+    /// Convert to the equivalent disjunctive normal form by distributing the clauses over each other
+    /// This may produce exponentially many clauses
+    pub fn to_dnf(&self) -> Dnf<P, PS> {
+        self.to_dual()
+    }
 }
-impl <P: Proposition,PS:PropSet1O<P>> From<PS> for DnfClause<P,PS> {
-    fn from(value: PS) -> Self { DnfClause(value,PhantomData) }
+impl<P: Proposition, PS: PropSet2O<P>> Dnf<P, PS>
+where
+    PS::I: NormalFormClause<P>,
+{
+    /// Remove every clause which contains a proposition alongside its complement, as such clauses never hold
+    pub fn remove_contradictions(&self) -> Self
+    where
+        P: ComplementaryProposition,
+    {
+        self.remove_complementary()
+    }
+    /// Convert to the equivalent conjunctive normal form by distributing the clauses over each other
+    /// This may produce exponentially many clauses
+    pub fn to_cnf(&self) -> Cnf<P, PS> {
+        self.to_dual()
+    }
 }
-
-
-// struct MapCnfIter<P:Proposition,PS:PropSet1O<P>,I>(I);
-// impl<'a, I> Iterator for MapCnfIter<I>
-// where
-//     I: Iterator<Item = &'a i32>,
-// {
-//     type Item = NewType;
-//     fn next(&mut self) -> Option<Self::Item> {
-//         self.0.next().map(|v| NewType(*v))
-//     }
-// }
-
-// impl<'a> IntoIterator for &'a Source {
-//     type Item = NewType;
-//     type IntoIter = MapIter<std::slice::Iter<'a, i32>>;
-
-//     fn into_iter(self) -> Self::IntoIter {
-//         MapIter(self.0.iter())
-//     }
-// }
\ No newline at end of file
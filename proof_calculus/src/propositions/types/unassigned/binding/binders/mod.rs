@@ -8,11 +8,11 @@ use crate::{
             binding::bounds::{
                 GetBoundsForUpropIdenticalToUprop, GetBoundsForUpropsEquivalentToUprop,
                 GetBoundsForUpropsSubsumedByUprop, GetBoundsForUpropsSubsumingProp,
-                InsertBoundsForUprop,
+                InsertBoundsForUprop, RemoveBoundsForUprop,
             },
         },
     },
-    utils::collections::binding::binders::{Binder, InsertBinder},
+    utils::collections::binding::binders::{Binder, InsertBinder, RemoveBinder},
 };
 
 pub trait GetBinderForUpropIdenticalToUprop<ElemUprop: UnassignedProposition>: Binder {
@@ -75,3 +75,14 @@ pub trait InsertBinderForUprop<'elem, ElemUprop: 'elem + UnassignedProposition>:
         self.insert_by_bounds(&Self::DefaultInsertionBounds::from(uprop), value)
     }
 }
+
+pub trait RemoveBinderForUprop<'elem, ElemUprop: 'elem + UnassignedProposition>:
+    RemoveBinder<Self::DefaultRemovalBounds>
+{
+    type DefaultRemovalBounds: RemoveBoundsForUprop<'elem, ElemUprop, Self>;
+
+    /// Remove the value which was inserted for the provided unassigned proposition, returning whether it was removed
+    fn remove_uprop(&mut self, uprop: &'elem ElemUprop, value: &Self::Value) -> bool {
+        self.remove_by_bounds(&Self::DefaultRemovalBounds::from(uprop), value)
+    }
+}
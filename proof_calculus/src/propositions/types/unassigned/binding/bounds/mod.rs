@@ -9,8 +9,8 @@ use crate::{
         types::{assigned::Proposition, unassigned::UnassignedProposition},
     },
     utils::collections::binding::{
-        binders::{Binder, InsertBinder},
-        bounds::{GetBounds, InsertBounds, UniqueGetBounds},
+        binders::{Binder, InsertBinder, RemoveBinder},
+        bounds::{GetBounds, InsertBounds, RemoveBounds, UniqueGetBounds},
     },
 };
 
@@ -119,3 +119,10 @@ pub trait InsertBoundsForUprop<
 >: InsertBounds<B> + From<&'elem ElemUprop>
 {
 }
+pub trait RemoveBoundsForUprop<
+    'elem,
+    ElemUprop: 'elem + UnassignedProposition,
+    B: RemoveBinder<Self>,
+>: RemoveBounds<B> + From<&'elem ElemUprop>
+{
+}
@@ -0,0 +1,71 @@
+use crate::{
+    proofs::{
+        errors::ValidatableInferenceRule,
+        inferences::{Inference, InferenceRule},
+        sequential::{SequentialProof, composite::CompositeSequentialProof},
+    },
+    propositions::types::assigned::Proposition,
+};
+
+/// A proposition identified only by its number
+#[derive(Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub(crate) struct TestProp(pub(crate) u32);
+impl Proposition for TestProp {}
+
+/// A rule which accepts any inference ([Valid](TestRule::Valid)) or none ([Invalid](TestRule::Invalid))
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum TestRule {
+    Valid,
+    Invalid,
+}
+impl InferenceRule<TestProp> for TestRule {}
+impl ValidatableInferenceRule<TestProp> for TestRule {
+    type Err = TestProp;
+
+    fn validate(inference: &Inference<TestProp, Self>) -> Result<(), Self::Err> {
+        match inference.inference_type {
+            TestRule::Valid => Ok(()),
+            TestRule::Invalid => Err(inference.conclusions[0].clone()),
+        }
+    }
+}
+
+pub(crate) fn props(ids: &[u32]) -> Box<[TestProp]> {
+    ids.iter().map(|id| TestProp(*id)).collect()
+}
+pub(crate) fn inference(
+    rule: TestRule,
+    assumptions: &[u32],
+    conclusion: u32,
+) -> Inference<TestProp, TestRule> {
+    Inference {
+        inference_type: rule,
+        assumptions: props(assumptions),
+        conclusions: props(&[conclusion]),
+    }
+}
+pub(crate) fn inference_step(
+    rule: TestRule,
+    assumptions: &[u32],
+    conclusion: u32,
+) -> SequentialProof<TestProp, TestRule> {
+    SequentialProof::Inference(inference(rule, assumptions, conclusion))
+}
+pub(crate) fn composite(
+    assumptions: &[u32],
+    subproofs: Vec<SequentialProof<TestProp, TestRule>>,
+    conclusions: &[u32],
+) -> CompositeSequentialProof<TestProp, TestRule> {
+    CompositeSequentialProof {
+        assumptions: props(assumptions),
+        subproofs: subproofs.into(),
+        explicit_conclusions: props(conclusions),
+    }
+}
+pub(crate) fn composite_step(
+    assumptions: &[u32],
+    subproofs: Vec<SequentialProof<TestProp, TestRule>>,
+    conclusions: &[u32],
+) -> SequentialProof<TestProp, TestRule> {
+    SequentialProof::Composite(composite(assumptions, subproofs, conclusions))
+}
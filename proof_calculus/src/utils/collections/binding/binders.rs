@@ -56,3 +56,27 @@ pub trait GetBinder<B: GetBound>: Binder {
 pub trait InsertBinder<B>: Binder {
     fn insert_by_bounds(&mut self, bounds: &B, value: Self::Value);
 }
+pub trait RemoveBinder<B>: Binder {
+    /// Remove the value from under the provided bounds, returning whether it was removed
+    /// Nothing is removed unless the value is bound by every one of the bounds, so a value is never left partially bound
+    fn remove_by_bounds(&mut self, bounds: &B, value: &Self::Value) -> bool;
+    /// Replace the value with another under the provided bounds, returning whether it was replaced
+    fn replace_by_bounds(&mut self, bounds: &B, old: &Self::Value, new: Self::Value) -> bool
+    where
+        Self: InsertBinder<B>,
+    {
+        if self.remove_by_bounds(bounds, old) {
+            self.insert_by_bounds(bounds, new);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+pub trait UpdateBinder: Binder {
+    /// Remove the value from under every bound it was inserted with, returning whether it was present
+    fn remove_value(&mut self, value: &Self::Value) -> bool;
+    /// Replace the value with another under every bound it was inserted with, returning whether it was present
+    fn replace_value(&mut self, old: &Self::Value, new: Self::Value) -> bool;
+}
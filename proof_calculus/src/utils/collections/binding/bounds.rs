@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::utils::collections::binding::binders::{Binder, InsertBinder};
+use crate::utils::collections::binding::binders::{Binder, InsertBinder, RemoveBinder};
 
 pub trait GetBound {
     type ExtraReturnData;
@@ -29,3 +29,10 @@ pub trait InsertBounds<B: InsertBinder<Self>>: Sized {
     }
 }
 impl<Bs, Br: InsertBinder<Bs>> InsertBounds<Br> for Bs {}
+
+pub trait RemoveBounds<B: RemoveBinder<Self>>: Sized {
+    fn remove_from(&self, binder: &mut B, value: &B::Value) -> bool {
+        binder.remove_by_bounds(self, value)
+    }
+}
+impl<Bs, Br: RemoveBinder<Bs>> RemoveBounds<Br> for Bs {}
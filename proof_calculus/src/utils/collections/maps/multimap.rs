@@ -40,6 +40,12 @@ impl<K: Hash + Eq, V: Hash + Eq> MultiMap<K, V> {
         }
     }
 
+    pub fn contains(&self, key: &K, value: &V) -> bool {
+        self.inner.get(key).is_some_and(|set| set.contains(value))
+    }
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
     pub fn get(&self, key: &K) -> Option<&HashSet<V>> {
         self.inner.get(key)
     }
@@ -35,8 +35,35 @@ impl<T: FastOrd + Eq> IntoIterator for TinyImmutableOrderedSet<T> {
 }
 impl<T: FastOrd + Eq> FromIterator<T> for TinyImmutableOrderedSet<T> {
     fn from_iter<I: IntoIterator<Item = T>>(propositions: I) -> Self {
-        Self(propositions.into_iter().sorted_by(T::fast_cmp).collect())
+        Self(
+            propositions
+                .into_iter()
+                .sorted_by(T::fast_cmp)
+                .dedup_by(|left, right| left.fast_cmp(right).is_eq())
+                .collect(),
+        )
     }
 }
 
-// TODO: Tests!
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter_collapses_duplicates() {
+        let with_duplicates = TinyImmutableOrderedSet::from_iter([3, 1, 3, 2, 1, 3]);
+        let without = TinyImmutableOrderedSet::from_iter([1, 2, 3]);
+        assert_eq!(with_duplicates, without);
+        assert_eq!(with_duplicates.count(), 3);
+        assert_eq!(with_duplicates.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+        assert!(with_duplicates.contains(&2));
+        assert!(!with_duplicates.contains(&4));
+    }
+
+    #[test]
+    fn test_from_iter_empty() {
+        let empty = TinyImmutableOrderedSet::<u32>::from_iter([]);
+        assert_eq!(empty.count(), 0);
+        assert!(!empty.contains(&0));
+    }
+}
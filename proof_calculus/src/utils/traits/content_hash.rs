@@ -0,0 +1,193 @@
+use std::{fmt::Display, rc::Rc, str::FromStr, sync::Arc};
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+/// A 128-bit digest identifying a value by its content
+/// Content IDs are the same on every platform, in every run and under every Rust version, so they can be stored and compared later
+/// They are not collision-resistant, so two values sharing a content ID should be compared in full before being treated as the same
+pub struct ContentId(pub u128);
+
+impl Display for ContentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+/// The string was not 32 hexadecimal digits, as written by [ContentId]'s [Display] implementation
+pub struct ParseContentIdError(pub String);
+
+impl FromStr for ContentId {
+    type Err = ParseContentIdError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 32 || !s.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(ParseContentIdError(s.to_string()));
+        }
+        u128::from_str_radix(s, 16)
+            .map(ContentId)
+            .map_err(|_| ParseContentIdError(s.to_string()))
+    }
+}
+
+/// Builds a [ContentId] from a sequence of writes, using the 128-bit FNV-1a hash
+/// Every integer is written in little-endian order, and `usize` is always written as 64 bits
+/// This hash is not cryptographic, so it should not be relied upon to identify content chosen by an adversary
+#[derive(Clone, Debug)]
+pub struct ContentHasher {
+    state: u128,
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl ContentHasher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= *byte as u128;
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+    pub fn write_u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+    pub fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+    pub fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+    pub fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+    pub fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+    pub fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+    /// Write the length of a sequence, so that adjacent sequences cannot be confused with one another
+    pub fn write_length(&mut self, length: usize) {
+        self.write_usize(length);
+    }
+    pub fn write_id(&mut self, id: ContentId) {
+        self.write_u128(id.0);
+    }
+
+    pub fn finish(&self) -> ContentId {
+        ContentId(self.state)
+    }
+}
+
+/// Values which can be hashed by their content into a [ContentId] which is stable across platforms, runs and Rust versions
+/// Unlike [Hash](std::hash::Hash), implementations must only depend on a value's content, and must never change once published
+pub trait ContentHash {
+    fn content_hash(&self, hasher: &mut ContentHasher);
+
+    fn content_id(&self) -> ContentId {
+        let mut hasher = ContentHasher::new();
+        self.content_hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+macro_rules! content_hash_for_integer {
+    ($($int:ty => $write:ident),*) => {
+        $(
+            impl ContentHash for $int {
+                #[inline]
+                fn content_hash(&self, hasher: &mut ContentHasher) {
+                    hasher.$write(*self as _);
+                }
+            }
+        )*
+    };
+}
+content_hash_for_integer!(
+    u8 => write_u8, u16 => write_u16, u32 => write_u32, u64 => write_u64, u128 => write_u128, usize => write_usize,
+    i8 => write_u8, i16 => write_u16, i32 => write_u32, i64 => write_u64, i128 => write_u128, isize => write_usize
+);
+
+impl ContentHash for bool {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_u8(*self as u8);
+    }
+}
+impl ContentHash for char {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_u32(*self as u32);
+    }
+}
+impl ContentHash for str {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_length(self.len());
+        hasher.write(self.as_bytes());
+    }
+}
+impl ContentHash for String {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.as_str().content_hash(hasher);
+    }
+}
+impl ContentHash for ContentId {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_id(*self);
+    }
+}
+
+impl<T: ContentHash> ContentHash for [T] {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_length(self.len());
+        for item in self {
+            item.content_hash(hasher);
+        }
+    }
+}
+impl<T: ContentHash> ContentHash for Vec<T> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        self.as_slice().content_hash(hasher);
+    }
+}
+impl<T: ContentHash> ContentHash for Option<T> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        match self {
+            None => hasher.write_u8(0),
+            Some(value) => {
+                hasher.write_u8(1);
+                value.content_hash(hasher);
+            }
+        }
+    }
+}
+
+// Pointers hash the same as the value they point to, so the choice of pointer does not change a value's content ID
+impl<T: ContentHash + ?Sized> ContentHash for &T {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        (**self).content_hash(hasher);
+    }
+}
+impl<T: ContentHash + ?Sized> ContentHash for Box<T> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        (**self).content_hash(hasher);
+    }
+}
+impl<T: ContentHash + ?Sized> ContentHash for Rc<T> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        (**self).content_hash(hasher);
+    }
+}
+impl<T: ContentHash + ?Sized> ContentHash for Arc<T> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        (**self).content_hash(hasher);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod combinable;
+pub mod content_hash;
 pub mod fast_ord;
 pub mod map;
 pub mod try_from_iter;
@@ -19,6 +19,7 @@ use crate::structures::atoms::PhilosophicaInferenceAtoms;
 #[derive(Clone)]
 pub enum ConjunctionIntroductionError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     ConjunctionWrongLength(TblExpressionLength),
     ConjunctionWrongHead(TblExpression<C>),
     LeftSideInequal(TblExpression<C>, TblExpression<C>),
@@ -35,7 +36,7 @@ pub fn validate_conjunction_introduction<
 ) -> Result<(), ConjunctionIntroductionError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| ConjunctionIntroductionError::WrongConclusionCount(e.len()))?;
     // Throw an error if there are not/ exactly two assumptions
     let [assumption_left, assumption_right] =
         as_sized_slice(inference.get_located_assumptions())
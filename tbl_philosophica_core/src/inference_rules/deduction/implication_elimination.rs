@@ -19,6 +19,7 @@ use crate::structures::atoms::PhilosophicaInferenceAtoms;
 #[derive(Clone)]
 pub enum ImplicationEliminationError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     ImplicationWrongLength(TblExpressionLength),
     ImplicationWrongHead(TblExpression<C>),
     AntecedentInequal(TblExpression<C>, TblExpression<C>),
@@ -31,7 +32,7 @@ pub fn verify_implication_elimination<'a, C: CompoundTblExpression, Rule: TblInf
 ) -> Result<(), ImplicationEliminationError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| ImplicationEliminationError::WrongConclusionCount(e.len()))?;
     // Throw an error if there are not/ exactly two assumptions
     let [assumption_left, assumption_right] =
         as_sized_slice(inference.get_located_assumptions())
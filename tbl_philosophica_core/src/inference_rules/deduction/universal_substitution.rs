@@ -23,6 +23,7 @@ use crate::structures::atoms::PhilosophicaInferenceAtoms;
 #[derive(Clone)]
 pub enum UniversalSubstitutionError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     SubstitutionWrongLength(TblExpressionLength),
     SubtitutionWrongHead(TblExpression<C>),
     SubstitutionComparisonError(SubstitutionComparisonError<C>),
@@ -34,7 +35,7 @@ pub fn verify_universal_substitution<'a, C: CompoundTblExpression, Rule: TblInfe
 ) -> Result<(), UniversalSubstitutionError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| UniversalSubstitutionError::WrongConclusionCount(e.len()))?;
     // Throw an error if there is not exactly one assumption
     let [substitution] = as_sized_slice(inference.get_located_assumptions())
         .map_err(|e| UniversalSubstitutionError::WrongAssumptionCount(e.len()))?;
@@ -22,6 +22,7 @@ use crate::{
 #[derive(Clone)]
 pub enum AtomDifferentiationError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     NegationWrongLength(TblExpressionLength),
     NegationWrongHead(TblExpression<C>),
     IdentityWrongLength(TblExpressionLength),
@@ -39,7 +40,7 @@ pub fn verify_atom_differentiation<C: CompoundTblExpression, Rule: TblInferenceR
 ) -> Result<(), AtomDifferentiationError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| AtomDifferentiationError::WrongConclusionCount(e.len()))?;
     // Throw ane error if the rule has any assumptions (this rule requires none)
     let [] = as_sized_slice(inference.get_located_assumptions())
         .map_err(|e| AtomDifferentiationError::WrongAssumptionCount(e.len()))?;
@@ -22,6 +22,7 @@ use crate::{
 #[derive(Clone)]
 pub enum TupleAppendationError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     IdentityWrongSize(TblExpressionLength),
     IdentityWrongHead(TblExpression<C>),
     AppendationWrongSize(TblExpressionLength),
@@ -41,7 +42,7 @@ pub fn verify_tuple_appendation<C: CompoundTblExpression, Rule: TblInferenceRule
 ) -> Result<(), TupleAppendationError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| TupleAppendationError::WrongConclusionCount(e.len()))?;
     // Throw ane error if the rule has any assumptions (this rule requires none)
     let [] = as_sized_slice(inference.get_located_assumptions())
         .map_err(|e| TupleAppendationError::WrongAssumptionCount(e.len()))?;
@@ -22,6 +22,7 @@ use crate::{
 #[derive(Clone)]
 pub enum UnitarityAssertionError<C: CompoundTblExpression> {
     WrongAssumptionCount(usize),
+    WrongConclusionCount(usize),
     UnitarityWrongLength(TblExpressionLength),
     UnitarityWrongHead(TblExpression<C>),
     UnitarityParamNotVerbatim(TblExpression<C>),
@@ -34,7 +35,7 @@ pub fn verify_unitarity_assertion<C: CompoundTblExpression, Rule: TblInferenceRu
 ) -> Result<(), UnitarityAssertionError<C>> {
     // Throw an error if there is not exactly one conclusion
     let [conclusion] = as_sized_slice(inference.get_located_explicit_conclusions())
-        .expect("Inference objects must always return exactly one conclusion");
+        .map_err(|e| UnitarityAssertionError::WrongConclusionCount(e.len()))?;
     // Throw ane error if the rule has any assumptions (this rule requires none)
     let [] = as_sized_slice(inference.get_located_assumptions())
         .map_err(|e| UnitarityAssertionError::WrongAssumptionCount(e.len()))?;
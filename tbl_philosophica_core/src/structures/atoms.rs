@@ -1,6 +1,10 @@
 use enum_iterator::Sequence;
-use tbl_proof_calculus::expressions::assigned::{
-    TblExpression, atomic::AtomicTblExpression, compound::CompoundTblExpression,
+use tbl_proof_calculus::{
+    expressions::{
+        assigned::{TblExpression, atomic::AtomicTblExpression, compound::CompoundTblExpression},
+        types::assigned::atom::TblExpressionAtom,
+    },
+    proof_calculus_derived::aliases::propositions::types::negation::TblNegation,
 };
 
 /// Atoms which are built in to Tuple-Based Logic, and will appear in all axiomatic systems in Tuple-Based Logic
@@ -46,12 +50,22 @@ impl<C: CompoundTblExpression> Into<TblExpression<C>> for PhilosophicaInferenceA
     }
 }
 
+/// Negations in Philosophica are headed by the [Negation](PhilosophicaInferenceAtoms::Negation) atom
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PhilosophicaNegation;
+impl TblNegation for PhilosophicaNegation {
+    const NEGATION_ATOM: TblExpressionAtom = TblExpressionAtom(3);
+}
+
 #[cfg(test)]
 mod tests {
     use enum_iterator::all;
-    use tbl_proof_calculus::expressions::assigned::atomic::AtomicTblExpression;
+    use tbl_proof_calculus::{
+        expressions::assigned::atomic::AtomicTblExpression,
+        proof_calculus_derived::aliases::propositions::types::negation::TblNegation,
+    };
 
-    use crate::structures::atoms::PhilosophicaInferenceAtoms;
+    use crate::structures::atoms::{PhilosophicaInferenceAtoms, PhilosophicaNegation};
 
     #[test]
     fn test_differentiation_of_builtins() {
@@ -68,4 +82,10 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_negation_atom() {
+        let negation: AtomicTblExpression = PhilosophicaInferenceAtoms::Negation.into();
+        assert_eq!(PhilosophicaNegation::NEGATION_ATOM.0, negation.0)
+    }
 }
@@ -0,0 +1,199 @@
+//! Compares [TblDiscriminationTreeFactBinder] against [TblExpressionBinder] on randomly generated fact bases of increasing size
+//! [TblExpressionBinder] cannot find the patterns which a fact is an instance of, so those queries are timed for the discrimination tree alone
+//! Run with `cargo bench -p tbl_proof_calculus --bench binders`
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use proof_calculus::propositions::types::{
+    assigned::binding::binders::{
+        GetBinderForPropIdenticalToProp, GetBinderForPropsSubsumedByUprop, InsertBinderForProp,
+    },
+    unassigned::binding::binders::{GetBinderForUpropsSubsumingProp, InsertBinderForUprop},
+};
+use tbl_proof_calculus::expressions::types::{
+    assigned::{
+        BoxTblExpression, TblExpression, atom::TblExpressionAtom,
+        binding::binders::TblExpressionBinder, compound::TblExpressionCompound,
+    },
+    unassigned::{
+        BoxUnassignedTblExpression, UnassignedTblExpression,
+        binding::binders::discrimination_tree::{
+            TblDiscriminationTreeFactBinder, TblDiscriminationTreePatternBinder,
+        },
+        variable::TblExpressionVariable,
+    },
+};
+
+const FACT_COUNTS: [usize; 3] = [1_000, 10_000, 100_000];
+const PATTERN_COUNT: usize = 1_000;
+const ATOM_COUNT: u64 = 64;
+const MAX_DEPTH: usize = 4;
+
+/// A linear congruential generator, so that every run uses the same fact base
+struct Lcg(u64);
+impl Lcg {
+    fn next(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+fn random_expression(rng: &mut Lcg, depth: usize) -> BoxTblExpression {
+    if depth == 0 || rng.next(3) == 0 {
+        TblExpression::Atom(TblExpressionAtom(rng.next(ATOM_COUNT) as u16))
+    } else {
+        let length = 1 + rng.next(3) as usize;
+        TblExpression::Compound(
+            (0..length)
+                .map(|_| random_expression(rng, depth - 1))
+                .collect(),
+        )
+    }
+}
+
+/// Replace some of the atoms of the expression with variables, giving a pattern which the expression is an instance of
+fn random_generalization(
+    expr: &BoxTblExpression,
+    rng: &mut Lcg,
+    variable_count: &mut usize,
+) -> BoxUnassignedTblExpression {
+    match expr {
+        TblExpression::Atom(_) if rng.next(2) == 0 => {
+            *variable_count += 1;
+            UnassignedTblExpression::Variable(TblExpressionVariable(*variable_count - 1))
+        }
+        TblExpression::Atom(atom) => UnassignedTblExpression::Atom(*atom),
+        TblExpression::Compound(compound) => UnassignedTblExpression::Compound(
+            compound
+                .as_slice()
+                .iter()
+                .map(|subexpression| random_generalization(subexpression, rng, variable_count))
+                .collect(),
+        ),
+    }
+}
+
+fn time<R>(mut operation: impl FnMut() -> R) -> Duration {
+    let start = Instant::now();
+    black_box(operation());
+    start.elapsed()
+}
+
+fn report(name: &str, fact_count: usize, duration: Duration, operation_count: usize) {
+    println!(
+        "{name:<40} {fact_count:>8} facts {:>12.3?} total {:>10.3?} per operation",
+        duration,
+        duration / operation_count as u32
+    );
+}
+
+fn main() {
+    for generated_count in FACT_COUNTS {
+        let mut rng = Lcg(generated_count as u64);
+        // Identity queries expect at most one value for each fact, so repeated facts are dropped
+        let facts: Vec<_> = (0..generated_count)
+            .map(|_| random_expression(&mut rng, MAX_DEPTH))
+            .unique()
+            .collect();
+        let fact_count = facts.len();
+        let patterns: Vec<_> = facts
+            .iter()
+            .take(PATTERN_COUNT)
+            .map(|fact| random_generalization(fact, &mut rng, &mut 0))
+            .collect();
+
+        let mut intersecting = TblExpressionBinder::new();
+        let duration = time(|| {
+            for (index, fact) in facts.iter().enumerate() {
+                intersecting.insert_prop(fact, index);
+            }
+        });
+        report("intersecting: insert", fact_count, duration, fact_count);
+        let mut tree = TblDiscriminationTreeFactBinder::new();
+        let duration = time(|| {
+            for (index, fact) in facts.iter().enumerate() {
+                tree.insert_prop(fact, index);
+            }
+        });
+        report(
+            "discrimination tree: insert",
+            fact_count,
+            duration,
+            fact_count,
+        );
+
+        let duration = time(|| {
+            facts
+                .iter()
+                .filter(|fact| intersecting.get_identical_to(*fact).is_some())
+                .count()
+        });
+        report(
+            "intersecting: get identical",
+            fact_count,
+            duration,
+            fact_count,
+        );
+        let duration = time(|| {
+            facts
+                .iter()
+                .filter(|fact| tree.get_identical_to(*fact).is_some())
+                .count()
+        });
+        report(
+            "discrimination tree: get identical",
+            fact_count,
+            duration,
+            fact_count,
+        );
+
+        let duration = time(|| {
+            patterns
+                .iter()
+                .map(|pattern| intersecting.get_subsumed_by(pattern).len())
+                .sum::<usize>()
+        });
+        report(
+            "intersecting: get subsumed",
+            fact_count,
+            duration,
+            patterns.len(),
+        );
+        let duration = time(|| {
+            patterns
+                .iter()
+                .map(|pattern| tree.get_subsumed_by(pattern).len())
+                .sum::<usize>()
+        });
+        report(
+            "discrimination tree: get subsumed",
+            fact_count,
+            duration,
+            patterns.len(),
+        );
+
+        let mut rules = TblDiscriminationTreePatternBinder::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            rules.insert_uprop(pattern, index);
+        }
+        let duration = time(|| {
+            facts
+                .iter()
+                .map(|fact| rules.get_subsumers_of(fact).len())
+                .sum::<usize>()
+        });
+        report(
+            "discrimination tree: get subsumers",
+            fact_count,
+            duration,
+            fact_count,
+        );
+    }
+}
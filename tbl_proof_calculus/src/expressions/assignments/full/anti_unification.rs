@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+
+use proof_calculus::utils::traits::map::Map;
+
+use crate::expressions::types::{
+    assigned::{TblExpression, compound::TblExpressionCompound},
+    unassigned::{
+        UnassignedTblExpression, compound::UnassignedTblExpressionCompound,
+        variable::TblExpressionVariable,
+    },
+};
+
+/// The most specific unassigned expression which subsumes each of a collection of expressions, alongside the assignment which maps it back to each of them
+pub struct TblAntiUnification<Uc: UnassignedTblExpressionCompound, Assignment> {
+    pub generalization: UnassignedTblExpression<Uc>,
+    /// The assignment for each expression, in the order the expressions were provided
+    pub assignments: Vec<Assignment>,
+}
+
+/// Find the least general generalization of the provided expressions, or [None] if no expressions are provided
+/// Wherever the expressions disagree, the generalization contains a variable; the same variable is used wherever they disagree in the same way, so that no more general schema is returned than necessary
+/// Variables are numbered from [TblExpressionVariable::first], in the order they appear in a depth first traversal of the generalization
+pub fn anti_unify<
+    C: TblExpressionCompound,
+    Uc: UnassignedTblExpressionCompound + FromIterator<UnassignedTblExpression<Uc>>,
+    Assignment: Default + Map<TblExpressionVariable, TblExpression<C>>,
+>(
+    exprs: &[TblExpression<C>],
+) -> Option<TblAntiUnification<Uc, Assignment>> {
+    if exprs.is_empty() {
+        return None;
+    }
+    let mut variables = HashMap::new();
+    let generalization = anti_unify_helper(&exprs.iter().collect::<Vec<_>>(), &mut variables);
+    let mut assignments: Vec<_> = exprs.iter().map(|_| Assignment::default()).collect();
+    for (disagreement, variable) in variables {
+        for (assignment, expr) in assignments.iter_mut().zip(disagreement) {
+            assignment.insert(variable, expr.clone());
+        }
+    }
+    Some(TblAntiUnification {
+        generalization,
+        assignments,
+    })
+}
+
+fn anti_unify_helper<
+    'a,
+    C: TblExpressionCompound,
+    Uc: UnassignedTblExpressionCompound + FromIterator<UnassignedTblExpression<Uc>>,
+>(
+    exprs: &[&'a TblExpression<C>],
+    variables: &mut HashMap<Vec<&'a TblExpression<C>>, TblExpressionVariable>,
+) -> UnassignedTblExpression<Uc> {
+    match exprs[0] {
+        // If all are the same atom, keep it
+        TblExpression::Atom(atom)
+            if exprs
+                .iter()
+                .all(|expr| matches!(expr, TblExpression::Atom(other) if other == atom)) =>
+        {
+            UnassignedTblExpression::Atom(*atom)
+        }
+        // If all are compounds of the same length, generalize each of their subexpressions
+        TblExpression::Compound(compound)
+            if exprs.iter().all(|expr| {
+                matches!(expr, TblExpression::Compound(other) if other.len() == compound.len())
+            }) =>
+        {
+            UnassignedTblExpression::Compound(
+                (0..compound.len())
+                    .map(|index| {
+                        let subexpressions: Vec<_> = exprs
+                            .iter()
+                            .map(|expr| match expr {
+                                TblExpression::Compound(other) => &other.as_slice()[index],
+                                TblExpression::Atom(_) => {
+                                    unreachable!("Every expression is a compound")
+                                }
+                            })
+                            .collect();
+                        anti_unify_helper(&subexpressions, variables)
+                    })
+                    .collect(),
+            )
+        }
+        // Otherwise, they disagree here, so use the variable for this disagreement
+        _ => {
+            let next_variable = TblExpressionVariable(variables.len());
+            UnassignedTblExpression::Variable(
+                *variables.entry(exprs.to_vec()).or_insert(next_variable),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{
+        assignments::full::implementations::btree::BTreeTblExpressionAssignment,
+        types::{
+            assigned::{BoxTblExpression, compound::r#box::BoxTblExpressionCompound},
+            unassigned::{
+                BoxUnassignedTblExpression, compound::r#box::UnassignedBoxCompoundTblExpression,
+            },
+        },
+    };
+
+    type TestAssignment = BTreeTblExpressionAssignment<BoxTblExpressionCompound>;
+
+    fn anti_unify_boxed(
+        exprs: &[BoxTblExpression],
+    ) -> TblAntiUnification<UnassignedBoxCompoundTblExpression, TestAssignment> {
+        anti_unify(exprs).expect("Expressions were provided")
+    }
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+    /// Substitute the assignment into the generalization
+    fn assign(
+        generalization: &BoxUnassignedTblExpression,
+        assignment: &TestAssignment,
+    ) -> BoxTblExpression {
+        match generalization {
+            UnassignedTblExpression::Atom(atom) => TblExpression::Atom(*atom),
+            UnassignedTblExpression::Variable(variable) => assignment
+                .get(variable)
+                .expect("Every variable of the generalization is assigned")
+                .clone(),
+            UnassignedTblExpression::Compound(compound) => TblExpression::Compound(
+                compound
+                    .as_slice()
+                    .iter()
+                    .map(|subexpression| assign(subexpression, assignment))
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_assignments_give_inputs() {
+        let exprs: [BoxTblExpression; 3] = [
+            [1.into(), [2.into(), 3.into()].into()].into(),
+            [1.into(), [4.into(), 3.into()].into()].into(),
+            [5.into(), [2.into(), 3.into()].into()].into(),
+        ];
+        let result = anti_unify_boxed(&exprs);
+        assert_eq!(
+            result.generalization,
+            UnassignedTblExpression::from([var(0), [var(1), 3.into()].into()])
+        );
+        assert_eq!(result.assignments.len(), exprs.len());
+        for (assignment, expr) in result.assignments.iter().zip(exprs.iter()) {
+            assert_eq!(&assign(&result.generalization, assignment), expr);
+        }
+    }
+
+    #[test]
+    fn test_repeated_disagreement_reuses_variable() {
+        let exprs: [BoxTblExpression; 2] = [
+            [1.into(), 1.into(), 2.into()].into(),
+            [3.into(), 3.into(), 2.into()].into(),
+        ];
+        let result = anti_unify_boxed(&exprs);
+        assert_eq!(
+            result.generalization,
+            UnassignedTblExpression::from([var(0), var(0), 2.into()])
+        );
+        // Disagreements which differ in any expression get different variables
+        let exprs: [BoxTblExpression; 2] =
+            [[1.into(), 1.into()].into(), [3.into(), 4.into()].into()];
+        let result = anti_unify_boxed(&exprs);
+        assert_eq!(
+            result.generalization,
+            UnassignedTblExpression::from([var(0), var(1)])
+        );
+        for (assignment, expr) in result.assignments.iter().zip(exprs.iter()) {
+            assert_eq!(&assign(&result.generalization, assignment), expr);
+        }
+    }
+
+    #[test]
+    fn test_trivial_inputs() {
+        assert!(
+            anti_unify::<
+                BoxTblExpressionCompound,
+                UnassignedBoxCompoundTblExpression,
+                TestAssignment,
+            >(&[])
+            .is_none()
+        );
+        let expr: BoxTblExpression = [1.into(), [2.into()].into()].into();
+        let result = anti_unify_boxed(&[expr.clone()]);
+        assert_eq!(assign(&result.generalization, &result.assignments[0]), expr);
+        assert_eq!(result.assignments[0].0.len(), 0);
+    }
+}
@@ -157,6 +157,7 @@ trait TblAssignmentHelper<C: TblExpressionCompound>: Default {
         }
     }
 }
+
 impl<
     C: TblExpressionCompound,
     M: Default + MapWithoutConflicts<TblExpressionVariable, TblProposition<C>>,
@@ -1,3 +1,4 @@
+pub mod anti_unification;
 pub mod constructor_implementations;
 pub mod errors;
 pub mod implementations;
@@ -1,2 +1,3 @@
 pub mod full;
 pub mod partial;
+pub mod restriction;
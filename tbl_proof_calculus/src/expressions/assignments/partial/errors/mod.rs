@@ -1,2 +1,3 @@
 pub mod assignment;
 pub mod reverse_assignment;
+pub mod unification;
@@ -0,0 +1,61 @@
+use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+
+use crate::expressions::{
+    paths::TblSubexpressionInExpressionPath,
+    types::unassigned::{
+        UnassignedTblExpression, compound::UnassignedTblExpressionCompound,
+        variable::TblExpressionVariable,
+    },
+};
+
+/// Two subexpressions which cannot be made equal; they are different atoms, compounds of different lengths, or an atom and a compound
+pub struct TblUnificationClashError<Uc: UnassignedTblExpressionCompound> {
+    pub left: UnassignedTblExpression<Uc>,
+    pub right: UnassignedTblExpression<Uc>,
+}
+pub type TblUnificationClashErrorInExpression<'a, Uc: UnassignedTblExpressionCompound> =
+    ObjAtPath<'a, TblUnificationClashError<Uc>, TblSubexpressionInExpressionPath>;
+pub type OwnedTblUnificationClashErrorInExpression<Uc: UnassignedTblExpressionCompound> =
+    OwnedObjAtPath<TblUnificationClashError<Uc>, TblSubexpressionInExpressionPath>;
+
+/// A variable which would have to be assigned an expression containing itself
+pub struct TblUnificationOccursCheckError<Uc: UnassignedTblExpressionCompound> {
+    pub variable: TblExpressionVariable,
+    pub expression: UnassignedTblExpression<Uc>,
+}
+pub type TblUnificationOccursCheckErrorInExpression<'a, Uc: UnassignedTblExpressionCompound> =
+    ObjAtPath<'a, TblUnificationOccursCheckError<Uc>, TblSubexpressionInExpressionPath>;
+pub type OwnedTblUnificationOccursCheckErrorInExpression<Uc: UnassignedTblExpressionCompound> =
+    OwnedObjAtPath<TblUnificationOccursCheckError<Uc>, TblSubexpressionInExpressionPath>;
+
+/// The path of each error is the position within both expressions at which it was found
+/// The subexpressions reported have had the variables assigned so far substituted into them, so they may not appear in either expression as written
+pub enum TblUnificationError<Uc: UnassignedTblExpressionCompound> {
+    Clash(OwnedTblUnificationClashErrorInExpression<Uc>),
+    OccursCheck(OwnedTblUnificationOccursCheckErrorInExpression<Uc>),
+}
+impl<Uc: UnassignedTblExpressionCompound> TblUnificationError<Uc> {
+    pub fn clash(
+        left: UnassignedTblExpression<Uc>,
+        right: UnassignedTblExpression<Uc>,
+        path: TblSubexpressionInExpressionPath,
+    ) -> Self {
+        Self::Clash(OwnedObjAtPath {
+            obj: TblUnificationClashError { left, right },
+            path,
+        })
+    }
+    pub fn occurs_check(
+        variable: TblExpressionVariable,
+        expression: UnassignedTblExpression<Uc>,
+        path: TblSubexpressionInExpressionPath,
+    ) -> Self {
+        Self::OccursCheck(OwnedObjAtPath {
+            obj: TblUnificationOccursCheckError {
+                variable,
+                expression,
+            },
+            path,
+        })
+    }
+}
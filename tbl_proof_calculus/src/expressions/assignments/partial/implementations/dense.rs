@@ -201,3 +201,50 @@ mod usage {
     }
 }
 // TODO: consider performance implications of having a From<Vec> implementation for these to leverage From<Vec> of underlying DenseUsizeMap
+
+mod algebra {
+    use crate::expressions::{
+        assignments::{
+            full::{
+                errors::assignment::TblAssignmentError,
+                implementations::dense::DenseTblExpressionAssignment,
+            },
+            partial::{
+                errors::assignment::TblPartialAssignmentError,
+                implementations::{
+                    TblPartialAssignmentHelper, dense::DenseTblPartialExpressionAssignment,
+                },
+            },
+        },
+        types::{
+            assigned::{TblExpression, compound::TblExpressionCompound},
+            unassigned::{UnassignedTblExpression, compound::UnassignedTblExpressionCompound},
+        },
+    };
+
+    impl<Uc: UnassignedTblExpressionCompound> DenseTblPartialExpressionAssignment<Uc> {
+        /// Get the assignment equivalent to applying this assignment and then the other, for every variable this assigns
+        /// Fails if this assigns an expression containing a variable which the other does not assign
+        pub fn compose(&self, then: &Self) -> Result<Self, TblPartialAssignmentError>
+        where
+            Uc: FromIterator<UnassignedTblExpression<Uc>>,
+        {
+            self.compose_helper(then)
+        }
+        /// Get the full assignment equivalent to applying this assignment and then the provided full assignment, for every variable this assigns
+        pub fn compose_full<C: TblExpressionCompound + FromIterator<TblExpression<C>>>(
+            &self,
+            then: &DenseTblExpressionAssignment<C>,
+        ) -> Result<DenseTblExpressionAssignment<C>, TblAssignmentError> {
+            self.compose_full_helper(then)
+        }
+        /// Whether this assignment only renames variables, assigning each of its variables a distinct variable
+        pub fn is_renaming(&self) -> bool {
+            self.is_renaming_helper()
+        }
+        /// If this assignment only renames variables, get the renaming which reverses it
+        pub fn invert_renaming(&self) -> Option<Self> {
+            self.invert_renaming_helper()
+        }
+    }
+}
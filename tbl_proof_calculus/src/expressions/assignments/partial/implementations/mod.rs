@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use itertools::Itertools;
 use proof_calculus::utils::{
     collections::maps::KeyConflictError,
@@ -6,18 +8,25 @@ use proof_calculus::utils::{
 
 use crate::{
     expressions::{
-        assignments::partial::errors::{
-            assignment::TblPartialAssignmentError,
-            reverse_assignment::{
-                TblPartialReverseAssignmentError, TblPartialReverseAssignmentVariableConflictError,
+        assignments::{
+            full::errors::assignment::TblAssignmentError,
+            partial::errors::{
+                assignment::TblPartialAssignmentError,
+                reverse_assignment::{
+                    TblPartialReverseAssignmentError,
+                    TblPartialReverseAssignmentVariableConflictError,
+                },
             },
         },
         paths::{
             TblSubexpressionInExpressionPath, immediate::ImmediateTblSubexpressionInExpressionPath,
         },
-        types::unassigned::{
-            UnassignedTblExpression, compound::UnassignedTblExpressionCompound,
-            variable::TblExpressionVariable,
+        types::{
+            assigned::{TblExpression, compound::TblExpressionCompound},
+            unassigned::{
+                UnassignedTblExpression, compound::UnassignedTblExpressionCompound,
+                variable::TblExpressionVariable,
+            },
         },
     },
     proof_calculus_derived::aliases::propositions::types::unassigned::UnassignedTblProposition,
@@ -34,6 +43,11 @@ trait TblPartialAssignmentHelper<Uc: UnassignedTblExpressionCompound>: Default {
         var: TblExpressionVariable,
         expr: UnassignedTblExpression<Uc>,
     ) -> Result<(), KeyConflictError<TblExpressionVariable, UnassignedTblExpression<Uc>>>;
+    fn iter<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (&'a TblExpressionVariable, &'a UnassignedTblExpression<Uc>)>
+    where
+        Uc: 'a;
 
     fn partial_assign_helper<
         PreUc: UnassignedTblExpressionCompound,
@@ -178,6 +192,105 @@ trait TblPartialAssignmentHelper<Uc: UnassignedTblExpressionCompound>: Default {
             ),
         }
     }
+
+    /// Assign the provided expression, keeping the type of its compounds
+    fn substitute_helper(
+        &self,
+        unassigned: &UnassignedTblExpression<Uc>,
+    ) -> Result<UnassignedTblExpression<Uc>, TblPartialAssignmentError>
+    where
+        Uc: FromIterator<UnassignedTblExpression<Uc>>,
+    {
+        match unassigned {
+            UnassignedTblExpression::Atom(atom) => Ok(UnassignedTblExpression::Atom(*atom)),
+            UnassignedTblExpression::Variable(variable) => self
+                .get(variable)
+                .cloned()
+                .ok_or(TblPartialAssignmentError(*variable)),
+            UnassignedTblExpression::Compound(compound) => Ok(UnassignedTblExpression::Compound(
+                compound
+                    .as_slice()
+                    .iter()
+                    .map(|uexpr| self.substitute_helper(uexpr))
+                    .try_collect()?,
+            )),
+        }
+    }
+
+    /// Get the assignment which assigns each variable of this assignment the result of applying this assignment and then the other
+    fn compose_helper(&self, then: &Self) -> Result<Self, TblPartialAssignmentError>
+    where
+        Uc: FromIterator<UnassignedTblExpression<Uc>>,
+    {
+        let mut composed = Self::default();
+        for (variable, expr) in self.iter() {
+            composed
+                .insert(*variable, then.substitute_helper(expr)?)
+                .unwrap_or_else(|_| unreachable!("Each variable is only assigned once"));
+        }
+        Ok(composed)
+    }
+    /// As with [TblPartialAssignmentHelper::compose_helper], but applying a full assignment afterwards
+    fn compose_full_helper<
+        C: TblExpressionCompound + FromIterator<TblExpression<C>>,
+        Full: Default + Map<TblExpressionVariable, TblExpression<C>>,
+    >(
+        &self,
+        then: &Full,
+    ) -> Result<Full, TblAssignmentError> {
+        let mut composed = Full::default();
+        for (variable, expr) in self.iter() {
+            composed.insert(*variable, ground_helper(expr, then)?);
+        }
+        Ok(composed)
+    }
+
+    /// Whether this assignment assigns each of its variables a distinct variable
+    fn is_renaming_helper(&self) -> bool {
+        let mut renamed_to = HashSet::new();
+        self.iter().all(|(_, expr)| match expr {
+            UnassignedTblExpression::Variable(variable) => renamed_to.insert(*variable),
+            _ => false,
+        })
+    }
+    /// If this assignment is a renaming, get the renaming which reverses it
+    fn invert_renaming_helper(&self) -> Option<Self> {
+        let mut inverted = Self::default();
+        for (variable, expr) in self.iter() {
+            match expr {
+                UnassignedTblExpression::Variable(renamed_to) => inverted
+                    .insert(*renamed_to, UnassignedTblExpression::Variable(*variable))
+                    .ok()?,
+                _ => return None,
+            }
+        }
+        Some(inverted)
+    }
+}
+
+/// Assign the provided expression using a full assignment, building its compounds from the assigned expressions directly
+fn ground_helper<
+    Uc: UnassignedTblExpressionCompound,
+    C: TblExpressionCompound + FromIterator<TblExpression<C>>,
+    Full: Map<TblExpressionVariable, TblExpression<C>>,
+>(
+    unassigned: &UnassignedTblExpression<Uc>,
+    assignment: &Full,
+) -> Result<TblExpression<C>, TblAssignmentError> {
+    match unassigned {
+        UnassignedTblExpression::Atom(atom) => Ok(TblExpression::Atom(*atom)),
+        UnassignedTblExpression::Variable(variable) => assignment
+            .get(variable)
+            .cloned()
+            .ok_or(TblAssignmentError(*variable)),
+        UnassignedTblExpression::Compound(compound) => Ok(TblExpression::Compound(
+            compound
+                .as_slice()
+                .iter()
+                .map(|uexpr| ground_helper(uexpr, assignment))
+                .try_collect()?,
+        )),
+    }
 }
 
 impl<
@@ -196,4 +309,93 @@ impl<
     ) -> Result<(), KeyConflictError<TblExpressionVariable, UnassignedTblExpression<C>>> {
         Self::insert_conflictless(self, var, expr)
     }
+    fn iter<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (&'a TblExpressionVariable, &'a UnassignedTblExpression<C>)>
+    where
+        C: 'a,
+    {
+        Map::iter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::expressions::{
+        assignments::{
+            partial::implementations::btree::BTreeTblPartialExpressionAssignment,
+            restriction::RestrictableTblAssignment,
+        },
+        types::unassigned::{
+            BoxUnassignedTblExpression, compound::r#box::UnassignedBoxCompoundTblExpression,
+        },
+    };
+
+    type TestAssignment = BTreeTblPartialExpressionAssignment<UnassignedBoxCompoundTblExpression>;
+
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+    fn assignment<const N: usize>(
+        assigned: [(usize, BoxUnassignedTblExpression); N],
+    ) -> TestAssignment {
+        assigned
+            .into_iter()
+            .map(|(id, expr)| (TblExpressionVariable(id), expr))
+            .collect::<BTreeMap<_, _>>()
+            .into()
+    }
+
+    #[test]
+    fn test_compose_order() {
+        let first = assignment([(0, [var(1), 2.into()].into())]);
+        let then = assignment([(1, 3.into()), (2, var(0))]);
+        // Composing applies this assignment first, then substitutes the other into the result
+        assert_eq!(
+            first.compose(&then).ok().unwrap(),
+            assignment([(0, [3.into(), 2.into()].into())])
+        );
+        assert_eq!(
+            then.compose(&first).ok().unwrap(),
+            assignment([(1, 3.into()), (2, [var(1), 2.into()].into())])
+        );
+        // The first assignment introduces a variable which the other does not assign
+        assert!(assignment([(0, var(4))]).compose(&then).is_err());
+    }
+
+    #[test]
+    fn test_invert_renaming() {
+        let renaming = assignment([(0, var(1)), (1, var(2))]);
+        assert!(renaming.is_renaming());
+        let inverse = renaming.invert_renaming().unwrap();
+        assert_eq!(inverse, assignment([(1, var(0)), (2, var(1))]));
+        assert_eq!(
+            renaming.compose(&inverse).ok().unwrap(),
+            assignment([(0, var(0)), (1, var(1))])
+        );
+        // Two variables renamed to the same variable cannot be told apart again
+        let merging = assignment([(0, var(2)), (1, var(2))]);
+        assert!(!merging.is_renaming());
+        assert_eq!(merging.invert_renaming(), None);
+        // Assigning anything other than a variable is not a renaming
+        let assigning = assignment([(0, 1.into())]);
+        assert!(!assigning.is_renaming());
+        assert_eq!(assigning.invert_renaming(), None);
+    }
+
+    #[test]
+    fn test_restrict() {
+        let assigned = assignment([(0, var(1)), (1, 2.into()), (2, var(0))]);
+        assert_eq!(
+            assigned.restrict([
+                TblExpressionVariable(0),
+                TblExpressionVariable(2),
+                TblExpressionVariable(5)
+            ]),
+            assignment([(0, var(1)), (2, var(0))])
+        );
+    }
 }
@@ -1,3 +1,4 @@
 pub mod constructor_implementations;
 pub mod errors;
 pub mod implementations;
+pub mod unification;
@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+
+use proof_calculus::{
+    propositions::assignments::{PartialPropositionalAssignment, UnifiableProposition},
+    utils::traits::map::Map,
+};
+
+use crate::expressions::{
+    assignments::partial::errors::unification::TblUnificationError,
+    paths::{
+        TblSubexpressionInExpressionPath, immediate::ImmediateTblSubexpressionInExpressionPath,
+    },
+    types::unassigned::{
+        UnassignedTblExpression, compound::UnassignedTblExpressionCompound,
+        subexpressions::ParentOfUnassignedSubexpressions, variable::TblExpressionVariable,
+    },
+};
+
+/// The variables assigned so far during unification
+/// An assigned expression may contain variables which were assigned later, so expressions are only fully substituted once unification finishes
+type TblUnificationBindings<Uc> = HashMap<TblExpressionVariable, UnassignedTblExpression<Uc>>;
+
+impl<
+    Uc: UnassignedTblExpressionCompound + FromIterator<UnassignedTblExpression<Uc>>,
+    Assignment: Default
+        + Map<TblExpressionVariable, UnassignedTblExpression<Uc>>
+        + PartialPropositionalAssignment<UnassignedTblExpression<Uc>, UnassignedTblExpression<Uc>>,
+> UnifiableProposition<Assignment> for UnassignedTblExpression<Uc>
+{
+    type UnificationError = TblUnificationError<Uc>;
+    fn unify(&self, other: &Self) -> Result<Assignment, Self::UnificationError> {
+        let mut bindings = TblUnificationBindings::default();
+        let mut path = TblSubexpressionInExpressionPath::default();
+        unify_helper(self, other, &mut bindings, &mut path)?;
+        // Variables which were left free are assigned themselves, so the assignment can be applied to both expressions
+        let mut assignment = Assignment::default();
+        for subexpression in self
+            .get_subexpressions()
+            .into_iter()
+            .chain(other.get_subexpressions())
+        {
+            if let UnassignedTblExpression::Variable(variable) = subexpression {
+                assignment.insert(*variable, substitute(subexpression, &bindings));
+            }
+        }
+        Ok(assignment)
+    }
+}
+
+fn unify_helper<Uc: UnassignedTblExpressionCompound>(
+    left: &UnassignedTblExpression<Uc>,
+    right: &UnassignedTblExpression<Uc>,
+    bindings: &mut TblUnificationBindings<Uc>,
+    current_path: &mut TblSubexpressionInExpressionPath,
+) -> Result<(), TblUnificationError<Uc>> {
+    // The resolved expressions are cloned, as they may be borrowed from the bindings which are about to be extended
+    let left = resolve(left, bindings).clone();
+    let right = resolve(right, bindings).clone();
+    match (left, right) {
+        (
+            UnassignedTblExpression::Variable(left_variable),
+            UnassignedTblExpression::Variable(right_variable),
+        ) if left_variable == right_variable => Ok(()),
+        // If either is an unassigned variable, assign it the other, as long as the other does not contain it
+        (UnassignedTblExpression::Variable(variable), expr)
+        | (expr, UnassignedTblExpression::Variable(variable)) => {
+            if occurs(&variable, &expr, bindings) {
+                return Err(TblUnificationError::occurs_check(
+                    variable,
+                    expr,
+                    current_path.clone(),
+                ));
+            }
+            bindings.insert(variable, expr);
+            Ok(())
+        }
+        (UnassignedTblExpression::Atom(left_atom), UnassignedTblExpression::Atom(right_atom))
+            if left_atom == right_atom =>
+        {
+            Ok(())
+        }
+        // If both are compounds of the same length, recurse
+        (
+            UnassignedTblExpression::Compound(left_compound),
+            UnassignedTblExpression::Compound(right_compound),
+        ) if left_compound.len() == right_compound.len() => {
+            for (index, (left_subexpression, right_subexpression)) in left_compound
+                .as_slice()
+                .iter()
+                .zip(right_compound.as_slice().iter())
+                .enumerate()
+            {
+                current_path
+                    .0
+                    .push(ImmediateTblSubexpressionInExpressionPath(index));
+                unify_helper(
+                    left_subexpression,
+                    right_subexpression,
+                    bindings,
+                    current_path,
+                )?;
+                current_path.0.pop();
+            }
+            Ok(())
+        }
+        (left, right) => Err(TblUnificationError::clash(
+            left,
+            right,
+            current_path.clone(),
+        )),
+    }
+}
+
+/// Follow the bindings of the provided expression until it is not an assigned variable
+fn resolve<'a, Uc: UnassignedTblExpressionCompound>(
+    expr: &'a UnassignedTblExpression<Uc>,
+    bindings: &'a TblUnificationBindings<Uc>,
+) -> &'a UnassignedTblExpression<Uc> {
+    let mut expr = expr;
+    while let UnassignedTblExpression::Variable(variable) = expr {
+        match bindings.get(variable) {
+            Some(bound) => expr = bound,
+            None => break,
+        }
+    }
+    expr
+}
+
+/// Whether the variable appears within the provided expression once the bindings are substituted into it
+fn occurs<Uc: UnassignedTblExpressionCompound>(
+    variable: &TblExpressionVariable,
+    expr: &UnassignedTblExpression<Uc>,
+    bindings: &TblUnificationBindings<Uc>,
+) -> bool {
+    match resolve(expr, bindings) {
+        UnassignedTblExpression::Atom(_) => false,
+        UnassignedTblExpression::Variable(other) => other == variable,
+        UnassignedTblExpression::Compound(compound) => compound
+            .as_slice()
+            .iter()
+            .any(|subexpression| occurs(variable, subexpression, bindings)),
+    }
+}
+
+/// Substitute the bindings into the provided expression until no assigned variables remain
+/// This terminates because the occurs check prevents any variable from being bound to an expression containing itself
+fn substitute<Uc: UnassignedTblExpressionCompound + FromIterator<UnassignedTblExpression<Uc>>>(
+    expr: &UnassignedTblExpression<Uc>,
+    bindings: &TblUnificationBindings<Uc>,
+) -> UnassignedTblExpression<Uc> {
+    match resolve(expr, bindings) {
+        UnassignedTblExpression::Compound(compound) => UnassignedTblExpression::Compound(
+            compound
+                .as_slice()
+                .iter()
+                .map(|subexpression| substitute(subexpression, bindings))
+                .collect(),
+        ),
+        resolved => resolved.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::{
+        assignments::partial::implementations::btree::BTreeTblPartialExpressionAssignment,
+        types::unassigned::{
+            BoxUnassignedTblExpression, compound::r#box::UnassignedBoxCompoundTblExpression,
+        },
+    };
+
+    type TestAssignment = BTreeTblPartialExpressionAssignment<UnassignedBoxCompoundTblExpression>;
+
+    fn atom(id: u16) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::from(id)
+    }
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+    fn compound<const N: usize>(
+        subexpressions: [BoxUnassignedTblExpression; N],
+    ) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::from(subexpressions)
+    }
+    fn unify(
+        left: &BoxUnassignedTblExpression,
+        right: &BoxUnassignedTblExpression,
+    ) -> Result<TestAssignment, TblUnificationError<UnassignedBoxCompoundTblExpression>> {
+        left.unify(right)
+    }
+    /// Unify the expressions, and check that the assignment makes them equal
+    fn unify_and_apply(
+        left: &BoxUnassignedTblExpression,
+        right: &BoxUnassignedTblExpression,
+    ) -> BoxUnassignedTblExpression {
+        let assignment = unify(left, right)
+            .ok()
+            .expect("The expressions are unifiable");
+        let left_assigned: BoxUnassignedTblExpression = assignment.assign(left).ok().unwrap();
+        let right_assigned: BoxUnassignedTblExpression = assignment.assign(right).ok().unwrap();
+        assert_eq!(left_assigned, right_assigned);
+        left_assigned
+    }
+
+    #[test]
+    fn test_clash() {
+        let result = unify(&compound([var(0), atom(1)]), &compound([var(1), atom(2)]));
+        match result {
+            Err(TblUnificationError::Clash(err)) => {
+                assert_eq!(err.obj.left, atom(1));
+                assert_eq!(err.obj.right, atom(2));
+                assert_eq!(
+                    err.path,
+                    TblSubexpressionInExpressionPath(vec![
+                        ImmediateTblSubexpressionInExpressionPath(1)
+                    ])
+                );
+            }
+            _ => panic!("The atoms should clash"),
+        }
+        assert!(matches!(
+            unify(&compound([atom(1)]), &compound([atom(1), atom(1)])),
+            Err(TblUnificationError::Clash(_))
+        ));
+    }
+
+    #[test]
+    fn test_occurs_check() {
+        let result = unify(&var(0), &compound([atom(1), var(0)]));
+        match result {
+            Err(TblUnificationError::OccursCheck(err)) => {
+                assert_eq!(err.obj.variable, TblExpressionVariable(0));
+            }
+            _ => panic!("The variable should fail the occurs check"),
+        }
+        // The variable only occurs once the bindings made so far are substituted
+        assert!(matches!(
+            unify(
+                &compound([var(0), var(1)]),
+                &compound([var(1), compound([var(0)])])
+            ),
+            Err(TblUnificationError::OccursCheck(_))
+        ));
+    }
+
+    #[test]
+    fn test_variable_bound_to_variable() {
+        let assignment = unify(&var(0), &var(1)).ok().unwrap();
+        let unified = unify_and_apply(&var(0), &var(1));
+        assert!(unified == var(0) || unified == var(1));
+        assert_eq!(assignment.0.len(), 2);
+        // A variable bound to another is resolved through it
+        assert_eq!(
+            unify_and_apply(&compound([var(0), var(1)]), &compound([var(1), atom(3)])),
+            compound([atom(3), atom(3)])
+        );
+    }
+
+    #[test]
+    fn test_shared_variable() {
+        // A variable shared by both expressions must take the same value on each side
+        assert_eq!(
+            unify_and_apply(
+                &compound([var(0), var(1)]),
+                &compound([atom(2), compound([var(0)])])
+            ),
+            compound([atom(2), compound([atom(2)])])
+        );
+        assert!(matches!(
+            unify(&compound([var(0), atom(1)]), &compound([atom(2), var(0)])),
+            Err(TblUnificationError::Clash(_))
+        ));
+        assert!(unify(&compound([var(0), atom(1)]), &compound([atom(1), var(0)])).is_ok());
+    }
+
+    #[test]
+    fn test_most_general() {
+        let left = compound([var(0), compound([var(1)])]);
+        let right = compound([compound([var(2)]), var(0)]);
+        let unified = unify_and_apply(&left, &right);
+        // No variable is fixed that did not need to be, so the result only identifies the variables which must be equal
+        assert!(
+            unified == compound([compound([var(1)]), compound([var(1)])])
+                || unified == compound([compound([var(2)]), compound([var(2)])])
+        );
+        // Any other assignment making the expressions equal is an instance of the most general one
+        let ground = compound([compound([atom(5)]), compound([atom(5)])]);
+        assert_eq!(unify_and_apply(&unified, &ground), ground);
+    }
+}
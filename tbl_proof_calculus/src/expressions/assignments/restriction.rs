@@ -0,0 +1,23 @@
+use proof_calculus::utils::traits::map::Map;
+
+use crate::expressions::types::unassigned::variable::TblExpressionVariable;
+
+/// Assignments of TBL variables, whether full or partial, which can be restricted to some of their variables
+pub trait RestrictableTblAssignment<Value: Clone>:
+    Default + Map<TblExpressionVariable, Value>
+{
+    /// Get the assignment of only the provided variables, ignoring any which this does not assign
+    fn restrict<I: IntoIterator<Item = TblExpressionVariable>>(&self, variables: I) -> Self {
+        let mut restricted = Self::default();
+        for variable in variables {
+            if let Some(value) = self.get(&variable) {
+                restricted.insert(variable, value.clone());
+            }
+        }
+        restricted
+    }
+}
+impl<Value: Clone, Assignment: Default + Map<TblExpressionVariable, Value>>
+    RestrictableTblAssignment<Value> for Assignment
+{
+}
@@ -1,6 +1,7 @@
 use std::num::TryFromIntError;
 
 use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+use proof_calculus::utils::traits::content_hash::{ContentHash, ContentHasher};
 
 /// An [Identifier] used for Atom objects, which are used for building tuple objects in Tuple-based logic
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
@@ -13,6 +14,11 @@ impl TblExpressionAtom {
         TblExpressionAtom(self.0 + 1)
     }
 }
+impl ContentHash for TblExpressionAtom {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        hasher.write_u16(self.0);
+    }
+}
 impl TryFrom<usize> for TblExpressionAtom {
     type Error = TryFromIntError;
     fn try_from(value: usize) -> Result<Self, Self::Error> {
@@ -1,11 +1,7 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
+use std::{collections::HashSet, hash::Hash};
 
 use proof_calculus::utils::collections::{
-    binding::binders::{Binder, GetBinder},
-    maps::multimap::MultiMap,
+    binding::binders::{Binder, GetBinder, UpdateBinder},
     sets::hashset::transform_hashset,
 };
 
@@ -14,28 +10,24 @@ use crate::expressions::{
     types::assigned::{
         atom::TblExpressionAtom,
         binding::{
-            binders::{get_helper, insert_helper},
+            binders::CountedBindings,
             bounds::{TblExpressionBoundAtomExactValue, TblExpressionBoundAtomExistsAtLocation},
         },
     },
 };
 
 pub struct TblExpressionBinderAtomExactValue<T: Hash + Eq>(
-    HashMap<TblSubexpressionInExpressionPath, MultiMap<TblExpressionAtom, T>>,
+    CountedBindings<TblSubexpressionInExpressionPath, TblExpressionAtom, T>,
 );
 
-impl<T: Eq + Hash> Binder for TblExpressionBinderAtomExactValue<T> {
+impl<T: Eq + Hash + Clone> Binder for TblExpressionBinderAtomExactValue<T> {
     type Value = T;
 
     fn get_all<'binder>(&'binder self) -> HashSet<&'binder Self::Value> {
-        self.0
-            .iter()
-            .map(|(_, inner)| inner.flat_values())
-            .flatten()
-            .collect()
+        self.0.values().collect()
     }
 }
-impl<T: Eq + Hash> GetBinder<TblExpressionBoundAtomExactValue>
+impl<T: Eq + Hash + Clone> GetBinder<TblExpressionBoundAtomExactValue>
     for TblExpressionBinderAtomExactValue<T>
 {
     fn get<'binder>(
@@ -51,14 +43,14 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundAtomExactValue>
         transform_hashset(self.get_inner(&bound.path, &bound.value), |v| (v, ()))
     }
 }
-impl<T: Eq + Hash> GetBinder<TblExpressionBoundAtomExistsAtLocation>
+impl<T: Eq + Hash + Clone> GetBinder<TblExpressionBoundAtomExistsAtLocation>
     for TblExpressionBinderAtomExactValue<T>
 {
     fn get<'binder>(
         &'binder self,
         bound: &TblExpressionBoundAtomExistsAtLocation,
     ) -> HashSet<&'binder Self::Value> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner.flat_values().into_iter().collect(),
             None => HashSet::new(),
         }
@@ -67,7 +59,7 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundAtomExistsAtLocation>
         &'binder self,
         bound: &TblExpressionBoundAtomExistsAtLocation,
     ) -> HashSet<(&'binder Self::Value, TblExpressionAtom)> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner
                 .pairs()
                 .into_iter()
@@ -78,13 +70,17 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundAtomExistsAtLocation>
     }
 }
 
-impl<T: Hash + Eq> TblExpressionBinderAtomExactValue<T> {
+impl<T: Hash + Eq + Clone> TblExpressionBinderAtomExactValue<T> {
+    pub fn new() -> Self {
+        Self(CountedBindings::new())
+    }
+
     fn get_inner(
         &self,
         path: &TblSubexpressionInExpressionPath,
         value: &TblExpressionAtom,
     ) -> HashSet<&T> {
-        get_helper(&self.0, path, value)
+        self.0.get(path, value)
     }
 
     pub fn insert(
@@ -93,9 +89,34 @@ impl<T: Hash + Eq> TblExpressionBinderAtomExactValue<T> {
         atom: TblExpressionAtom,
         value: T,
     ) -> bool {
-        insert_helper(&mut self.0, path, atom, value)
+        self.0.insert(path, atom, value)
     }
     pub fn insert2(&mut self, bound: TblExpressionBoundAtomExactValue, value: T) -> bool {
         self.insert(&bound.path, bound.value, value)
     }
+
+    pub fn contains(
+        &self,
+        path: &TblSubexpressionInExpressionPath,
+        atom: &TblExpressionAtom,
+        value: &T,
+    ) -> bool {
+        self.0.contains(path, atom, value)
+    }
+    pub fn remove(
+        &mut self,
+        path: &TblSubexpressionInExpressionPath,
+        atom: &TblExpressionAtom,
+        value: &T,
+    ) -> bool {
+        self.0.remove(path, atom, value)
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for TblExpressionBinderAtomExactValue<T> {
+    fn remove_value(&mut self, value: &T) -> bool {
+        self.0.remove_value(value)
+    }
+    fn replace_value(&mut self, old: &T, new: T) -> bool {
+        self.0.replace_value(old, new)
+    }
 }
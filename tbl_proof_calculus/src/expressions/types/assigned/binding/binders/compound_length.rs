@@ -1,18 +1,14 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
+use std::{collections::HashSet, hash::Hash};
 
 use proof_calculus::utils::collections::{
-    binding::binders::{Binder, GetBinder},
-    maps::multimap::MultiMap,
+    binding::binders::{Binder, GetBinder, UpdateBinder},
     sets::hashset::transform_hashset,
 };
 
 use crate::expressions::{
     paths::TblSubexpressionInExpressionPath,
     types::assigned::binding::{
-        binders::{get_helper, insert_helper},
+        binders::CountedBindings,
         bounds::{
             TblExpressionBoundCompoundExactLength, TblExpressionBoundCompoundExistsAtLocation,
         },
@@ -20,21 +16,17 @@ use crate::expressions::{
 };
 
 pub struct TblExpressionBinderCompoundExactLength<T: Hash + Eq>(
-    HashMap<TblSubexpressionInExpressionPath, MultiMap<usize, T>>,
+    CountedBindings<TblSubexpressionInExpressionPath, usize, T>,
 );
 
-impl<T: Eq + Hash> Binder for TblExpressionBinderCompoundExactLength<T> {
+impl<T: Eq + Hash + Clone> Binder for TblExpressionBinderCompoundExactLength<T> {
     type Value = T;
 
     fn get_all<'binder>(&'binder self) -> HashSet<&'binder Self::Value> {
-        self.0
-            .iter()
-            .map(|(_, inner)| inner.flat_values())
-            .flatten()
-            .collect()
+        self.0.values().collect()
     }
 }
-impl<T: Eq + Hash> GetBinder<TblExpressionBoundCompoundExactLength>
+impl<T: Eq + Hash + Clone> GetBinder<TblExpressionBoundCompoundExactLength>
     for TblExpressionBinderCompoundExactLength<T>
 {
     fn get<'binder>(
@@ -50,14 +42,14 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundCompoundExactLength>
         transform_hashset(self.get(bound), |v| (v, ()))
     }
 }
-impl<T: Eq + Hash> GetBinder<TblExpressionBoundCompoundExistsAtLocation>
+impl<T: Eq + Hash + Clone> GetBinder<TblExpressionBoundCompoundExistsAtLocation>
     for TblExpressionBinderCompoundExactLength<T>
 {
     fn get<'binder>(
         &'binder self,
         bound: &TblExpressionBoundCompoundExistsAtLocation,
     ) -> HashSet<&'binder Self::Value> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner.flat_values().into_iter().collect(),
             None => HashSet::new(),
         }
@@ -66,7 +58,7 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundCompoundExistsAtLocation>
         &'binder self,
         bound: &TblExpressionBoundCompoundExistsAtLocation,
     ) -> HashSet<(&'binder Self::Value, usize)> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner
                 .pairs()
                 .into_iter()
@@ -77,9 +69,13 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundCompoundExistsAtLocation>
     }
 }
 
-impl<T: Hash + Eq> TblExpressionBinderCompoundExactLength<T> {
+impl<T: Hash + Eq + Clone> TblExpressionBinderCompoundExactLength<T> {
+    pub fn new() -> Self {
+        Self(CountedBindings::new())
+    }
+
     fn get_inner(&self, path: &TblSubexpressionInExpressionPath, length: usize) -> HashSet<&T> {
-        get_helper(&self.0, path, &length)
+        self.0.get(path, &length)
     }
 
     pub fn insert(
@@ -88,9 +84,34 @@ impl<T: Hash + Eq> TblExpressionBinderCompoundExactLength<T> {
         length: usize,
         value: T,
     ) -> bool {
-        insert_helper(&mut self.0, path, length, value)
+        self.0.insert(path, length, value)
     }
     pub fn insert2(&mut self, bound: TblExpressionBoundCompoundExactLength, value: T) -> bool {
         self.insert(&bound.path, bound.length, value)
     }
+
+    pub fn contains(
+        &self,
+        path: &TblSubexpressionInExpressionPath,
+        length: usize,
+        value: &T,
+    ) -> bool {
+        self.0.contains(path, &length, value)
+    }
+    pub fn remove(
+        &mut self,
+        path: &TblSubexpressionInExpressionPath,
+        length: usize,
+        value: &T,
+    ) -> bool {
+        self.0.remove(path, &length, value)
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for TblExpressionBinderCompoundExactLength<T> {
+    fn remove_value(&mut self, value: &T) -> bool {
+        self.0.remove_value(value)
+    }
+    fn replace_value(&mut self, old: &T, new: T) -> bool {
+        self.0.replace_value(old, new)
+    }
 }
@@ -5,10 +5,11 @@ use std::{
 
 use proof_calculus::{
     propositions::types::assigned::binding::binders::{
-        GetBinderForPropIdenticalToProp, InsertBinderForProp,
+        GetBinderForPropIdenticalToProp, GetBinderForPropsSubsumedByUprop, InsertBinderForProp,
+        RemoveBinderForProp,
     },
     utils::collections::{
-        binding::binders::{Binder, GetBinder, InsertBinder},
+        binding::binders::{Binder, GetBinder, InsertBinder, RemoveBinder, UpdateBinder},
         maps::multimap::MultiMap,
         sets::hashset::transform_hashset,
     },
@@ -32,16 +33,20 @@ use crate::{
                 TblPropositionBoundAtomExistsAtLocation, TblPropositionBoundCompoundExactLength,
                 TblPropositionBoundCompoundExistsAtLocation,
                 TblPropositionBoundExpressionExistsAtLocation, TblPropositionBoundValueDuplicated,
-                TblPropositionIdentityBound,
+                TblPropositionIdentityBound, TblPropositionInsertionBound,
             },
             operation_bounds::{
                 get_identical_to_prop::fast_construct::TblFastConstructGetBoundsForPropIdenticalToProp,
+                get_subsumed_by_uprop::fast_construct::TblFastConstructGetBoundsForPropsSubsumedByUprop,
                 insert::TblFastConstructInsertionBoundsForProp,
             },
         },
         compound::TblExpressionCompound,
     },
-    proof_calculus_derived::aliases::propositions::types::assigned::TblProposition,
+    expressions::types::unassigned::compound::UnassignedTblExpressionCompound,
+    proof_calculus_derived::aliases::propositions::types::{
+        assigned::TblProposition, unassigned::UnassignedTblProposition,
+    },
 };
 
 pub mod atom_value;
@@ -52,6 +57,18 @@ pub struct TblExpressionBinder<T: Hash + Eq + Clone> {
     atom_value_bounds: TblExpressionBinderAtomExactValue<T>,
     compound_length_bounds: TblExpressionBinderCompoundExactLength<T>,
     duplicate_value_bounds: TblExpressionBinderValueDuplication<T>,
+    insertions: InsertionRecords<TblExpressionInsertionBound, T>,
+}
+
+impl<T: Hash + Eq + Clone> TblExpressionBinder<T> {
+    pub fn new() -> Self {
+        Self {
+            atom_value_bounds: TblExpressionBinderAtomExactValue::new(),
+            compound_length_bounds: TblExpressionBinderCompoundExactLength::new(),
+            duplicate_value_bounds: TblExpressionBinderValueDuplication::new(),
+            insertions: InsertionRecords::new(),
+        }
+    }
 }
 
 pub type TblPropositionBinder<T> = TblExpressionBinder<T>;
@@ -198,6 +215,25 @@ impl<T: Hash + Eq + Clone> GetBinder<TblPropositionIdentityBound> for TblProposi
     }
 }
 
+impl<T: Hash + Eq + Clone> GetBinder<TblPropositionInsertionBound> for TblPropositionBinder<T> {
+    fn get<'binder>(
+        &'binder self,
+        key: &TblPropositionInsertionBound,
+    ) -> HashSet<&'binder Self::Value> {
+        match key {
+            TblExpressionInsertionBound::AtomValue(atom_bound) => self.get(atom_bound),
+            TblExpressionInsertionBound::CompoundLength(compound_bound) => self.get(compound_bound),
+            TblExpressionInsertionBound::ValueDuplicated(dups_bound) => self.get(dups_bound),
+        }
+    }
+    fn get_with_extra_data<'binder>(
+        &'binder self,
+        bound: &TblPropositionInsertionBound,
+    ) -> HashSet<(&'binder Self::Value, ())> {
+        transform_hashset(self.get(bound), |v| (v, ()))
+    }
+}
+
 impl<T: Hash + Eq + Clone> InsertBinder<TblFastConstructInsertionBoundsForProp>
     for TblPropositionBinder<T>
 {
@@ -206,6 +242,7 @@ impl<T: Hash + Eq + Clone> InsertBinder<TblFastConstructInsertionBoundsForProp>
         bounds: &TblFastConstructInsertionBoundsForProp,
         value: Self::Value,
     ) {
+        self.insertions.record(bounds.bounds(), value.clone());
         for bound in bounds.bounds() {
             match bound {
                 TblExpressionInsertionBound::AtomValue(atom_bound) => self
@@ -226,6 +263,52 @@ impl<T: Hash + Eq + Clone> InsertBinder<TblFastConstructInsertionBoundsForProp>
     }
 }
 
+impl<T: Hash + Eq + Clone> RemoveBinder<TblFastConstructInsertionBoundsForProp>
+    for TblPropositionBinder<T>
+{
+    fn remove_by_bounds(
+        &mut self,
+        bounds: &TblFastConstructInsertionBoundsForProp,
+        value: &Self::Value,
+    ) -> bool {
+        // Each bound may be shared with other propositions, so only a value inserted under exactly these bounds is removed
+        if !self.insertions.forget(bounds.bounds(), value) {
+            return false;
+        }
+        for bound in bounds.bounds() {
+            match bound {
+                TblExpressionInsertionBound::AtomValue(atom_bound) => self
+                    .atom_value_bounds
+                    .remove(&atom_bound.path, &atom_bound.value, value),
+                TblExpressionInsertionBound::CompoundLength(compound_bound) => self
+                    .compound_length_bounds
+                    .remove(&compound_bound.path, compound_bound.length, value),
+                TblExpressionInsertionBound::ValueDuplicated(dups_bound) => self
+                    .duplicate_value_bounds
+                    .remove(dups_bound.path1(), dups_bound.path2(), value),
+            };
+        }
+        true
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for TblPropositionBinder<T> {
+    fn remove_value(&mut self, value: &Self::Value) -> bool {
+        // Each component binder is updated even once the value has been found in another
+        let atom_removed = self.atom_value_bounds.remove_value(value);
+        let compound_removed = self.compound_length_bounds.remove_value(value);
+        let duplicate_removed = self.duplicate_value_bounds.remove_value(value);
+        self.insertions.forget_value(value);
+        atom_removed || compound_removed || duplicate_removed
+    }
+    fn replace_value(&mut self, old: &Self::Value, new: Self::Value) -> bool {
+        self.insertions.replace_value(old, new.clone());
+        let atom_replaced = self.atom_value_bounds.replace_value(old, new.clone());
+        let compound_replaced = self.compound_length_bounds.replace_value(old, new.clone());
+        let duplicate_replaced = self.duplicate_value_bounds.replace_value(old, new);
+        atom_replaced || compound_replaced || duplicate_replaced
+    }
+}
+
 impl<C: TblExpressionCompound, T: Hash + Eq + Clone>
     GetBinderForPropIdenticalToProp<TblProposition<C>> for TblPropositionBinder<T>
 {
@@ -234,38 +317,234 @@ impl<C: TblExpressionCompound, T: Hash + Eq + Clone>
     where
         C: 'prop;
 }
+impl<C: UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    GetBinderForPropsSubsumedByUprop<UnassignedTblProposition<C>> for TblPropositionBinder<T>
+{
+    type DefaultGetBoundsForPropsSubsumedByUprop<'elem>
+        = TblFastConstructGetBoundsForPropsSubsumedByUprop
+    where
+        C: 'elem;
+}
 impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
     InsertBinderForProp<'prop, TblProposition<C>> for TblPropositionBinder<T>
 {
     type DefaultInsertionBounds = TblFastConstructInsertionBoundsForProp;
 }
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBinderForProp<'prop, TblProposition<C>> for TblPropositionBinder<T>
+{
+    type DefaultRemovalBounds = TblFastConstructInsertionBoundsForProp;
+}
+
+/// Values bound under pairs of keys, such as a path and the atom at that path
+/// A value may be inserted for several propositions which share a bound, so the number of times it was inserted under each pair of keys is counted, and it is only unbound once it has been removed as many times
+/// The pairs of keys each value is bound under are also stored, so that a value can be removed or replaced without scanning the whole binder
+pub(crate) struct CountedBindings<K1: Hash + Eq, K2: Hash + Eq, V: Hash + Eq> {
+    bindings: HashMap<K1, MultiMap<K2, V>>,
+    counts: HashMap<V, HashMap<(K1, K2), usize>>,
+}
+impl<K1: Hash + Eq, K2: Hash + Eq, V: Hash + Eq> CountedBindings<K1, K2, V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+            counts: HashMap::new(),
+        }
+    }
 
-pub(crate) fn get_helper<'a, K1: Hash + Eq + Clone, K2: Hash + Eq, V: Hash + Eq>(
-    map: &'a HashMap<K1, MultiMap<K2, V>>,
-    key1: &K1,
-    key2: &K2,
-) -> HashSet<&'a V> {
-    let optional_found = map.get(key1).map(|inner| inner.get_refs(&key2));
-    if let Some(Some(found)) = optional_found {
-        found
-    } else {
-        HashSet::new()
+    pub(crate) fn get(&self, key1: &K1, key2: &K2) -> HashSet<&V> {
+        match self.bindings.get(key1).map(|inner| inner.get_refs(key2)) {
+            Some(Some(found)) => found,
+            _ => HashSet::new(),
+        }
+    }
+    pub(crate) fn get_by_outer_key(&self, key1: &K1) -> Option<&MultiMap<K2, V>> {
+        self.bindings.get(key1)
+    }
+    pub(crate) fn values(&self) -> impl Iterator<Item = &V> {
+        self.counts.keys()
+    }
+    pub(crate) fn contains(&self, key1: &K1, key2: &K2, value: &V) -> bool {
+        self.bindings
+            .get(key1)
+            .is_some_and(|inner| inner.contains(key2, value))
     }
 }
+impl<K1: Hash + Eq + Clone, K2: Hash + Eq + Clone, V: Hash + Eq + Clone>
+    CountedBindings<K1, K2, V>
+{
+    /// Bind the value under the keys, returning whether it was not already bound under them
+    pub(crate) fn insert(&mut self, key1: &K1, key2: K2, value: V) -> bool {
+        let count = self
+            .counts
+            .entry(value.clone())
+            .or_default()
+            .entry((key1.clone(), key2.clone()))
+            .or_insert(0);
+        *count += 1;
+        if *count > 1 {
+            return false;
+        }
+        self.bind(key1.clone(), key2, value);
+        true
+    }
+    /// Remove one insertion of the value under the keys, returning whether it had been inserted under them
+    /// The value stays bound under the keys until every insertion has been removed
+    pub(crate) fn remove(&mut self, key1: &K1, key2: &K2, value: &V) -> bool {
+        let Some(value_counts) = self.counts.get_mut(value) else {
+            return false;
+        };
+        let keys = (key1.clone(), key2.clone());
+        let Some(count) = value_counts.get_mut(&keys) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            value_counts.remove(&keys);
+            if value_counts.is_empty() {
+                self.counts.remove(value);
+            }
+            self.unbind(key1, key2, value);
+        }
+        true
+    }
+    /// Remove every insertion of the value, returning whether it was bound under any keys
+    pub(crate) fn remove_value(&mut self, value: &V) -> bool {
+        let Some(value_counts) = self.counts.remove(value) else {
+            return false;
+        };
+        for (key1, key2) in value_counts.keys() {
+            self.unbind(key1, key2, value);
+        }
+        true
+    }
+    /// Replace the value with another wherever it is bound, returning whether it was bound under any keys
+    pub(crate) fn replace_value(&mut self, old: &V, new: V) -> bool {
+        let Some(old_counts) = self.counts.remove(old) else {
+            return false;
+        };
+        for ((key1, key2), count) in old_counts {
+            self.unbind(&key1, &key2, old);
+            let new_count = self
+                .counts
+                .entry(new.clone())
+                .or_default()
+                .entry((key1.clone(), key2.clone()))
+                .or_insert(0);
+            *new_count += count;
+            self.bind(key1, key2, new.clone());
+        }
+        true
+    }
 
-pub(crate) fn insert_helper<K1: Hash + Eq + Clone, K2: Hash + Eq, V: Hash + Eq>(
-    map: &mut HashMap<K1, MultiMap<K2, V>>,
-    key1: &K1,
-    key2: K2,
-    value: V,
-) -> bool {
-    match map.get_mut(&key1) {
-        Some(inner) => inner.insert(key2, value),
-        None => {
-            let mut inner = MultiMap::new();
-            inner.insert(key2, value);
-            map.insert(key1.clone(), inner);
-            true
+    fn bind(&mut self, key1: K1, key2: K2, value: V) {
+        self.bindings
+            .entry(key1)
+            .or_insert_with(MultiMap::new)
+            .insert(key2, value);
+    }
+    fn unbind(&mut self, key1: &K1, key2: &K2, value: &V) {
+        if let Some(inner) = self.bindings.get_mut(key1) {
+            inner.remove(key2, value);
+            if inner.is_empty() {
+                self.bindings.remove(key1);
+            }
+        }
+    }
+}
+
+/// The bounds each value was inserted under, recorded once per insertion
+/// Bounds are shared between propositions, so a value bound under every bound of a proposition may still have been inserted for others; these records let removal match one insertion exactly
+pub(crate) struct InsertionRecords<B: Hash + Eq, V: Hash + Eq>(HashMap<V, Vec<HashSet<B>>>);
+impl<B: Hash + Eq + Clone, V: Hash + Eq> InsertionRecords<B, V> {
+    pub(crate) fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub(crate) fn record(&mut self, bounds: &[B], value: V) {
+        self.0
+            .entry(value)
+            .or_default()
+            .push(bounds.iter().cloned().collect());
+    }
+    /// Forget one insertion of the value under exactly the provided bounds, returning whether there was one
+    pub(crate) fn forget(&mut self, bounds: &[B], value: &V) -> bool {
+        let Some(insertions) = self.0.get_mut(value) else {
+            return false;
+        };
+        let bounds: HashSet<B> = bounds.iter().cloned().collect();
+        let Some(index) = insertions.iter().position(|inserted| *inserted == bounds) else {
+            return false;
+        };
+        insertions.swap_remove(index);
+        if insertions.is_empty() {
+            self.0.remove(value);
         }
+        true
+    }
+    pub(crate) fn forget_value(&mut self, value: &V) {
+        self.0.remove(value);
+    }
+    pub(crate) fn replace_value(&mut self, old: &V, new: V) {
+        if let Some(insertions) = self.0.remove(old) {
+            self.0.entry(new).or_default().extend(insertions);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::assigned::BoxTblExpression;
+
+    #[test]
+    fn test_remove_value_shared_by_bound() {
+        let mut binder = TblPropositionBinder::new();
+        let first: BoxTblExpression = [1.into(), 2.into()].into();
+        let second: BoxTblExpression = [1.into(), 3.into()].into();
+        // Both propositions bind the value to the atom 1 at the same path
+        binder.insert_prop(&first, 0);
+        binder.insert_prop(&second, 0);
+        assert!(binder.remove_prop(&first, &0));
+        assert_eq!(binder.get_identical_to(&first), None);
+        assert_eq!(binder.get_identical_to(&second), Some(&0));
+        assert!(!binder.remove_prop(&first, &0));
+        assert!(binder.remove_prop(&second, &0));
+        assert!(binder.get_all().is_empty());
+    }
+
+    #[test]
+    fn test_update_value() {
+        let mut binder = TblPropositionBinder::new();
+        let first: BoxTblExpression = [1.into(), 2.into()].into();
+        let second: BoxTblExpression = [1.into(), 3.into()].into();
+        binder.insert_prop(&first, 0);
+        binder.insert_prop(&second, 0);
+        binder.insert_prop(&second, 1);
+        assert!(binder.replace_value(&0, 1));
+        assert_eq!(binder.get_identical_to(&first), Some(&1));
+        assert_eq!(binder.get_all(), HashSet::from([&1]));
+        // The replaced value was counted under the bounds it took over, so removing one proposition keeps the other
+        assert!(binder.remove_prop(&second, &1));
+        assert_eq!(binder.get_identical_to(&second), Some(&1));
+        assert!(binder.remove_value(&1));
+        assert!(binder.get_all().is_empty());
+        assert!(!binder.remove_value(&1));
+    }
+
+    #[test]
+    fn test_remove_requires_exact_bounds() {
+        let mut binder = TblPropositionBinder::new();
+        let first: BoxTblExpression = [1.into(), 2.into(), 5.into()].into();
+        let second: BoxTblExpression = [4.into(), 3.into(), 6.into()].into();
+        // Every bound of this proposition is shared with one of the others, but it was never inserted
+        let mixed: BoxTblExpression = [1.into(), 3.into(), 6.into()].into();
+        binder.insert_prop(&first, 0);
+        binder.insert_prop(&second, 0);
+        assert!(!binder.remove_prop(&mixed, &0));
+        assert_eq!(binder.get_identical_to(&first), Some(&0));
+        assert_eq!(binder.get_identical_to(&second), Some(&0));
+        assert!(binder.remove_prop(&first, &0));
+        assert!(binder.remove_prop(&second, &0));
+        assert!(binder.get_all().is_empty());
     }
 }
@@ -1,12 +1,8 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
+use std::{collections::HashSet, hash::Hash};
 
 use proof_calculus::utils::{
     collections::{
-        binding::binders::{Binder, GetBinder},
-        maps::multimap::MultiMap,
+        binding::binders::{Binder, GetBinder, UpdateBinder},
         sets::hashset::transform_hashset,
     },
     traits::fast_ord::FastOrd,
@@ -15,34 +11,29 @@ use proof_calculus::utils::{
 use crate::expressions::{
     paths::TblSubexpressionInExpressionPath,
     types::assigned::binding::{
-        binders::{get_helper, insert_helper},
-        bounds::TblExpressionBoundValueDuplicated,
+        binders::CountedBindings, bounds::TblExpressionBoundValueDuplicated,
     },
 };
 
 pub struct TblExpressionBinderValueDuplication<T: Hash + Eq>(
-    HashMap<TblSubexpressionInExpressionPath, MultiMap<TblSubexpressionInExpressionPath, T>>,
+    CountedBindings<TblSubexpressionInExpressionPath, TblSubexpressionInExpressionPath, T>,
 );
 
-impl<T: Eq + Hash> Binder for TblExpressionBinderValueDuplication<T> {
+impl<T: Eq + Hash + Clone> Binder for TblExpressionBinderValueDuplication<T> {
     type Value = T;
 
     fn get_all<'binder>(&'binder self) -> HashSet<&'binder Self::Value> {
-        self.0
-            .iter()
-            .map(|(_, inner)| inner.flat_values())
-            .flatten()
-            .collect()
+        self.0.values().collect()
     }
 }
-impl<T: Eq + Hash> GetBinder<TblExpressionBoundValueDuplicated>
+impl<T: Eq + Hash + Clone> GetBinder<TblExpressionBoundValueDuplicated>
     for TblExpressionBinderValueDuplication<T>
 {
     fn get<'binder>(
         &'binder self,
         bound: &TblExpressionBoundValueDuplicated,
     ) -> HashSet<&'binder Self::Value> {
-        get_helper(&self.0, bound.path1(), bound.path2())
+        self.0.get(bound.path1(), bound.path2())
     }
     fn get_with_extra_data<'binder>(
         &'binder self,
@@ -52,7 +43,11 @@ impl<T: Eq + Hash> GetBinder<TblExpressionBoundValueDuplicated>
     }
 }
 
-impl<T: Hash + Eq> TblExpressionBinderValueDuplication<T> {
+impl<T: Hash + Eq + Clone> TblExpressionBinderValueDuplication<T> {
+    pub fn new() -> Self {
+        Self(CountedBindings::new())
+    }
+
     fn get_inner(
         &self,
         path1: &TblSubexpressionInExpressionPath,
@@ -63,7 +58,7 @@ impl<T: Hash + Eq> TblExpressionBinderValueDuplication<T> {
         } else {
             (path2, path1)
         };
-        get_helper(&self.0, key.0, key.1)
+        self.0.get(key.0, key.1)
     }
 
     pub fn insert(
@@ -77,10 +72,45 @@ impl<T: Hash + Eq> TblExpressionBinderValueDuplication<T> {
         } else {
             (path2, path1)
         };
-        insert_helper(&mut self.0, &key.0, key.1, value)
+        self.0.insert(&key.0, key.1, value)
     }
     pub fn insert2(&mut self, bound: TblExpressionBoundValueDuplicated, value: T) -> bool {
         let key = bound.into_paths();
-        insert_helper(&mut self.0, &key.0, key.1, value)
+        self.0.insert(&key.0, key.1, value)
+    }
+
+    pub fn contains(
+        &self,
+        path1: &TblSubexpressionInExpressionPath,
+        path2: &TblSubexpressionInExpressionPath,
+        value: &T,
+    ) -> bool {
+        let key = if path1.fast_cmp(&path2).is_lt() {
+            (path1, path2)
+        } else {
+            (path2, path1)
+        };
+        self.0.contains(key.0, key.1, value)
+    }
+    pub fn remove(
+        &mut self,
+        path1: &TblSubexpressionInExpressionPath,
+        path2: &TblSubexpressionInExpressionPath,
+        value: &T,
+    ) -> bool {
+        let key = if path1.fast_cmp(&path2).is_lt() {
+            (path1, path2)
+        } else {
+            (path2, path1)
+        };
+        self.0.remove(key.0, key.1, value)
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for TblExpressionBinderValueDuplication<T> {
+    fn remove_value(&mut self, value: &T) -> bool {
+        self.0.remove_value(value)
+    }
+    fn replace_value(&mut self, old: &T, new: T) -> bool {
+        self.0.replace_value(old, new)
     }
 }
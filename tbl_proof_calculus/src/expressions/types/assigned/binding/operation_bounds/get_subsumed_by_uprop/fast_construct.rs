@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use crate::expressions::{
-    assignments::implementations::btree::constructors::BTreeTblExpressionAssignmentConstructor,
+    assignments::full::constructor_implementations::btree::BTreeTblExpressionAssignmentConstructor,
     types::assigned::binding::bounds::TblExpressionInsertionBound,
 };
 
@@ -118,7 +118,7 @@ mod operational_retrieval {
 
     use crate::{
         expressions::{
-            assignments::implementations::btree::constructors::BTreeTblExpressionAssignmentConstructor,
+            assignments::full::constructor_implementations::btree::BTreeTblExpressionAssignmentConstructor,
             types::{
                 assigned::{
                     TblExpression,
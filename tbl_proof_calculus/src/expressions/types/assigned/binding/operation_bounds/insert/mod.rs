@@ -1,6 +1,9 @@
 use proof_calculus::{
-    propositions::types::assigned::binding::bounds::InsertBoundsForProp,
-    utils::collections::{binding::binders::InsertBinder, maps::multimap::MultiMap},
+    propositions::types::assigned::binding::bounds::{InsertBoundsForProp, RemoveBoundsForProp},
+    utils::collections::{
+        binding::binders::{InsertBinder, RemoveBinder},
+        maps::multimap::MultiMap,
+    },
 };
 
 use crate::expressions::types::assigned::{
@@ -21,6 +24,11 @@ impl<'prop, C: 'prop + TblExpressionCompound, B: InsertBinder<Self>>
     InsertBoundsForProp<'prop, TblExpression<C>, B> for TblFastConstructInsertionBoundsForProp
 {
 }
+// Values are removed using the same bounds they were inserted with
+impl<'prop, C: 'prop + TblExpressionCompound, B: RemoveBinder<Self>>
+    RemoveBoundsForProp<'prop, TblExpression<C>, B> for TblFastConstructInsertionBoundsForProp
+{
+}
 
 impl<'a, C: TblExpressionCompound> From<&'a TblExpression<C>>
     for TblFastConstructInsertionBoundsForExpr
@@ -1,4 +1,5 @@
 use path_lib::obj_at_path::{ObjAtPath, OwnedObjAtPath};
+use proof_calculus::utils::traits::content_hash::{ContentHash, ContentHasher};
 
 use crate::expressions::{
     TblExpressionLength,
@@ -139,6 +140,22 @@ impl<C: TblExpressionCompound> TryInto<TblExpressionAtom> for TblExpression<C> {
 //     }}
 // }
 
+/// Expressions hash the same regardless of which [TblExpressionCompound] holds their subexpressions
+impl<C: TblExpressionCompound> ContentHash for TblExpression<C> {
+    fn content_hash(&self, hasher: &mut ContentHasher) {
+        match self {
+            TblExpression::Atom(atom) => {
+                hasher.write_u8(0);
+                atom.content_hash(hasher);
+            }
+            TblExpression::Compound(compound) => {
+                hasher.write_u8(1);
+                compound.as_slice().content_hash(hasher);
+            }
+        }
+    }
+}
+
 impl<C: TblExpressionCompound> ParentOfImmediateSubexpressions<C> for TblExpression<C> {
     fn get_immediate_subexpression_paths(
         &self,
@@ -0,0 +1,108 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use crate::expressions::types::unassigned::{
+    UnassignedTblExpression, alpha_equivalence::UnassignedTblExpressionAlphaKey,
+    compound::UnassignedTblExpressionCompound,
+};
+
+/// A map from unassigned expressions, in which alpha-equivalent expressions are the same key
+/// Each entry keeps the first expression it was inserted with, as a representative of its alpha-equivalence class
+/// Two maps are equal if they have values at the same alpha-equivalence classes, however those classes are represented
+#[derive(Clone, Debug)]
+pub struct AlphaTblExpressionHashMap<C: UnassignedTblExpressionCompound, V> {
+    entries: HashMap<UnassignedTblExpressionAlphaKey, (UnassignedTblExpression<C>, V)>,
+}
+
+impl<C: UnassignedTblExpressionCompound, V> Default for AlphaTblExpressionHashMap<C, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound, V: PartialEq> PartialEq
+    for AlphaTblExpressionHashMap<C, V>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.entries.iter().all(|(key, (_, value))| {
+                other
+                    .entries
+                    .get(key)
+                    .is_some_and(|(_, other_value)| value == other_value)
+            })
+    }
+}
+impl<C: UnassignedTblExpressionCompound, V: Eq> Eq for AlphaTblExpressionHashMap<C, V> {}
+
+impl<C: UnassignedTblExpressionCompound, V> AlphaTblExpressionHashMap<C, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Insert a value at the provided expression, returning the value previously held by any alpha-equivalent expression
+    /// If an alpha-equivalent expression is already present, it is kept as the representative
+    pub fn insert(&mut self, expr: UnassignedTblExpression<C>, value: V) -> Option<V> {
+        match self.entries.entry(expr.alpha_key()) {
+            Entry::Occupied(mut entry) => Some(std::mem::replace(&mut entry.get_mut().1, value)),
+            Entry::Vacant(entry) => {
+                entry.insert((expr, value));
+                None
+            }
+        }
+    }
+    pub fn get(&self, expr: &UnassignedTblExpression<C>) -> Option<&V> {
+        self.entries.get(&expr.alpha_key()).map(|(_, value)| value)
+    }
+    pub fn get_mut(&mut self, expr: &UnassignedTblExpression<C>) -> Option<&mut V> {
+        self.entries
+            .get_mut(&expr.alpha_key())
+            .map(|(_, value)| value)
+    }
+    /// Get the representative of the provided expression's alpha-equivalence class, alongside its value
+    pub fn get_key_value(
+        &self,
+        expr: &UnassignedTblExpression<C>,
+    ) -> Option<(&UnassignedTblExpression<C>, &V)> {
+        self.entries
+            .get(&expr.alpha_key())
+            .map(|(representative, value)| (representative, value))
+    }
+    pub fn contains_key(&self, expr: &UnassignedTblExpression<C>) -> bool {
+        self.entries.contains_key(&expr.alpha_key())
+    }
+    /// Remove the entry of the provided expression's alpha-equivalence class, returning its representative and value
+    pub fn remove(
+        &mut self,
+        expr: &UnassignedTblExpression<C>,
+    ) -> Option<(UnassignedTblExpression<C>, V)> {
+        self.entries.remove(&expr.alpha_key())
+    }
+
+    /// Iterate over the representative and value of each entry
+    pub fn iter(&self) -> impl Iterator<Item = (&UnassignedTblExpression<C>, &V)> {
+        self.entries
+            .values()
+            .map(|(representative, value)| (representative, value))
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound, V> FromIterator<(UnassignedTblExpression<C>, V)>
+    for AlphaTblExpressionHashMap<C, V>
+{
+    fn from_iter<T: IntoIterator<Item = (UnassignedTblExpression<C>, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        for (expr, value) in iter {
+            map.insert(expr, value);
+        }
+        map
+    }
+}
@@ -0,0 +1,216 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use proof_calculus::utils::traits::fast_ord::{FastOrd, fastcmp_for_sorted_slices};
+
+use crate::expressions::types::unassigned::{
+    AtomOrVariableOrCompoundLength, UnassignedTblExpression,
+    compound::UnassignedTblExpressionCompound, variable::TblExpressionVariable,
+};
+
+mod map;
+mod set;
+
+pub use map::AlphaTblExpressionHashMap;
+pub use set::AlphaTblExpressionHashSet;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// A canonical form of an unassigned expression, which is shared by every expression that differs from it only in how its variables are numbered
+/// The expression is written out depth first, with each variable numbered by the order in which it first appears
+pub struct UnassignedTblExpressionAlphaKey(Box<[AtomOrVariableOrCompoundLength]>);
+
+impl UnassignedTblExpressionAlphaKey {
+    pub fn new<C: UnassignedTblExpressionCompound>(expr: &UnassignedTblExpression<C>) -> Self {
+        let mut tokens = Vec::new();
+        write_key(expr, &mut HashMap::new(), &mut tokens);
+        Self(tokens.into())
+    }
+
+    pub fn tokens(&self) -> &[AtomOrVariableOrCompoundLength] {
+        &self.0
+    }
+    pub fn into_tokens(self) -> Box<[AtomOrVariableOrCompoundLength]> {
+        self.0
+    }
+
+    /// The number of distinct variables within the expression
+    pub fn variable_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter_map(|token| match token {
+                AtomOrVariableOrCompoundLength::Variable(variable) => Some(variable.0 + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+impl<C: UnassignedTblExpressionCompound> From<&UnassignedTblExpression<C>>
+    for UnassignedTblExpressionAlphaKey
+{
+    fn from(expr: &UnassignedTblExpression<C>) -> Self {
+        Self::new(expr)
+    }
+}
+impl FastOrd for UnassignedTblExpressionAlphaKey {
+    fn fast_cmp(&self, other: &Self) -> Ordering {
+        fastcmp_for_sorted_slices(&self.0, &other.0)
+    }
+}
+
+impl FastOrd for AtomOrVariableOrCompoundLength {
+    fn fast_cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Atom(atom), Self::Atom(other_atom)) => atom.0.fast_cmp(&other_atom.0),
+            (Self::Variable(variable), Self::Variable(other_variable)) => {
+                variable.0.fast_cmp(&other_variable.0)
+            }
+            (Self::CompoundLength(length), Self::CompoundLength(other_length)) => {
+                length.fast_cmp(other_length)
+            }
+            (Self::Atom(_), _) => Ordering::Less,
+            (_, Self::Atom(_)) => Ordering::Greater,
+            (Self::Variable(_), _) => Ordering::Less,
+            (_, Self::Variable(_)) => Ordering::Greater,
+        }
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound> UnassignedTblExpression<C> {
+    pub fn alpha_key(&self) -> UnassignedTblExpressionAlphaKey {
+        UnassignedTblExpressionAlphaKey::new(self)
+    }
+    /// Whether the two expressions differ only in how their variables are numbered
+    pub fn is_alpha_equivalent(&self, other: &Self) -> bool {
+        self.alpha_key() == other.alpha_key()
+    }
+    /// Renumber the variables of this expression by the order in which they first appear, starting from [TblExpressionVariable::first]
+    /// Any two alpha-equivalent expressions are renumbered to the same expression
+    pub fn alpha_canonical(&self) -> Self
+    where
+        C: FromIterator<UnassignedTblExpression<C>>,
+    {
+        renumber(self, &mut HashMap::new())
+    }
+}
+
+/// Get the canonical number of the provided variable, numbering it after every variable seen so far if it has not been seen before
+fn canonical_variable(
+    variable: &TblExpressionVariable,
+    renumbering: &mut HashMap<TblExpressionVariable, TblExpressionVariable>,
+) -> TblExpressionVariable {
+    let next_variable = TblExpressionVariable(renumbering.len());
+    *renumbering.entry(*variable).or_insert(next_variable)
+}
+
+fn write_key<C: UnassignedTblExpressionCompound>(
+    expr: &UnassignedTblExpression<C>,
+    renumbering: &mut HashMap<TblExpressionVariable, TblExpressionVariable>,
+    tokens: &mut Vec<AtomOrVariableOrCompoundLength>,
+) {
+    match expr {
+        UnassignedTblExpression::Atom(atom) => {
+            tokens.push(AtomOrVariableOrCompoundLength::Atom(*atom));
+        }
+        UnassignedTblExpression::Variable(variable) => {
+            tokens.push(AtomOrVariableOrCompoundLength::Variable(
+                canonical_variable(variable, renumbering),
+            ));
+        }
+        UnassignedTblExpression::Compound(compound) => {
+            tokens.push(AtomOrVariableOrCompoundLength::CompoundLength(
+                compound.len(),
+            ));
+            for subexpression in compound.as_slice() {
+                write_key(subexpression, renumbering, tokens);
+            }
+        }
+    }
+}
+
+fn renumber<C: UnassignedTblExpressionCompound + FromIterator<UnassignedTblExpression<C>>>(
+    expr: &UnassignedTblExpression<C>,
+    renumbering: &mut HashMap<TblExpressionVariable, TblExpressionVariable>,
+) -> UnassignedTblExpression<C> {
+    match expr {
+        UnassignedTblExpression::Atom(atom) => UnassignedTblExpression::Atom(*atom),
+        UnassignedTblExpression::Variable(variable) => {
+            UnassignedTblExpression::Variable(canonical_variable(variable, renumbering))
+        }
+        UnassignedTblExpression::Compound(compound) => UnassignedTblExpression::Compound(
+            compound
+                .as_slice()
+                .iter()
+                .map(|subexpression| renumber(subexpression, renumbering))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::unassigned::{
+        BoxUnassignedTblExpression, compound::r#box::UnassignedBoxCompoundTblExpression,
+    };
+
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+
+    #[test]
+    fn test_alpha_equivalent_keys_collide() {
+        let expr: BoxUnassignedTblExpression = [1.into(), var(0), [var(3), var(0)].into()].into();
+        let renamed: BoxUnassignedTblExpression =
+            [1.into(), var(7), [var(2), var(7)].into()].into();
+        assert_eq!(expr.alpha_key(), renamed.alpha_key());
+        assert!(expr.is_alpha_equivalent(&renamed));
+        assert_eq!(expr.alpha_canonical(), renamed.alpha_canonical());
+        assert_eq!(
+            expr.alpha_canonical(),
+            [1.into(), var(0), [var(1), var(0)].into()].into()
+        );
+        assert_eq!(expr.alpha_key().variable_count(), 2);
+    }
+
+    #[test]
+    fn test_non_equivalent_keys_differ() {
+        let distinct: BoxUnassignedTblExpression = [1.into(), var(0), var(1)].into();
+        let repeated: BoxUnassignedTblExpression = [1.into(), var(0), var(0)].into();
+        assert_ne!(distinct.alpha_key(), repeated.alpha_key());
+        assert!(!distinct.is_alpha_equivalent(&repeated));
+        // A variable is not equivalent to an atom, nor a compound to its flattening
+        assert_ne!(
+            var(0).alpha_key(),
+            BoxUnassignedTblExpression::from(0).alpha_key()
+        );
+        let nested: BoxUnassignedTblExpression = [[var(0)].into(), var(1)].into();
+        let flat: BoxUnassignedTblExpression = [var(0), var(1)].into();
+        assert_ne!(nested.alpha_key(), flat.alpha_key());
+    }
+
+    #[test]
+    fn test_collections_compare_classes() {
+        let first: BoxUnassignedTblExpression = [var(0), var(1)].into();
+        let renamed: BoxUnassignedTblExpression = [var(5), var(4)].into();
+        let repeated: BoxUnassignedTblExpression = [var(0), var(0)].into();
+
+        let mut set = AlphaTblExpressionHashSet::<UnassignedBoxCompoundTblExpression>::new();
+        assert!(set.insert(first.clone()));
+        assert!(!set.insert(renamed.clone()));
+        assert!(set.insert(repeated.clone()));
+        assert_eq!(set.get(&renamed), Some(&first));
+        // Sets with different representatives of the same classes are equal
+        let other_set = AlphaTblExpressionHashSet::from_iter([renamed.clone(), repeated.clone()]);
+        assert_eq!(set, other_set);
+        assert_ne!(set, AlphaTblExpressionHashSet::from_iter([renamed.clone()]));
+
+        let map = AlphaTblExpressionHashMap::from_iter([(first.clone(), 1), (repeated.clone(), 2)]);
+        assert_eq!(map.get(&renamed), Some(&1));
+        let other_map = AlphaTblExpressionHashMap::from_iter([(renamed, 1), (repeated.clone(), 2)]);
+        assert_eq!(map, other_map);
+        assert_ne!(
+            map,
+            AlphaTblExpressionHashMap::from_iter([(first, 1), (repeated, 3)])
+        );
+    }
+}
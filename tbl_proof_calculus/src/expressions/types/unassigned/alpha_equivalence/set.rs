@@ -0,0 +1,87 @@
+use std::collections::{HashMap, hash_map::Entry};
+
+use crate::expressions::types::unassigned::{
+    UnassignedTblExpression, alpha_equivalence::UnassignedTblExpressionAlphaKey,
+    compound::UnassignedTblExpressionCompound,
+};
+
+/// A set of unassigned expressions, which holds at most one expression from each alpha-equivalence class
+/// The first expression inserted from each class is kept as its representative
+/// Two sets are equal if they hold the same alpha-equivalence classes, however those classes are represented
+#[derive(Clone, Debug)]
+pub struct AlphaTblExpressionHashSet<C: UnassignedTblExpressionCompound> {
+    representatives: HashMap<UnassignedTblExpressionAlphaKey, UnassignedTblExpression<C>>,
+}
+
+impl<C: UnassignedTblExpressionCompound> Default for AlphaTblExpressionHashSet<C> {
+    fn default() -> Self {
+        Self {
+            representatives: HashMap::new(),
+        }
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound> PartialEq for AlphaTblExpressionHashSet<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .representatives
+                .keys()
+                .all(|key| other.representatives.contains_key(key))
+    }
+}
+impl<C: UnassignedTblExpressionCompound> Eq for AlphaTblExpressionHashSet<C> {}
+
+impl<C: UnassignedTblExpressionCompound> AlphaTblExpressionHashSet<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.representatives.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.representatives.is_empty()
+    }
+
+    /// Insert the provided expression, returning false if an alpha-equivalent expression was already present
+    pub fn insert(&mut self, expr: UnassignedTblExpression<C>) -> bool {
+        match self.representatives.entry(expr.alpha_key()) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(entry) => {
+                entry.insert(expr);
+                true
+            }
+        }
+    }
+    pub fn contains(&self, expr: &UnassignedTblExpression<C>) -> bool {
+        self.representatives.contains_key(&expr.alpha_key())
+    }
+    /// Get the representative of the provided expression's alpha-equivalence class
+    pub fn get(&self, expr: &UnassignedTblExpression<C>) -> Option<&UnassignedTblExpression<C>> {
+        self.representatives.get(&expr.alpha_key())
+    }
+    /// Remove the provided expression's alpha-equivalence class, returning its representative
+    pub fn remove(
+        &mut self,
+        expr: &UnassignedTblExpression<C>,
+    ) -> Option<UnassignedTblExpression<C>> {
+        self.representatives.remove(&expr.alpha_key())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &UnassignedTblExpression<C>> {
+        self.representatives.values()
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound> FromIterator<UnassignedTblExpression<C>>
+    for AlphaTblExpressionHashSet<C>
+{
+    fn from_iter<T: IntoIterator<Item = UnassignedTblExpression<C>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        for expr in iter {
+            set.insert(expr);
+        }
+        set
+    }
+}
@@ -0,0 +1,174 @@
+use std::{collections::HashSet, hash::Hash, marker::PhantomData};
+
+use proof_calculus::{
+    propositions::types::{
+        assigned::binding::bounds::{
+            GetBoundsForPropIdenticalToProp, GetBoundsForPropsSubsumedByUprop, InsertBoundsForProp,
+            RemoveBoundsForProp,
+        },
+        unassigned::binding::bounds::{
+            GetBoundsForUpropsSubsumingProp, InsertBoundsForUprop, RemoveBoundsForUprop,
+        },
+    },
+    utils::collections::binding::bounds::{GetBounds, UniqueGetBounds},
+};
+
+use crate::expressions::types::{
+    assigned::{TblExpression, compound::TblExpressionCompound},
+    unassigned::{
+        AtomOrVariableOrCompoundLength, UnassignedTblExpression,
+        alpha_equivalence::UnassignedTblExpressionAlphaKey,
+        binding::binders::discrimination_tree::{
+            TblDiscriminationTreeFactBinder, TblDiscriminationTreeFacts, TblDiscriminationTreeKind,
+            TblDiscriminationTreePatternBinder, TblDiscriminationTreePatterns, expression_tokens,
+        },
+        compound::UnassignedTblExpressionCompound,
+    },
+};
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// The tokens an expression or unassigned expression is bound under in a [TblDiscriminationTreeBinder](super::TblDiscriminationTreeBinder) of the same kind, which are used both to insert and to remove its value
+/// Unassigned expressions have their variables numbered by the order in which they first appear, so alpha-equivalent expressions share their tokens
+pub struct TblDiscriminationTreeInsertionBounds<Kind: TblDiscriminationTreeKind>(
+    Box<[AtomOrVariableOrCompoundLength]>,
+    PhantomData<Kind>,
+);
+impl<'a, C: TblExpressionCompound> From<&'a TblExpression<C>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreeFacts>
+{
+    fn from(expr: &'a TblExpression<C>) -> Self {
+        Self(expression_tokens(expr), PhantomData)
+    }
+}
+impl<'a, C: UnassignedTblExpressionCompound> From<&'a UnassignedTblExpression<C>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreePatterns>
+{
+    fn from(expr: &'a UnassignedTblExpression<C>) -> Self {
+        Self(
+            UnassignedTblExpressionAlphaKey::new(expr).into_tokens(),
+            PhantomData,
+        )
+    }
+}
+impl<Kind: TblDiscriminationTreeKind> TblDiscriminationTreeInsertionBounds<Kind> {
+    pub fn tokens(&self) -> &[AtomOrVariableOrCompoundLength] {
+        &self.0
+    }
+}
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    InsertBoundsForProp<'prop, TblExpression<C>, TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreeFacts>
+{
+}
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBoundsForProp<'prop, TblExpression<C>, TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreeFacts>
+{
+}
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    InsertBoundsForUprop<'prop, UnassignedTblExpression<C>, TblDiscriminationTreePatternBinder<T>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreePatterns>
+{
+}
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBoundsForUprop<'prop, UnassignedTblExpression<C>, TblDiscriminationTreePatternBinder<T>>
+    for TblDiscriminationTreeInsertionBounds<TblDiscriminationTreePatterns>
+{
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Gets the value bound to the expression with exactly the same tokens
+pub struct TblDiscriminationTreeGetBoundsForPropIdenticalToProp(
+    Box<[AtomOrVariableOrCompoundLength]>,
+);
+impl<'a, C: TblExpressionCompound> From<&'a TblExpression<C>>
+    for TblDiscriminationTreeGetBoundsForPropIdenticalToProp
+{
+    fn from(expr: &'a TblExpression<C>) -> Self {
+        Self(expression_tokens(expr))
+    }
+}
+impl<T: Hash + Eq + Clone> GetBounds<TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeGetBoundsForPropIdenticalToProp
+{
+    fn get_from<'binder>(
+        &self,
+        binder: &'binder TblDiscriminationTreeFactBinder<T>,
+    ) -> HashSet<&'binder T> {
+        binder.root.collect_identical(&self.0)
+    }
+}
+impl<T: Hash + Eq + Clone> UniqueGetBounds<TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeGetBoundsForPropIdenticalToProp
+{
+}
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    GetBoundsForPropIdenticalToProp<'prop, TblExpression<C>, TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeGetBoundsForPropIdenticalToProp
+{
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Gets the values bound to expressions which are instances of an unassigned expression
+pub struct TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop(UnassignedTblExpressionAlphaKey);
+impl<'a, C: UnassignedTblExpressionCompound> From<&'a UnassignedTblExpression<C>>
+    for TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop
+{
+    fn from(expr: &'a UnassignedTblExpression<C>) -> Self {
+        Self(UnassignedTblExpressionAlphaKey::new(expr))
+    }
+}
+impl<T: Hash + Eq + Clone> GetBounds<TblDiscriminationTreeFactBinder<T>>
+    for TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop
+{
+    fn get_from<'binder>(
+        &self,
+        binder: &'binder TblDiscriminationTreeFactBinder<T>,
+    ) -> HashSet<&'binder T> {
+        let mut found = HashSet::new();
+        binder
+            .root
+            .collect_instances(self.0.tokens(), 0, &mut Vec::new(), &mut found);
+        found
+    }
+}
+impl<'elem, C: 'elem + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    GetBoundsForPropsSubsumedByUprop<
+        'elem,
+        UnassignedTblExpression<C>,
+        TblDiscriminationTreeFactBinder<T>,
+    > for TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop
+{
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Gets the values bound to unassigned expressions which an expression is an instance of
+pub struct TblDiscriminationTreeGetBoundsForUpropsSubsumingProp(
+    Box<[AtomOrVariableOrCompoundLength]>,
+);
+impl<'a, C: TblExpressionCompound> From<&'a TblExpression<C>>
+    for TblDiscriminationTreeGetBoundsForUpropsSubsumingProp
+{
+    fn from(expr: &'a TblExpression<C>) -> Self {
+        Self(expression_tokens(expr))
+    }
+}
+impl<T: Hash + Eq + Clone> GetBounds<TblDiscriminationTreePatternBinder<T>>
+    for TblDiscriminationTreeGetBoundsForUpropsSubsumingProp
+{
+    fn get_from<'binder>(
+        &self,
+        binder: &'binder TblDiscriminationTreePatternBinder<T>,
+    ) -> HashSet<&'binder T> {
+        let mut found = HashSet::new();
+        binder
+            .root
+            .collect_generalizations(&self.0, 0, &mut Vec::new(), &mut found);
+        found
+    }
+}
+impl<'elem, C: 'elem + TblExpressionCompound, T: Hash + Eq + Clone>
+    GetBoundsForUpropsSubsumingProp<'elem, TblExpression<C>, TblDiscriminationTreePatternBinder<T>>
+    for TblDiscriminationTreeGetBoundsForUpropsSubsumingProp
+{
+}
@@ -0,0 +1,274 @@
+use std::{collections::HashSet, fmt::Debug, hash::Hash, marker::PhantomData};
+
+use proof_calculus::{
+    propositions::types::{
+        assigned::binding::binders::{
+            GetBinderForPropIdenticalToProp, GetBinderForPropsSubsumedByUprop, InsertBinderForProp,
+            RemoveBinderForProp,
+        },
+        unassigned::binding::binders::{
+            GetBinderForUpropsSubsumingProp, InsertBinderForUprop, RemoveBinderForUprop,
+        },
+    },
+    utils::collections::binding::binders::{Binder, InsertBinder, RemoveBinder, UpdateBinder},
+};
+
+use crate::{
+    expressions::types::{
+        assigned::{TblExpression, compound::TblExpressionCompound},
+        unassigned::{
+            AtomOrVariableOrCompoundLength,
+            binding::binders::discrimination_tree::{
+                bounds::{
+                    TblDiscriminationTreeGetBoundsForPropIdenticalToProp,
+                    TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop,
+                    TblDiscriminationTreeGetBoundsForUpropsSubsumingProp,
+                    TblDiscriminationTreeInsertionBounds,
+                },
+                node::TblDiscriminationTreeNode,
+            },
+            compound::UnassignedTblExpressionCompound,
+        },
+    },
+    proof_calculus_derived::aliases::propositions::types::{
+        assigned::TblProposition, unassigned::UnassignedTblProposition,
+    },
+};
+
+pub mod bounds;
+mod node;
+
+/// What the values of a [TblDiscriminationTreeBinder] are bound to
+/// Expressions and unassigned expressions are kept in separate trees, as a query for instances would otherwise match the variables of an unassigned expression as though they were subexpressions
+pub trait TblDiscriminationTreeKind: Clone + PartialEq + Eq + Hash + Debug {}
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Values are bound to expressions, and found by the unassigned expressions which the expressions are instances of
+pub struct TblDiscriminationTreeFacts;
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// Values are bound to unassigned expressions, and found by the expressions which are instances of them
+pub struct TblDiscriminationTreePatterns;
+impl TblDiscriminationTreeKind for TblDiscriminationTreeFacts {}
+impl TblDiscriminationTreeKind for TblDiscriminationTreePatterns {}
+
+/// Binds values to expressions or unassigned expressions, depending on its kind, by following their tokens, written out depth first, down a discrimination tree
+/// Unlike [TblExpressionBinder](crate::expressions::types::assigned::binding::binders::TblExpressionBinder), queries walk the tree rather than intersecting a set of values for each bound, so they do not slow down as the number of values sharing a bound grows
+pub struct TblDiscriminationTreeBinder<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind> {
+    root: TblDiscriminationTreeNode<T>,
+    kind: PhantomData<Kind>,
+}
+pub type TblDiscriminationTreeFactBinder<T> =
+    TblDiscriminationTreeBinder<T, TblDiscriminationTreeFacts>;
+pub type TblDiscriminationTreePatternBinder<T> =
+    TblDiscriminationTreeBinder<T, TblDiscriminationTreePatterns>;
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind> TblDiscriminationTreeBinder<T, Kind> {
+    pub fn new() -> Self {
+        Self {
+            root: TblDiscriminationTreeNode::default(),
+            kind: PhantomData,
+        }
+    }
+
+    /// Whether the value is bound under the provided bounds
+    pub fn contains(&self, bounds: &TblDiscriminationTreeInsertionBounds<Kind>, value: &T) -> bool {
+        self.root.contains(bounds.tokens(), value)
+    }
+}
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind> Default
+    for TblDiscriminationTreeBinder<T, Kind>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind> Binder
+    for TblDiscriminationTreeBinder<T, Kind>
+{
+    type Value = T;
+
+    fn get_all<'binder>(&'binder self) -> HashSet<&'binder Self::Value> {
+        let mut found = HashSet::new();
+        self.root.collect_all(&mut found);
+        found
+    }
+}
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind>
+    InsertBinder<TblDiscriminationTreeInsertionBounds<Kind>>
+    for TblDiscriminationTreeBinder<T, Kind>
+{
+    fn insert_by_bounds(
+        &mut self,
+        bounds: &TblDiscriminationTreeInsertionBounds<Kind>,
+        value: Self::Value,
+    ) {
+        self.root.insert(bounds.tokens(), value);
+    }
+}
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind>
+    RemoveBinder<TblDiscriminationTreeInsertionBounds<Kind>>
+    for TblDiscriminationTreeBinder<T, Kind>
+{
+    fn remove_by_bounds(
+        &mut self,
+        bounds: &TblDiscriminationTreeInsertionBounds<Kind>,
+        value: &Self::Value,
+    ) -> bool {
+        self.root.remove(bounds.tokens(), value)
+    }
+}
+impl<T: Hash + Eq + Clone, Kind: TblDiscriminationTreeKind> UpdateBinder
+    for TblDiscriminationTreeBinder<T, Kind>
+{
+    fn remove_value(&mut self, value: &Self::Value) -> bool {
+        self.root.remove_value(value)
+    }
+    fn replace_value(&mut self, old: &Self::Value, new: Self::Value) -> bool {
+        self.root.replace_value(old, new)
+    }
+}
+
+impl<C: TblExpressionCompound, T: Hash + Eq + Clone>
+    GetBinderForPropIdenticalToProp<TblProposition<C>> for TblDiscriminationTreeFactBinder<T>
+{
+    type DefaultGetBoundsForPropIdenticalToProp<'prop>
+        = TblDiscriminationTreeGetBoundsForPropIdenticalToProp
+    where
+        C: 'prop;
+}
+impl<C: UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    GetBinderForPropsSubsumedByUprop<UnassignedTblProposition<C>>
+    for TblDiscriminationTreeFactBinder<T>
+{
+    type DefaultGetBoundsForPropsSubsumedByUprop<'elem>
+        = TblDiscriminationTreeGetBoundsForPropsSubsumedByUprop
+    where
+        C: 'elem;
+}
+impl<C: TblExpressionCompound, T: Hash + Eq + Clone>
+    GetBinderForUpropsSubsumingProp<TblProposition<C>> for TblDiscriminationTreePatternBinder<T>
+{
+    type DefaultGetBoundsForUpropsSubsumingProp<'elem, 'bounds, 'binder>
+        = TblDiscriminationTreeGetBoundsForUpropsSubsumingProp
+    where
+        C: 'elem;
+}
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    InsertBinderForProp<'prop, TblProposition<C>> for TblDiscriminationTreeFactBinder<T>
+{
+    type DefaultInsertionBounds = TblDiscriminationTreeInsertionBounds<TblDiscriminationTreeFacts>;
+}
+impl<'prop, C: 'prop + TblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBinderForProp<'prop, TblProposition<C>> for TblDiscriminationTreeFactBinder<T>
+{
+    type DefaultRemovalBounds = TblDiscriminationTreeInsertionBounds<TblDiscriminationTreeFacts>;
+}
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    InsertBinderForUprop<'prop, UnassignedTblProposition<C>>
+    for TblDiscriminationTreePatternBinder<T>
+{
+    type DefaultInsertionBounds =
+        TblDiscriminationTreeInsertionBounds<TblDiscriminationTreePatterns>;
+}
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBinderForUprop<'prop, UnassignedTblProposition<C>>
+    for TblDiscriminationTreePatternBinder<T>
+{
+    type DefaultRemovalBounds = TblDiscriminationTreeInsertionBounds<TblDiscriminationTreePatterns>;
+}
+
+/// Write out the expression depth first, giving each compound as its length followed by its subexpressions
+fn expression_tokens<C: TblExpressionCompound>(
+    expr: &TblExpression<C>,
+) -> Box<[AtomOrVariableOrCompoundLength]> {
+    let mut tokens = Vec::new();
+    write_tokens(expr, &mut tokens);
+    tokens.into()
+}
+fn write_tokens<C: TblExpressionCompound>(
+    expr: &TblExpression<C>,
+    tokens: &mut Vec<AtomOrVariableOrCompoundLength>,
+) {
+    match expr {
+        TblExpression::Atom(atom) => tokens.push(AtomOrVariableOrCompoundLength::Atom(*atom)),
+        TblExpression::Compound(compound) => {
+            tokens.push(AtomOrVariableOrCompoundLength::CompoundLength(
+                compound.len(),
+            ));
+            for subexpression in compound.as_slice() {
+                write_tokens(subexpression, tokens);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::{
+        assigned::BoxTblExpression,
+        unassigned::{
+            BoxUnassignedTblExpression, UnassignedTblExpression, variable::TblExpressionVariable,
+        },
+    };
+
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+
+    #[test]
+    fn test_fact_binder() {
+        let mut binder = TblDiscriminationTreeFactBinder::new();
+        let pair: BoxTblExpression = [1.into(), 2.into()].into();
+        let repeated: BoxTblExpression = [1.into(), 1.into()].into();
+        let nested: BoxTblExpression = [[1.into()].into(), 2.into()].into();
+        binder.insert_prop(&pair, 0);
+        binder.insert_prop(&repeated, 1);
+        binder.insert_prop(&nested, 2);
+        assert_eq!(binder.get_identical_to(&pair), Some(&0));
+        assert_eq!(binder.get_identical_to(&BoxTblExpression::from(1)), None);
+        assert_eq!(
+            binder.get_subsumed_by(&[var(0), var(1)].into()),
+            HashSet::from([&0, &1, &2])
+        );
+        assert_eq!(
+            binder.get_subsumed_by(&[var(0), var(0)].into()),
+            HashSet::from([&1])
+        );
+        assert_eq!(
+            binder.get_subsumed_by(&[var(0), 2.into()].into()),
+            HashSet::from([&0, &2])
+        );
+        assert!(binder.remove_prop(&pair, &0));
+        assert!(!binder.remove_prop(&pair, &0));
+        assert_eq!(binder.get_identical_to(&pair), None);
+        assert_eq!(binder.get_subsumed_by(&var(0)), HashSet::from([&1, &2]));
+    }
+
+    #[test]
+    fn test_pattern_binder() {
+        let mut binder = TblDiscriminationTreePatternBinder::new();
+        let any = var(0);
+        let any_pair: BoxUnassignedTblExpression = [var(0), var(1)].into();
+        let same_pair: BoxUnassignedTblExpression = [var(0), var(0)].into();
+        let ends_in_two: BoxUnassignedTblExpression = [var(0), 2.into()].into();
+        binder.insert_uprop(&any, 0);
+        binder.insert_uprop(&any_pair, 1);
+        binder.insert_uprop(&same_pair, 2);
+        binder.insert_uprop(&ends_in_two, 3);
+        let pair: BoxTblExpression = [1.into(), 2.into()].into();
+        let repeated: BoxTblExpression = [2.into(), 2.into()].into();
+        assert_eq!(binder.get_subsumers_of(&pair), HashSet::from([&0, &1, &3]));
+        assert_eq!(
+            binder.get_subsumers_of(&repeated),
+            HashSet::from([&0, &1, &2, &3])
+        );
+        assert_eq!(
+            binder.get_subsumers_of(&BoxTblExpression::from(1)),
+            HashSet::from([&0])
+        );
+        // Alpha-equivalent unassigned expressions share their bounds, so either removes the value
+        assert!(binder.remove_uprop(&[var(3), var(5)].into(), &1));
+        assert!(!binder.remove_uprop(&any_pair, &1));
+        assert_eq!(binder.get_subsumers_of(&pair), HashSet::from([&0, &3]));
+    }
+}
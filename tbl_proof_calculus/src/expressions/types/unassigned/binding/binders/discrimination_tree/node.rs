@@ -0,0 +1,447 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    ops::Range,
+};
+
+use crate::expressions::types::unassigned::{
+    AtomOrVariableOrCompoundLength, variable::TblExpressionVariable,
+};
+
+/// A node of a discrimination tree, reached by following the tokens of an expression written out depth first
+pub(super) struct TblDiscriminationTreeNode<T: Hash + Eq> {
+    /// The values of the expressions whose tokens end at this node
+    values: HashSet<T>,
+    children: HashMap<AtomOrVariableOrCompoundLength, TblDiscriminationTreeNode<T>>,
+}
+impl<T: Hash + Eq> Default for TblDiscriminationTreeNode<T> {
+    fn default() -> Self {
+        Self {
+            values: HashSet::new(),
+            children: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq> TblDiscriminationTreeNode<T> {
+    pub(super) fn insert(&mut self, tokens: &[AtomOrVariableOrCompoundLength], value: T) -> bool {
+        let mut node = self;
+        for token in tokens {
+            node = node.children.entry(*token).or_default();
+        }
+        node.values.insert(value)
+    }
+    pub(super) fn contains(&self, tokens: &[AtomOrVariableOrCompoundLength], value: &T) -> bool {
+        self.follow(tokens)
+            .is_some_and(|node| node.values.contains(value))
+    }
+    /// Remove the value from the node the tokens lead to, pruning any nodes which are left empty
+    pub(super) fn remove(&mut self, tokens: &[AtomOrVariableOrCompoundLength], value: &T) -> bool {
+        match tokens.split_first() {
+            None => self.values.remove(value),
+            Some((token, rest)) => match self.children.get_mut(token) {
+                Some(child) => {
+                    let removed = child.remove(rest, value);
+                    if child.is_empty() {
+                        self.children.remove(token);
+                    }
+                    removed
+                }
+                None => false,
+            },
+        }
+    }
+    /// Remove the value from every node, pruning any nodes which are left empty
+    pub(super) fn remove_value(&mut self, value: &T) -> bool {
+        let mut removed = self.values.remove(value);
+        self.children.retain(|_, child| {
+            removed |= child.remove_value(value);
+            !child.is_empty()
+        });
+        removed
+    }
+    pub(super) fn replace_value(&mut self, old: &T, new: T) -> bool
+    where
+        T: Clone,
+    {
+        let mut replaced = false;
+        if self.values.remove(old) {
+            self.values.insert(new.clone());
+            replaced = true;
+        }
+        for child in self.children.values_mut() {
+            replaced |= child.replace_value(old, new.clone());
+        }
+        replaced
+    }
+    fn is_empty(&self) -> bool {
+        self.values.is_empty() && self.children.is_empty()
+    }
+
+    pub(super) fn collect_all<'a>(&'a self, found: &mut HashSet<&'a T>) {
+        found.extend(self.values.iter());
+        for child in self.children.values() {
+            child.collect_all(found);
+        }
+    }
+    pub(super) fn collect_identical<'a>(
+        &'a self,
+        tokens: &[AtomOrVariableOrCompoundLength],
+    ) -> HashSet<&'a T> {
+        match self.follow(tokens) {
+            Some(node) => node.values.iter().collect(),
+            None => HashSet::new(),
+        }
+    }
+    fn follow(&self, tokens: &[AtomOrVariableOrCompoundLength]) -> Option<&Self> {
+        tokens
+            .iter()
+            .try_fold(self, |node, token| node.children.get(token))
+    }
+
+    /// Collect the values of the expressions which are instances of the query, from the provided position of the query onwards
+    /// The query has its variables numbered by the order in which they first appear, so the tokens each variable was bound to are found by its number
+    pub(super) fn collect_instances<'a>(
+        &'a self,
+        query: &[AtomOrVariableOrCompoundLength],
+        position: usize,
+        bindings: &mut Vec<Vec<AtomOrVariableOrCompoundLength>>,
+        found: &mut HashSet<&'a T>,
+    ) {
+        if position == query.len() {
+            found.extend(self.values.iter());
+            return;
+        }
+        match query[position] {
+            // The first appearance of a variable matches any subexpression, which becomes its binding
+            AtomOrVariableOrCompoundLength::Variable(variable) if variable.0 == bindings.len() => {
+                let mut ends = Vec::new();
+                self.skip_subexpression(1, &mut Vec::new(), &mut ends);
+                for (end, skipped) in ends {
+                    bindings.push(skipped);
+                    end.collect_instances(query, position + 1, bindings, found);
+                    bindings.pop();
+                }
+            }
+            // Later appearances only match the subexpression the variable was bound to
+            AtomOrVariableOrCompoundLength::Variable(variable) => {
+                let end = bindings
+                    .get(variable.0)
+                    .and_then(|bound| self.follow(bound));
+                if let Some(end) = end {
+                    end.collect_instances(query, position + 1, bindings, found);
+                }
+            }
+            token => {
+                if let Some(child) = self.children.get(&token) {
+                    child.collect_instances(query, position + 1, bindings, found);
+                }
+            }
+        }
+    }
+    /// Find every node which is reached by following the tokens of the provided number of whole subexpressions, alongside the tokens followed
+    fn skip_subexpression<'a>(
+        &'a self,
+        remaining: usize,
+        skipped: &mut Vec<AtomOrVariableOrCompoundLength>,
+        ends: &mut Vec<(&'a Self, Vec<AtomOrVariableOrCompoundLength>)>,
+    ) {
+        if remaining == 0 {
+            ends.push((self, skipped.clone()));
+            return;
+        }
+        for (token, child) in self.children.iter() {
+            skipped.push(*token);
+            child.skip_subexpression(
+                remaining - 1 + immediate_subexpression_count(token),
+                skipped,
+                ends,
+            );
+            skipped.pop();
+        }
+    }
+
+    /// Collect the values of the expressions which the query is an instance of, from the provided position of the query onwards
+    /// The query contains no variables, and each variable in the tree is bound to the range of the query it matched
+    pub(super) fn collect_generalizations<'a>(
+        &'a self,
+        query: &[AtomOrVariableOrCompoundLength],
+        position: usize,
+        bindings: &mut Vec<Range<usize>>,
+        found: &mut HashSet<&'a T>,
+    ) {
+        if position == query.len() {
+            found.extend(self.values.iter());
+            return;
+        }
+        if let Some(child) = self.children.get(&query[position]) {
+            child.collect_generalizations(query, position + 1, bindings, found);
+        }
+        // Variables are numbered by the order in which they first appear, so only those already bound and the next may follow
+        let end = subexpression_end(query, position);
+        let bound_count = bindings.len();
+        for index in 0..=bound_count {
+            let child = match self.children.get(&AtomOrVariableOrCompoundLength::Variable(
+                TblExpressionVariable(index),
+            )) {
+                Some(child) => child,
+                None => continue,
+            };
+            if index == bound_count {
+                bindings.push(position..end);
+                child.collect_generalizations(query, end, bindings, found);
+                bindings.pop();
+            } else if query[bindings[index].clone()] == query[position..end] {
+                child.collect_generalizations(query, end, bindings, found);
+            }
+        }
+    }
+}
+
+fn immediate_subexpression_count(token: &AtomOrVariableOrCompoundLength) -> usize {
+    match token {
+        AtomOrVariableOrCompoundLength::CompoundLength(length) => *length,
+        _ => 0,
+    }
+}
+
+/// Get the position just past the subexpression which starts at the provided position
+fn subexpression_end(tokens: &[AtomOrVariableOrCompoundLength], start: usize) -> usize {
+    let mut remaining = 1;
+    let mut position = start;
+    while remaining > 0 {
+        remaining = remaining - 1 + immediate_subexpression_count(&tokens[position]);
+        position += 1;
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::{
+        assigned::{
+            BoxTblExpression, atom::TblExpressionAtom, binding::binders::TblExpressionBinder,
+        },
+        unassigned::{
+            BoxUnassignedTblExpression, UnassignedTblExpression,
+            alpha_equivalence::UnassignedTblExpressionAlphaKey,
+            binding::binders::discrimination_tree::expression_tokens,
+        },
+    };
+    use itertools::Itertools;
+    use proof_calculus::propositions::types::assigned::binding::binders::{
+        GetBinderForPropIdenticalToProp, GetBinderForPropsSubsumedByUprop, InsertBinderForProp,
+    };
+
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+    fn atom(value: u16) -> AtomOrVariableOrCompoundLength {
+        AtomOrVariableOrCompoundLength::Atom(TblExpressionAtom(value))
+    }
+    fn pattern_tokens(
+        pattern: &BoxUnassignedTblExpression,
+    ) -> Box<[AtomOrVariableOrCompoundLength]> {
+        UnassignedTblExpressionAlphaKey::new(pattern).into_tokens()
+    }
+    /// Every expression of depth at most two built from the atoms 1 and 2 and compounds of length at most two, each only once
+    fn facts() -> Vec<BoxTblExpression> {
+        let atoms: Vec<BoxTblExpression> = vec![1.into(), 2.into()];
+        let mut small = atoms.clone();
+        for (first, second) in atoms.iter().cartesian_product(atoms.iter()) {
+            small.push([first.clone(), second.clone()].into());
+        }
+        for first in &atoms {
+            small.push([first.clone()].into());
+        }
+        let mut facts = small.clone();
+        for (first, second) in small.iter().cartesian_product(small.iter()) {
+            facts.push([first.clone(), second.clone()].into());
+        }
+        for first in &small {
+            facts.push([first.clone()].into());
+        }
+        facts.into_iter().unique().collect()
+    }
+    /// Patterns which the facts may be instances of, including several which repeat a variable
+    fn patterns() -> Vec<BoxUnassignedTblExpression> {
+        vec![
+            var(0),
+            [var(0)].into(),
+            [var(0), var(1)].into(),
+            [var(1), var(0)].into(),
+            [var(0), var(0)].into(),
+            [1.into(), var(0)].into(),
+            [var(0), [var(0)].into()].into(),
+            [[var(0)].into(), var(0)].into(),
+            [[var(0), var(1)].into(), [var(1), var(0)].into()].into(),
+            [[var(0), var(0)].into(), var(1)].into(),
+            [var(0), [1.into(), var(0)].into()].into(),
+            [[var(0), 2.into()].into(), [var(0), var(1)].into()].into(),
+            [[var(0), var(1)].into()].into(),
+            [var(0), var(1), var(0)].into(),
+        ]
+    }
+
+    #[test]
+    fn test_collect_identical_agrees_with_expression_binder() {
+        let facts = facts();
+        let mut root = TblDiscriminationTreeNode::default();
+        let mut binder = TblExpressionBinder::new();
+        for (index, fact) in facts.iter().enumerate() {
+            root.insert(&expression_tokens(fact), index);
+            binder.insert_prop(fact, index);
+        }
+        for fact in &facts {
+            let expected: HashSet<_> = binder.get_identical_to(fact).into_iter().collect();
+            assert_eq!(root.collect_identical(&expression_tokens(fact)), expected);
+        }
+        let absent: BoxTblExpression = [3.into(), 1.into()].into();
+        assert!(
+            root.collect_identical(&expression_tokens(&absent))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_collect_instances_agrees_with_expression_binder() {
+        let facts = facts();
+        let mut root = TblDiscriminationTreeNode::default();
+        let mut binder = TblExpressionBinder::new();
+        for (index, fact) in facts.iter().enumerate() {
+            root.insert(&expression_tokens(fact), index);
+            binder.insert_prop(fact, index);
+        }
+        for pattern in patterns() {
+            let mut found = HashSet::new();
+            root.collect_instances(&pattern_tokens(&pattern), 0, &mut Vec::new(), &mut found);
+            assert_eq!(found, binder.get_subsumed_by(&pattern), "{pattern:?}");
+        }
+        // A repeated variable only matches subexpressions which are the same
+        let mut found = HashSet::new();
+        root.collect_instances(
+            &pattern_tokens(&[var(0), var(0)].into()),
+            0,
+            &mut Vec::new(),
+            &mut found,
+        );
+        assert_eq!(found.len(), 8);
+    }
+
+    #[test]
+    fn test_collect_generalizations_agrees_with_expression_binder() {
+        let facts = facts();
+        let patterns = patterns();
+        let mut binder = TblExpressionBinder::new();
+        for (index, fact) in facts.iter().enumerate() {
+            binder.insert_prop(fact, index);
+        }
+        let mut root = TblDiscriminationTreeNode::default();
+        for (index, pattern) in patterns.iter().enumerate() {
+            root.insert(&pattern_tokens(pattern), index);
+        }
+        // Each pattern is expected to generalize exactly the facts the expression binder finds as its instances
+        let instances: Vec<HashSet<&usize>> = patterns
+            .iter()
+            .map(|pattern| binder.get_subsumed_by(pattern))
+            .collect();
+        for (fact_index, fact) in facts.iter().enumerate() {
+            let expected: HashSet<_> = patterns
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| instances[*index].contains(&fact_index))
+                .map(|(index, _)| index)
+                .collect();
+            let mut found = HashSet::new();
+            root.collect_generalizations(&expression_tokens(fact), 0, &mut Vec::new(), &mut found);
+            assert_eq!(found, expected.iter().collect(), "{fact:?}");
+        }
+    }
+
+    #[test]
+    fn test_skip_subexpression() {
+        let facts: [BoxTblExpression; 4] = [
+            1.into(),
+            [1.into()].into(),
+            [1.into(), 2.into()].into(),
+            [[1.into()].into(), 2.into()].into(),
+        ];
+        let mut root = TblDiscriminationTreeNode::default();
+        for (index, fact) in facts.iter().enumerate() {
+            root.insert(&expression_tokens(fact), index);
+        }
+        // Skipping one subexpression from the root skips each whole expression
+        let mut ends = Vec::new();
+        root.skip_subexpression(1, &mut Vec::new(), &mut ends);
+        let skipped: HashSet<_> = ends.iter().map(|(_, skipped)| skipped.clone()).collect();
+        let expected: HashSet<_> = facts
+            .iter()
+            .map(|fact| expression_tokens(fact).to_vec())
+            .collect();
+        assert_eq!(skipped, expected);
+        for (end, _) in &ends {
+            assert_eq!(end.values.len(), 1);
+        }
+        // Skipping within a compound stops after each of its immediate subexpressions
+        let pair = &root.children[&AtomOrVariableOrCompoundLength::CompoundLength(2)];
+        let mut ends = Vec::new();
+        pair.skip_subexpression(1, &mut Vec::new(), &mut ends);
+        let skipped: HashSet<_> = ends.into_iter().map(|(_, skipped)| skipped).collect();
+        assert_eq!(
+            skipped,
+            HashSet::from([
+                vec![atom(1)],
+                vec![AtomOrVariableOrCompoundLength::CompoundLength(1), atom(1)]
+            ])
+        );
+        let mut ends = Vec::new();
+        pair.skip_subexpression(0, &mut Vec::new(), &mut ends);
+        assert!(matches!(ends.as_slice(), [(_, skipped)] if skipped.is_empty()));
+    }
+
+    #[test]
+    fn test_remove_prunes_empty_nodes() {
+        let first: BoxTblExpression = [1.into(), 2.into()].into();
+        let second: BoxTblExpression = [1.into(), 3.into()].into();
+        let mut root = TblDiscriminationTreeNode::default();
+        root.insert(&expression_tokens(&first), 0);
+        root.insert(&expression_tokens(&second), 1);
+        assert!(!root.remove(&expression_tokens(&first), &1));
+        let unknown: BoxTblExpression = [1.into(), 4.into()].into();
+        assert!(!root.remove(&expression_tokens(&unknown), &0));
+        assert!(root.remove(&expression_tokens(&first), &0));
+        assert!(!root.remove(&expression_tokens(&first), &0));
+        // Only the branch leading to the removed expression is pruned
+        let shared =
+            &root.children[&AtomOrVariableOrCompoundLength::CompoundLength(2)].children[&atom(1)];
+        assert_eq!(shared.children.keys().collect::<Vec<_>>(), vec![&atom(3)]);
+        assert!(root.contains(&expression_tokens(&second), &1));
+        assert!(root.remove(&expression_tokens(&second), &1));
+        assert!(root.is_empty());
+    }
+
+    #[test]
+    fn test_remove_and_replace_value() {
+        let first: BoxTblExpression = [1.into(), 2.into()].into();
+        let second: BoxTblExpression = [1.into(), [3.into()].into()].into();
+        let mut root = TblDiscriminationTreeNode::default();
+        root.insert(&expression_tokens(&first), 0);
+        root.insert(&expression_tokens(&second), 0);
+        root.insert(&expression_tokens(&first), 1);
+        assert!(root.replace_value(&0, 2));
+        assert!(!root.replace_value(&0, 2));
+        assert_eq!(
+            root.collect_identical(&expression_tokens(&first)),
+            HashSet::from([&1, &2])
+        );
+        assert!(root.remove_value(&2));
+        assert!(!root.remove_value(&2));
+        assert!(!root.contains(&expression_tokens(&second), &2));
+        // The branch which only held the removed value is pruned
+        let pair = &root.children[&AtomOrVariableOrCompoundLength::CompoundLength(2)];
+        assert_eq!(pair.children[&atom(1)].children.len(), 1);
+        assert!(root.remove_value(&1));
+        assert!(root.is_empty());
+    }
+}
@@ -2,10 +2,10 @@ use std::{collections::HashSet, hash::Hash};
 
 use proof_calculus::{
     propositions::types::unassigned::binding::binders::{
-        GetBinderForUpropIdenticalToUprop, InsertBinderForUprop,
+        GetBinderForUpropIdenticalToUprop, InsertBinderForUprop, RemoveBinderForUprop,
     },
     utils::collections::{
-        binding::binders::{Binder, GetBinder, InsertBinder},
+        binding::binders::{Binder, GetBinder, InsertBinder, RemoveBinder, UpdateBinder},
         sets::hashset::transform_hashset,
     },
 };
@@ -17,7 +17,7 @@ use crate::{
             atom::TblExpressionAtom,
             binding::{
                 binders::{
-                    atom_value::TblExpressionBinderAtomExactValue,
+                    InsertionRecords, atom_value::TblExpressionBinderAtomExactValue,
                     compound_length::TblExpressionBinderCompoundExactLength,
                     value_duplication::TblExpressionBinderValueDuplication,
                 },
@@ -57,6 +57,7 @@ use crate::{
     proof_calculus_derived::aliases::propositions::types::unassigned::UnassignedTblProposition,
 };
 
+pub mod discrimination_tree;
 pub mod variable_value;
 
 pub struct UnassignedTblExpressionBinder<T: Hash + Eq + Clone> {
@@ -64,6 +65,19 @@ pub struct UnassignedTblExpressionBinder<T: Hash + Eq + Clone> {
     variable_value_bounds: TblExpressionBinderVariableExactValue<T>,
     compound_length_bounds: TblExpressionBinderCompoundExactLength<T>,
     duplicate_value_bounds: TblExpressionBinderValueDuplication<T>,
+    insertions: InsertionRecords<UnassignedTblExpressionInsertionBound, T>,
+}
+
+impl<T: Hash + Eq + Clone> UnassignedTblExpressionBinder<T> {
+    pub fn new() -> Self {
+        Self {
+            atom_value_bounds: TblExpressionBinderAtomExactValue::new(),
+            variable_value_bounds: TblExpressionBinderVariableExactValue::new(),
+            compound_length_bounds: TblExpressionBinderCompoundExactLength::new(),
+            duplicate_value_bounds: TblExpressionBinderValueDuplication::new(),
+            insertions: InsertionRecords::new(),
+        }
+    }
 }
 
 pub type UnassignedTblPropositionBinder<T> = UnassignedTblExpressionBinder<T>;
@@ -263,6 +277,7 @@ impl<T: Hash + Eq + Clone> InsertBinder<TblFastConstructInsertionBoundsForUprop>
         bounds: &TblFastConstructInsertionBoundsForUprop,
         value: Self::Value,
     ) {
+        self.insertions.record(bounds.bounds(), value.clone());
         for bound in bounds.bounds() {
             match bound {
                 UnassignedTblExpressionInsertionBound::AtomValue(atom_bound) => self
@@ -286,6 +301,57 @@ impl<T: Hash + Eq + Clone> InsertBinder<TblFastConstructInsertionBoundsForUprop>
     }
 }
 
+impl<T: Hash + Eq + Clone> RemoveBinder<TblFastConstructInsertionBoundsForUprop>
+    for UnassignedTblPropositionBinder<T>
+{
+    fn remove_by_bounds(
+        &mut self,
+        bounds: &TblFastConstructInsertionBoundsForUprop,
+        value: &Self::Value,
+    ) -> bool {
+        // Each bound may be shared with other unassigned propositions, so only a value inserted under exactly these bounds is removed
+        if !self.insertions.forget(bounds.bounds(), value) {
+            return false;
+        }
+        for bound in bounds.bounds() {
+            match bound {
+                UnassignedTblExpressionInsertionBound::AtomValue(atom_bound) => self
+                    .atom_value_bounds
+                    .remove(&atom_bound.path, &atom_bound.value, value),
+                UnassignedTblExpressionInsertionBound::VariableValue(variable_bound) => self
+                    .variable_value_bounds
+                    .remove(&variable_bound.path, &variable_bound.value, value),
+                UnassignedTblExpressionInsertionBound::CompoundLength(compound_bound) => self
+                    .compound_length_bounds
+                    .remove(&compound_bound.path, compound_bound.length, value),
+                UnassignedTblExpressionInsertionBound::ValueDuplicated(dups_bound) => self
+                    .duplicate_value_bounds
+                    .remove(dups_bound.path1(), dups_bound.path2(), value),
+            };
+        }
+        true
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for UnassignedTblPropositionBinder<T> {
+    fn remove_value(&mut self, value: &Self::Value) -> bool {
+        // Each component binder is updated even once the value has been found in another
+        let atom_removed = self.atom_value_bounds.remove_value(value);
+        let variable_removed = self.variable_value_bounds.remove_value(value);
+        let compound_removed = self.compound_length_bounds.remove_value(value);
+        let duplicate_removed = self.duplicate_value_bounds.remove_value(value);
+        self.insertions.forget_value(value);
+        atom_removed || variable_removed || compound_removed || duplicate_removed
+    }
+    fn replace_value(&mut self, old: &Self::Value, new: Self::Value) -> bool {
+        self.insertions.replace_value(old, new.clone());
+        let atom_replaced = self.atom_value_bounds.replace_value(old, new.clone());
+        let variable_replaced = self.variable_value_bounds.replace_value(old, new.clone());
+        let compound_replaced = self.compound_length_bounds.replace_value(old, new.clone());
+        let duplicate_replaced = self.duplicate_value_bounds.replace_value(old, new);
+        atom_replaced || variable_replaced || compound_replaced || duplicate_replaced
+    }
+}
+
 impl<C: UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
     GetBinderForUpropIdenticalToUprop<UnassignedTblProposition<C>>
     for UnassignedTblPropositionBinder<T>
@@ -300,3 +366,73 @@ impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
 {
     type DefaultInsertionBounds = TblFastConstructInsertionBoundsForUprop;
 }
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, T: Hash + Eq + Clone>
+    RemoveBinderForUprop<'prop, UnassignedTblProposition<C>> for UnassignedTblPropositionBinder<T>
+{
+    type DefaultRemovalBounds = TblFastConstructInsertionBoundsForUprop;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::unassigned::{
+        BoxUnassignedTblExpression, UnassignedTblExpression,
+    };
+
+    fn var(id: usize) -> BoxUnassignedTblExpression {
+        UnassignedTblExpression::Variable(TblExpressionVariable(id))
+    }
+
+    #[test]
+    fn test_remove_uprop() {
+        let mut binder = UnassignedTblPropositionBinder::new();
+        let first: BoxUnassignedTblExpression = [1.into(), var(0)].into();
+        let second: BoxUnassignedTblExpression = [1.into(), var(1)].into();
+        // Both unassigned propositions bind the value to the atom 1 at the same path
+        binder.insert_uprop(&first, 0);
+        binder.insert_uprop(&second, 0);
+        assert!(binder.remove_uprop(&first, &0));
+        assert_eq!(binder.get_identical_to(&first), None);
+        assert_eq!(binder.get_identical_to(&second), Some(&0));
+        assert!(!binder.remove_uprop(&first, &0));
+        assert!(binder.remove_uprop(&second, &0));
+        assert!(binder.get_all().is_empty());
+    }
+
+    #[test]
+    fn test_remove_requires_exact_bounds() {
+        let mut binder = UnassignedTblPropositionBinder::new();
+        let first: BoxUnassignedTblExpression = [1.into(), var(2), 5.into()].into();
+        let second: BoxUnassignedTblExpression = [4.into(), var(3), 6.into()].into();
+        // Every bound of this unassigned proposition is shared with one of the others, but it was never inserted
+        let mixed: BoxUnassignedTblExpression = [1.into(), var(3), 6.into()].into();
+        binder.insert_uprop(&first, 0);
+        binder.insert_uprop(&second, 0);
+        assert!(!binder.remove_uprop(&mixed, &0));
+        assert_eq!(binder.get_identical_to(&first), Some(&0));
+        assert_eq!(binder.get_identical_to(&second), Some(&0));
+        assert!(binder.remove_uprop(&first, &0));
+        assert!(binder.remove_uprop(&second, &0));
+        assert!(binder.get_all().is_empty());
+    }
+
+    #[test]
+    fn test_update_value() {
+        let mut binder = UnassignedTblPropositionBinder::new();
+        let first: BoxUnassignedTblExpression = [1.into(), var(0)].into();
+        let second: BoxUnassignedTblExpression = [var(0), var(0)].into();
+        binder.insert_uprop(&first, 0);
+        binder.insert_uprop(&second, 1);
+        assert!(binder.replace_value(&0, 1));
+        assert_eq!(binder.get_identical_to(&first), Some(&1));
+        assert_eq!(binder.get_all(), HashSet::from([&1]));
+        // The replaced value keeps the insertion it took over, so it can still be removed for the first unassigned proposition
+        assert!(binder.remove_uprop(&first, &1));
+        assert_eq!(binder.get_identical_to(&first), None);
+        assert_eq!(binder.get_identical_to(&second), Some(&1));
+        assert!(binder.remove_value(&1));
+        assert!(binder.get_all().is_empty());
+        assert!(!binder.remove_value(&1));
+        assert!(!binder.replace_value(&1, 2));
+    }
+}
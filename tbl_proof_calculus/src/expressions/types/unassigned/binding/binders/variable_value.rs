@@ -1,18 +1,14 @@
-use std::{
-    collections::{HashMap, HashSet},
-    hash::Hash,
-};
+use std::{collections::HashSet, hash::Hash};
 
 use proof_calculus::utils::collections::{
-    binding::binders::{Binder, GetBinder},
-    maps::multimap::MultiMap,
+    binding::binders::{Binder, GetBinder, UpdateBinder},
     sets::hashset::transform_hashset,
 };
 
 use crate::expressions::{
     paths::TblSubexpressionInExpressionPath,
     types::{
-        assigned::binding::binders::{get_helper, insert_helper},
+        assigned::binding::binders::CountedBindings,
         unassigned::{
             binding::bounds::{
                 UnassignedTblExpressionBoundVariableExactValue,
@@ -23,22 +19,18 @@ use crate::expressions::{
     },
 };
 
-pub struct TblExpressionBinderVariableExactValue<T: Hash>(
-    HashMap<TblSubexpressionInExpressionPath, MultiMap<TblExpressionVariable, T>>,
+pub struct TblExpressionBinderVariableExactValue<T: Hash + Eq>(
+    CountedBindings<TblSubexpressionInExpressionPath, TblExpressionVariable, T>,
 );
 
-impl<T: Eq + Hash> Binder for TblExpressionBinderVariableExactValue<T> {
+impl<T: Eq + Hash + Clone> Binder for TblExpressionBinderVariableExactValue<T> {
     type Value = T;
 
     fn get_all<'binder>(&'binder self) -> HashSet<&'binder Self::Value> {
-        self.0
-            .iter()
-            .map(|(_, inner)| inner.flat_values())
-            .flatten()
-            .collect()
+        self.0.values().collect()
     }
 }
-impl<T: Eq + Hash> GetBinder<UnassignedTblExpressionBoundVariableExactValue>
+impl<T: Eq + Hash + Clone> GetBinder<UnassignedTblExpressionBoundVariableExactValue>
     for TblExpressionBinderVariableExactValue<T>
 {
     fn get<'binder>(
@@ -54,14 +46,14 @@ impl<T: Eq + Hash> GetBinder<UnassignedTblExpressionBoundVariableExactValue>
         transform_hashset(self.get_inner(&bound.path, &bound.value), |v| (v, ()))
     }
 }
-impl<T: Eq + Hash> GetBinder<UnassignedTblExpressionBoundVariableExistsAtLocation>
+impl<T: Eq + Hash + Clone> GetBinder<UnassignedTblExpressionBoundVariableExistsAtLocation>
     for TblExpressionBinderVariableExactValue<T>
 {
     fn get<'binder>(
         &'binder self,
         bound: &UnassignedTblExpressionBoundVariableExistsAtLocation,
     ) -> HashSet<&'binder Self::Value> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner.flat_values().into_iter().collect(),
             None => HashSet::new(),
         }
@@ -70,7 +62,7 @@ impl<T: Eq + Hash> GetBinder<UnassignedTblExpressionBoundVariableExistsAtLocatio
         &'binder self,
         bound: &UnassignedTblExpressionBoundVariableExistsAtLocation,
     ) -> HashSet<(&'binder Self::Value, TblExpressionVariable)> {
-        match self.0.get(&bound.path) {
+        match self.0.get_by_outer_key(&bound.path) {
             Some(inner) => inner
                 .pairs()
                 .into_iter()
@@ -81,13 +73,17 @@ impl<T: Eq + Hash> GetBinder<UnassignedTblExpressionBoundVariableExistsAtLocatio
     }
 }
 
-impl<T: Hash + Eq> TblExpressionBinderVariableExactValue<T> {
+impl<T: Hash + Eq + Clone> TblExpressionBinderVariableExactValue<T> {
+    pub fn new() -> Self {
+        Self(CountedBindings::new())
+    }
+
     fn get_inner(
         &self,
         path: &TblSubexpressionInExpressionPath,
         value: &TblExpressionVariable,
     ) -> HashSet<&T> {
-        get_helper(&self.0, path, value)
+        self.0.get(path, value)
     }
 
     pub fn insert(
@@ -96,7 +92,7 @@ impl<T: Hash + Eq> TblExpressionBinderVariableExactValue<T> {
         atom: TblExpressionVariable,
         value: T,
     ) -> bool {
-        insert_helper(&mut self.0, path, atom, value)
+        self.0.insert(path, atom, value)
     }
     pub fn insert2(
         &mut self,
@@ -105,4 +101,29 @@ impl<T: Hash + Eq> TblExpressionBinderVariableExactValue<T> {
     ) -> bool {
         self.insert(&bound.path, bound.value, value)
     }
+
+    pub fn contains(
+        &self,
+        path: &TblSubexpressionInExpressionPath,
+        variable: &TblExpressionVariable,
+        value: &T,
+    ) -> bool {
+        self.0.contains(path, variable, value)
+    }
+    pub fn remove(
+        &mut self,
+        path: &TblSubexpressionInExpressionPath,
+        variable: &TblExpressionVariable,
+        value: &T,
+    ) -> bool {
+        self.0.remove(path, variable, value)
+    }
+}
+impl<T: Hash + Eq + Clone> UpdateBinder for TblExpressionBinderVariableExactValue<T> {
+    fn remove_value(&mut self, value: &T) -> bool {
+        self.0.remove_value(value)
+    }
+    fn replace_value(&mut self, old: &T, new: T) -> bool {
+        self.0.replace_value(old, new)
+    }
 }
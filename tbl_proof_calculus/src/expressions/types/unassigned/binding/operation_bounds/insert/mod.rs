@@ -1,6 +1,11 @@
 use proof_calculus::{
-    propositions::types::unassigned::binding::bounds::InsertBoundsForUprop,
-    utils::collections::{binding::binders::InsertBinder, maps::multimap::MultiMap},
+    propositions::types::unassigned::binding::bounds::{
+        InsertBoundsForUprop, RemoveBoundsForUprop,
+    },
+    utils::collections::{
+        binding::binders::{InsertBinder, RemoveBinder},
+        maps::multimap::MultiMap,
+    },
 };
 
 use crate::expressions::types::{
@@ -27,6 +32,12 @@ impl<'prop, C: 'prop + UnassignedTblExpressionCompound, B: InsertBinder<Self>>
     for TblFastConstructInsertionBoundsForUprop
 {
 }
+// Values are removed using the same bounds they were inserted with
+impl<'prop, C: 'prop + UnassignedTblExpressionCompound, B: RemoveBinder<Self>>
+    RemoveBoundsForUprop<'prop, UnassignedTblExpression<C>, B>
+    for TblFastConstructInsertionBoundsForUprop
+{
+}
 
 impl<'a, C: UnassignedTblExpressionCompound> From<&'a UnassignedTblExpression<C>>
     for TblFastConstructInsertionBoundsForUexpr
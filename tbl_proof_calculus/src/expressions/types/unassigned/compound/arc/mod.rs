@@ -114,6 +114,11 @@ mod from {
                 .collect()
         }
     }
+    impl From<&UnassignedArcCompoundTblExpression> for UnassignedArcCompoundTblExpression {
+        fn from(value: &UnassignedArcCompoundTblExpression) -> Self {
+            value.clone()
+        }
+    }
     // impl <C: CompoundTblExpression + FromIterator<TblExpression<C>>,Err> TryInto<C> for &UnassignedArcCompoundTblExpression
     // where for<'a> &'a ArcUnassignedTblExpression: TryInto<TblExpression<C>,Error=Err> {
     //     type Error = Err;
@@ -112,6 +112,11 @@ mod from {
                 .collect()
         }
     }
+    impl From<&UnassignedBoxCompoundTblExpression> for UnassignedBoxCompoundTblExpression {
+        fn from(value: &UnassignedBoxCompoundTblExpression) -> Self {
+            value.clone()
+        }
+    }
     impl TryInto<BoxTblExpressionCompound> for &UnassignedBoxCompoundTblExpression {
         type Error = TblExpressionVariable;
         fn try_into(self) -> Result<BoxTblExpressionCompound, Self::Error> {
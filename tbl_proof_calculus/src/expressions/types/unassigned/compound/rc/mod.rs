@@ -114,6 +114,11 @@ mod from {
                 .collect()
         }
     }
+    impl From<&UnassignedRcCompoundTblExpression> for UnassignedRcCompoundTblExpression {
+        fn from(value: &UnassignedRcCompoundTblExpression) -> Self {
+            value.clone()
+        }
+    }
     impl TryInto<RcTblExpressionCompound> for &UnassignedRcCompoundTblExpression {
         type Error = TblExpressionVariable;
         fn try_into(self) -> Result<RcTblExpressionCompound, Self::Error> {
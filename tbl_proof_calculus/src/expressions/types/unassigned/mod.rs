@@ -25,6 +25,7 @@ use crate::expressions::{
     },
 };
 
+pub mod alpha_equivalence;
 pub mod at_path_enum;
 pub mod binding;
 pub mod compound;
@@ -1,3 +1,4 @@
 pub mod assigned;
+pub mod negation;
 pub mod normalised_unassigned;
 pub mod unassigned;
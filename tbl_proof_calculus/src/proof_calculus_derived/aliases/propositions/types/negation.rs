@@ -0,0 +1,150 @@
+use std::{fmt::Debug, hash::Hash, marker::PhantomData};
+
+use proof_calculus::{
+    normal_forms::clauses::ComplementaryProposition, propositions::types::assigned::Proposition,
+};
+
+use crate::expressions::types::{
+    assigned::{TblExpression, atom::TblExpressionAtom, compound::TblExpressionCompound},
+    unassigned::{UnassignedTblExpression, compound::UnassignedTblExpressionCompound},
+};
+
+/// Names the atom which heads a negation within some axiomatic system, so that (¬,P) is the negation of P
+/// Tuple-Based Logic has no negation of its own, so each system built upon it supplies one
+pub trait TblNegation: Clone + Eq + Hash + Debug {
+    const NEGATION_ATOM: TblExpressionAtom;
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// An expression whose negations are headed by the atom named by N, so that a literal and its negation can be recognised as complementary within a normal form
+pub struct NegatableTblExpression<E, N: TblNegation>(pub E, PhantomData<N>);
+impl<E, N: TblNegation> From<E> for NegatableTblExpression<E, N> {
+    fn from(expression: E) -> Self {
+        Self(expression, PhantomData)
+    }
+}
+
+impl<C: TblExpressionCompound, N: TblNegation> NegatableTblExpression<TblExpression<C>, N> {
+    /// Get the expression negated by this one, or [None] if this is not of the form (¬,P)
+    pub fn get_negated(&self) -> Option<&TblExpression<C>> {
+        match self.0.as_slice() {
+            Ok([TblExpression::Atom(atom), negated]) if *atom == N::NEGATION_ATOM => Some(negated),
+            _ => None,
+        }
+    }
+}
+impl<C: TblExpressionCompound, N: TblNegation> Proposition
+    for NegatableTblExpression<TblExpression<C>, N>
+{
+}
+impl<C: TblExpressionCompound, N: TblNegation> ComplementaryProposition
+    for NegatableTblExpression<TblExpression<C>, N>
+{
+    fn is_complement_of(&self, other: &Self) -> bool {
+        self.get_negated() == Some(&other.0) || other.get_negated() == Some(&self.0)
+    }
+}
+
+impl<C: UnassignedTblExpressionCompound, N: TblNegation>
+    NegatableTblExpression<UnassignedTblExpression<C>, N>
+{
+    /// Get the expression negated by this one, or [None] if this is not of the form (¬,P)
+    /// A variable is never a negation, even though it may be assigned one
+    pub fn get_negated(&self) -> Option<&UnassignedTblExpression<C>> {
+        match self.0.as_slice() {
+            Ok([UnassignedTblExpression::Atom(atom), negated]) if *atom == N::NEGATION_ATOM => {
+                Some(negated)
+            }
+            _ => None,
+        }
+    }
+}
+impl<C: UnassignedTblExpressionCompound, N: TblNegation> ComplementaryProposition
+    for NegatableTblExpression<UnassignedTblExpression<C>, N>
+{
+    fn is_complement_of(&self, other: &Self) -> bool {
+        self.get_negated() == Some(&other.0) || other.get_negated() == Some(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expressions::types::{
+        assigned::BoxTblExpression,
+        unassigned::{BoxUnassignedTblExpression, variable::TblExpressionVariable},
+    };
+
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    struct TestNegation;
+    impl TblNegation for TestNegation {
+        const NEGATION_ATOM: TblExpressionAtom = TblExpressionAtom(3);
+    }
+    type Negatable = NegatableTblExpression<BoxTblExpression, TestNegation>;
+    type UnassignedNegatable = NegatableTblExpression<BoxUnassignedTblExpression, TestNegation>;
+
+    fn negate(expression: BoxTblExpression) -> BoxTblExpression {
+        [TblExpression::Atom(TestNegation::NEGATION_ATOM), expression].into()
+    }
+    fn negate_unassigned(expression: BoxUnassignedTblExpression) -> BoxUnassignedTblExpression {
+        [
+            UnassignedTblExpression::Atom(TestNegation::NEGATION_ATOM),
+            expression,
+        ]
+        .into()
+    }
+
+    #[test]
+    fn test_is_complement_of() {
+        let p: BoxTblExpression = [1.into(), 2.into()].into();
+        let q: BoxTblExpression = [1.into(), 4.into()].into();
+        let complementary = |a: &BoxTblExpression, b: &BoxTblExpression| {
+            Negatable::from(a.clone()).is_complement_of(&Negatable::from(b.clone()))
+        };
+        assert!(complementary(&negate(p.clone()), &p));
+        assert!(complementary(&p, &negate(p.clone())));
+        assert!(!complementary(&p, &negate(q.clone())));
+        assert!(!complementary(&p, &p));
+        // A double negation complements the single negation, but not the original expression
+        assert!(complementary(
+            &negate(negate(p.clone())),
+            &negate(p.clone())
+        ));
+        assert!(!complementary(&negate(negate(p.clone())), &p));
+        // A negation atom followed by several expressions is not a negation
+        let malformed: BoxTblExpression = [
+            TblExpression::Atom(TestNegation::NEGATION_ATOM),
+            p.clone(),
+            q,
+        ]
+        .into();
+        assert!(!complementary(&malformed, &p));
+        // Another system's negation atom does not negate
+        let other: BoxTblExpression = [TblExpression::Atom(TblExpressionAtom(4)), p.clone()].into();
+        assert!(!complementary(&other, &p));
+    }
+
+    #[test]
+    fn test_unassigned_is_complement_of() {
+        let x: BoxUnassignedTblExpression =
+            UnassignedTblExpression::Variable(TblExpressionVariable(0));
+        let p: BoxUnassignedTblExpression = [
+            UnassignedTblExpression::Atom(TblExpressionAtom(1)),
+            x.clone(),
+        ]
+        .into();
+        let complementary = |a: &BoxUnassignedTblExpression, b: &BoxUnassignedTblExpression| {
+            UnassignedNegatable::from(a.clone())
+                .is_complement_of(&UnassignedNegatable::from(b.clone()))
+        };
+        assert!(complementary(&negate_unassigned(p.clone()), &p));
+        assert!(complementary(&p, &negate_unassigned(p.clone())));
+        assert!(complementary(&negate_unassigned(x.clone()), &x));
+        assert!(!complementary(&p, &p));
+        // A variable is not a negation, even if it might be assigned one
+        let y: BoxUnassignedTblExpression =
+            UnassignedTblExpression::Variable(TblExpressionVariable(1));
+        assert!(!complementary(&y, &p));
+        assert!(!complementary(&negate_unassigned(y), &p));
+    }
+}